@@ -63,7 +63,7 @@ enum BddSolverType {
 impl From<BddSolverType> for DynamicBddSolver {
     fn from(value: BddSolverType) -> Self {
         match value {
-            BddSolverType::NaiveGreedy => Box::new(NaiveGreedySolver),
+            BddSolverType::NaiveGreedy => Box::new(NaiveGreedySolver::default()),
             BddSolverType::NaiveGreedyShared => Box::new(NaiveGreedySolverShared),
             BddSolverType::QuadraticGreedy => Box::new(QuadraticGreedySolver),
             BddSolverType::QuadraticGreedyShared => Box::new(QuadraticGreedySolverShared),
@@ -4,9 +4,10 @@ use crate::{
 use cancel_this::{Cancellable, is_cancelled};
 use ruddy::VariableId;
 use ruddy::split::Bdd;
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
 use std::ops::Index;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 /// Maps every [`Statement`] to a single BDD [`VariableId`].
 ///
@@ -14,21 +15,37 @@ use std::sync::Arc;
 /// necessarily need to use the exact same identifiers.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct DirectMap {
-    mapping: BTreeMap<Statement, VariableId>,
+    /// The variable and the (dense, zero-based) index of every statement.
+    mapping: BTreeMap<Statement, (VariableId, usize)>,
+    inverse: BTreeMap<VariableId, Statement>,
 }
 
 impl DirectMap {
     /// Create a new [`DirectMap`] from an ordered list of [`Statement`] objects.
+    ///
+    /// # Panics
+    ///
+    /// Fails if there are too many statements (see [`DirectMap::try_new`]).
     pub fn new(statements: &[Statement]) -> Self {
-        let mapping = statements
+        Self::try_new(statements).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Create a new [`DirectMap`] from an ordered list of [`Statement`] objects.
+    ///
+    /// Returns [`EncodingError::TooManyStatements`] if the statements cannot be mapped to BDD
+    /// variables, i.e. if there are more than `2^30` statements (each statement needs four
+    /// variables, see [`DirectMap::get_primed`]).
+    pub fn try_new(statements: &[Statement]) -> Result<Self, EncodingError> {
+        let mut mapping = BTreeMap::new();
+        for (index, stmt) in statements.iter().enumerate() {
+            let base = statement_base_variable(index, statements.len())?;
+            mapping.insert(stmt.clone(), (VariableId::new(base), index));
+        }
+        let inverse = mapping
             .iter()
-            .enumerate()
-            .map(|(index, stmt)| {
-                let index = u32::try_from(index).expect("Statement index out of range");
-                (stmt.clone(), VariableId::new(index << 2))
-            })
+            .map(|(stmt, (var, _))| (*var, stmt.clone()))
             .collect();
-        DirectMap { mapping }
+        Ok(DirectMap { mapping, inverse })
     }
 
     /// Get the number of statements in this map.
@@ -38,7 +55,22 @@ impl DirectMap {
 
     /// Get the BDD [`VariableId`] for a [`Statement`].
     pub fn get(&self, statement: &Statement) -> Option<VariableId> {
-        self.mapping.get(statement).copied()
+        self.mapping.get(statement).map(|(var, _)| *var)
+    }
+
+    /// Get the [`Statement`] that is encoded by the given BDD [`VariableId`], if any.
+    pub fn resolve(&self, var: VariableId) -> Option<&Statement> {
+        self.inverse.get(&var)
+    }
+
+    /// Get the (dense, zero-based) index of a [`Statement`] in this map, i.e. its position in
+    /// [`DirectMap::statements`].
+    ///
+    /// The index is assigned once the map is created and does not depend on the label of
+    /// the statement (which can be an arbitrary string, e.g. `-3`), only on its position
+    /// in the sorted order of all statements.
+    pub fn index_of(&self, statement: &Statement) -> Option<usize> {
+        self.mapping.get(statement).map(|(_, index)| *index)
     }
 
     /// Get all [`Statement`] objects in the map.
@@ -53,7 +85,7 @@ impl DirectMap {
     ///
     /// The order of values is not guaranteed.
     pub fn variable_ids(&self) -> impl DoubleEndedIterator<Item = &VariableId> + '_ {
-        self.mapping.values()
+        self.mapping.values().map(|(var, _)| var)
     }
 
     /// Return the last [`VariableId`] that is still relevant to this map.
@@ -70,15 +102,65 @@ impl DirectMap {
         let var = self[statement];
         Bdd::new_literal(var, polarity)
     }
+
+    /// Create a [`Bdd`] cube (conjunction of literals) which fixes the statements in
+    /// `assignment` to the given values.
+    ///
+    /// The literals are conjoined from the last variable to the first, so every step
+    /// only adds a single node on top of the partial cube.
+    ///
+    /// # Panics
+    ///
+    /// Every statement in `assignment` must be present in the map.
+    pub fn make_conjunction(&self, assignment: &BTreeMap<Statement, bool>) -> Bdd {
+        // Statements are assumed to follow the variable ordering (see [`DirectMap`]).
+        // Otherwise, the result is still correct, just not built as efficiently.
+        let mut result = Bdd::new_true();
+        for (statement, value) in assignment.iter().rev() {
+            let var = self
+                .get(statement)
+                .unwrap_or_else(|| panic!("Statement {} not found in DirectMap", statement));
+            result = Bdd::new_literal(var, *value).and(&result);
+        }
+        result
+    }
+
+    /// Get the "primed" BDD [`VariableId`] for a [`Statement`].
+    ///
+    /// Primed variables represent the *next* value of a statement in relations (see
+    /// [`AdfBdds::complete_relation`]). The statement with index `i` uses variable `4i`
+    /// for its direct encoding and variables `4i + 1` and `4i + 2` for its dual encoding.
+    /// The primed variable reuses the remaining spare slot `4i + 3`, meaning primed
+    /// variables always directly follow the variables of the same statement in the
+    /// BDD ordering.
+    pub fn get_primed(&self, statement: &Statement) -> Option<VariableId> {
+        self.get(statement).map(|var| {
+            VariableId::new_long(u64::from(var) + 3).expect("Primed variable out of range")
+        })
+    }
+
+    /// Create a [`Bdd`] literal for the primed variable of the given [`Statement`].
+    ///
+    /// # Panics
+    ///
+    /// The statement must be present in the map.
+    pub fn make_primed_literal(&self, statement: &Statement, polarity: bool) -> Bdd {
+        let var = self
+            .get_primed(statement)
+            .unwrap_or_else(|| panic!("Statement {} not found in DirectMap", statement));
+        Bdd::new_literal(var, polarity)
+    }
 }
 
 impl Index<&Statement> for DirectMap {
     type Output = VariableId;
 
     fn index(&self, statement: &Statement) -> &Self::Output {
-        self.mapping
+        &self
+            .mapping
             .get(statement)
             .expect("Statement not found in DirectMap")
+            .0
     }
 }
 
@@ -90,6 +172,31 @@ impl Index<Statement> for DirectMap {
     }
 }
 
+/// Identifies one of the two dual variables of a [`Statement`] in a [`DualMap`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Polarity {
+    /// The "positive" variable (the statement can be true).
+    Positive,
+    /// The "negative" variable (the statement can be false).
+    Negative,
+}
+
+/// The type of the link between two statements, i.e. how the condition of the target
+/// statement depends on the value of the source statement (see [`AdfBdds::link_type`]).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum LinkType {
+    /// The condition does not depend on the source statement.
+    Redundant,
+    /// Setting the source statement to `true` never changes the condition
+    /// from `true` to `false`.
+    Supporting,
+    /// Setting the source statement to `true` never changes the condition
+    /// from `false` to `true`.
+    Attacking,
+    /// The link is neither supporting nor attacking.
+    Dependent,
+}
+
 /// Maps every [`Statement`] to two BDD [`VariableId`] objects, one for "positive" and one for
 /// "negative" value of [`Statement`].
 ///
@@ -98,23 +205,42 @@ impl Index<Statement> for DirectMap {
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct DualMap {
     mapping: BTreeMap<Statement, (VariableId, VariableId)>,
+    inverse: BTreeMap<VariableId, (Statement, Polarity)>,
 }
 
 impl DualMap {
     /// Create a new [`DualMap`] from an ordered list of [`Statement`] objects.
     /// For each statement, two consecutive variable IDs are allocated (positive, then negative).
+    ///
+    /// # Panics
+    ///
+    /// Fails if there are too many statements (see [`DualMap::try_new`]).
     pub fn new(statements: &[Statement]) -> Self {
-        let mapping = statements
+        Self::try_new(statements).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Create a new [`DualMap`] from an ordered list of [`Statement`] objects.
+    ///
+    /// Returns [`EncodingError::TooManyStatements`] if the statements cannot be mapped to BDD
+    /// variables (see [`DirectMap::try_new`]).
+    pub fn try_new(statements: &[Statement]) -> Result<Self, EncodingError> {
+        let mut mapping = BTreeMap::new();
+        for (index, stmt) in statements.iter().enumerate() {
+            let base = statement_base_variable(index, statements.len())?;
+            let t_var = VariableId::new(base + 1);
+            let f_var = VariableId::new(base + 2);
+            mapping.insert(stmt.clone(), (t_var, f_var));
+        }
+        let inverse = mapping
             .iter()
-            .enumerate()
-            .map(|(i, stmt)| {
-                let index = u32::try_from(i).expect("Statement index out of range");
-                let t_var = VariableId::new((index << 2) + 1);
-                let f_var = VariableId::new((index << 2) + 2);
-                (stmt.clone(), (t_var, f_var))
+            .flat_map(|(stmt, (t_var, f_var))| {
+                [
+                    (*t_var, (stmt.clone(), Polarity::Positive)),
+                    (*f_var, (stmt.clone(), Polarity::Negative)),
+                ]
             })
             .collect();
-        DualMap { mapping }
+        Ok(DualMap { mapping, inverse })
     }
 
     /// Get the number of statements in this map.
@@ -127,6 +253,12 @@ impl DualMap {
         self.mapping.get(statement).copied()
     }
 
+    /// Get the [`Statement`] and [`Polarity`] of the dual variable given by a BDD
+    /// [`VariableId`], if any.
+    pub fn resolve(&self, var: VariableId) -> Option<(Statement, Polarity)> {
+        self.inverse.get(&var).cloned()
+    }
+
     /// Get all [`Statement`] objects in the map.
     ///
     /// The statements are returned in sorted order (by their index) because they are
@@ -204,10 +336,26 @@ impl Index<Statement> for DualMap {
 ///
 /// Note that statements can exist in `var_map` that do not have corresponding conditions.
 /// These are considered to be "free" statements.
+///
+/// Additionally, the encoding can contain a global `constraint` (see
+/// [`AdfBdds::with_constraint`]) that every two-valued interpretation must satisfy.
 #[derive(Clone)]
 pub struct DirectEncoding {
     var_map: DirectMap,
     conditions: BTreeMap<Statement, Bdd>,
+    constraint: Bdd,
+}
+
+impl fmt::Debug for DirectEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nodes: usize = self.conditions.values().map(|it| it.node_count()).sum();
+        f.debug_struct("DirectEncoding")
+            .field("statements", &self.var_map.size())
+            .field("conditions", &self.conditions.len())
+            .field("condition_nodes", &nodes)
+            .field("constraint_nodes", &self.constraint.node_count())
+            .finish()
+    }
 }
 
 impl DirectEncoding {
@@ -216,11 +364,70 @@ impl DirectEncoding {
         &self.var_map
     }
 
+    /// Get the global constraint over the direct variables. This is `true` unless
+    /// some constraint was added using [`AdfBdds::with_constraint`].
+    pub fn constraint(&self) -> &Bdd {
+        &self.constraint
+    }
+
     /// Get the [`Bdd`] condition for a [`Statement`], if it exists.
     pub fn get_condition(&self, statement: &Statement) -> Option<&Bdd> {
         self.conditions.get(statement)
     }
 
+    /// Get the statements that the [`Bdd`] condition of a [`Statement`] actually depends on,
+    /// if the condition exists.
+    ///
+    /// Unlike the statements referenced by the original
+    /// [`ConditionExpression`](crate::ConditionExpression), this omits references that are
+    /// vacuous (e.g. `b` in `or(a, and(b, neg(b)))`). The statements are returned in
+    /// sorted order.
+    pub fn condition_support(&self, statement: &Statement) -> Option<Vec<Statement>> {
+        let condition = self.conditions.get(statement)?;
+        let used = condition.used_variables();
+        let support = self
+            .var_map
+            .statements()
+            .filter(|s| used.contains(&self.var_map[*s]))
+            .cloned()
+            .collect();
+        Some(support)
+    }
+
+    /// Compute the prime implicants of the condition of the given `statement`. Returns `None`
+    /// if the statement is free or unknown.
+    ///
+    /// Each implicant is a partial assignment (cube) that guarantees the condition is `true`,
+    /// and no statement can be removed from it without losing this property. The implicants
+    /// are returned in sorted order. A condition that is always `true` has a single empty
+    /// implicant, while a condition that is always `false` has none.
+    ///
+    /// The number of prime implicants can be exponential in the size of the condition
+    /// support, so this is only intended for reasonably small conditions (e.g. when
+    /// explaining why a condition is satisfied).
+    pub fn condition_prime_implicants(
+        &self,
+        statement: &Statement,
+    ) -> Option<Vec<BTreeMap<Statement, bool>>> {
+        let condition = self.conditions.get(statement)?;
+        let support = condition.used_variables().into_iter().collect::<Vec<_>>();
+        let implicants = prime_implicants(condition, &support)
+            .into_iter()
+            .map(|cube| {
+                cube.into_iter()
+                    .map(|(var, value)| {
+                        let statement = self
+                            .var_map
+                            .resolve(var)
+                            .expect("Correctness violation: Unknown condition variable.");
+                        (statement.clone(), value)
+                    })
+                    .collect::<BTreeMap<_, _>>()
+            })
+            .collect::<BTreeSet<_>>();
+        Some(implicants.into_iter().collect())
+    }
+
     /// Get all statements that have conditions.
     ///
     /// The statements are returned in sorted order (by their index) because they are
@@ -229,6 +436,34 @@ impl DirectEncoding {
         self.conditions.keys()
     }
 
+    /// Iterate over all statements that have conditions, together with their [`Bdd`]
+    /// conditions. The statements are returned in sorted order.
+    pub fn iter_conditions(&self) -> impl Iterator<Item = (&Statement, &Bdd)> {
+        self.conditions.iter()
+    }
+
+    /// Get the number of nodes ([`Bdd::node_count`]) of the condition of every statement
+    /// that has a condition, e.g. to identify the statements responsible for a large encoding.
+    pub fn condition_sizes(&self) -> BTreeMap<Statement, usize> {
+        self.conditions
+            .iter()
+            .map(|(s, c)| (s.clone(), c.node_count()))
+            .collect()
+    }
+
+    /// Get the statement with the largest condition (see [`DirectEncoding::condition_sizes`]),
+    /// together with its node count. If multiple conditions have the same size, the first
+    /// statement is returned. Returns `None` if there are no conditions.
+    pub fn largest_condition(&self) -> Option<(Statement, usize)> {
+        self.conditions
+            .iter()
+            .map(|(s, c)| (s, c.node_count()))
+            // `max_by_key` returns the last maximum, hence the reversed iteration.
+            .rev()
+            .max_by_key(|(_, size)| *size)
+            .map(|(s, size)| (s.clone(), size))
+    }
+
     /// Returns true if the given [`Bdd`] only uses variables used by this [`DirectMap`].
     pub fn is_direct_encoded(&self, bdd: &Bdd) -> bool {
         let used: BTreeSet<VariableId> = bdd.used_variables();
@@ -240,6 +475,10 @@ impl DirectEncoding {
     /// ADF statements.
     ///
     /// The given BDD can only use variables of the direct encoding.
+    ///
+    /// The result only overflows to [`f64::INFINITY`] if the number of valuations
+    /// itself is not representable. Variables that are not used by the BDD are not
+    /// part of the intermediate count.
     pub fn count_direct_valuations(&self, bdd: &Bdd) -> f64 {
         assert!(self.is_direct_encoded(bdd));
 
@@ -258,8 +497,12 @@ impl DirectEncoding {
 
         // Count valuations and normalize them based on unused variables.
         let count = bdd.count_satisfying_valuations(Some(max_var));
+        if count.is_finite() {
+            return count / 2.0f64.powf(unused_vars as f64);
+        }
 
-        count / 2.0f64.powf(unused_vars as f64)
+        // The count over all variables overflows, but the result can be smaller.
+        count_compacted_valuations(bdd, statement_count * 4 - unused_vars)
     }
 
     /// Extract the valuation with the highest number of fixed zeros.
@@ -308,6 +551,23 @@ pub struct DualEncoding {
     var_map: DualMap,
     conditions: BTreeMap<Statement, (Bdd, Bdd)>,
     valid: Bdd,
+    constraint: Bdd,
+}
+
+impl fmt::Debug for DualEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nodes: usize = self
+            .conditions
+            .values()
+            .map(|(t, f)| t.node_count() + f.node_count())
+            .sum();
+        f.debug_struct("DualEncoding")
+            .field("statements", &self.var_map.size())
+            .field("conditions", &self.conditions.len())
+            .field("condition_nodes", &nodes)
+            .field("constraint_nodes", &self.constraint.node_count())
+            .finish()
+    }
 }
 
 impl DualEncoding {
@@ -329,15 +589,38 @@ impl DualEncoding {
         self.conditions.keys()
     }
 
+    /// Iterate over all statements that have conditions, together with their [`Bdd`]
+    /// conditions (can_be_true, can_be_false). The statements are returned in sorted order.
+    pub fn iter_conditions(&self) -> impl Iterator<Item = (&Statement, (&Bdd, &Bdd))> {
+        self.conditions.iter().map(|(s, (t, f))| (s, (t, f)))
+    }
+
     /// Get the [`Bdd`] representing all valid dual variable valuations.
     ///
     /// This BDD is true for valuations where, for every statement, at least one of its
     /// dual variables (positive or negative) is true. Valuations where both dual variables
     /// of any statement are false are excluded as invalid.
+    ///
+    /// In other words, every statement is restricted to one of the three values
+    /// `1` (positive only), `0` (negative only), or `*` (both), hence the valid valuations
+    /// correspond exactly to the `3^n` three-valued interpretations of the `n` statements.
+    /// The constraint applies to *all* statements, including free statements, and it
+    /// does not depend on the conditions. Every dual encoded set (see
+    /// [`DualEncoding::is_dual_encoded`]) is a subset of this BDD, which is why the
+    /// solvers use it as the initial constraint of every three-valued semantics.
     pub fn valid(&self) -> &Bdd {
         &self.valid
     }
 
+    /// Get the global constraint translated to the dual encoding.
+    ///
+    /// A three-valued interpretation satisfies the constraint if all of its two-valued
+    /// completions satisfy [`DirectEncoding::constraint`]. This is `true` unless some
+    /// constraint was added using [`AdfBdds::with_constraint`].
+    pub fn constraint(&self) -> &Bdd {
+        &self.constraint
+    }
+
     /// Returns true if the given [`Bdd`] only uses variables used by this [`DualMap`],
     /// and represents a subset of [`Self::valid`].
     pub fn is_dual_encoded(&self, bdd: &Bdd) -> bool {
@@ -352,6 +635,10 @@ impl DualEncoding {
     ///
     /// The given BDD can only use variables of the dual encoding and must not contain
     /// any valuations that are invalid in the dual encoding.
+    ///
+    /// The result only overflows to [`f64::INFINITY`] if the number of valuations
+    /// itself is not representable. Variables that are not used by the BDD are not
+    /// part of the intermediate count.
     pub fn count_dual_valuations(&self, bdd: &Bdd) -> f64 {
         assert!(self.is_dual_encoded(bdd));
 
@@ -370,8 +657,12 @@ impl DualEncoding {
 
         // Count valuations and normalize them based on unused variables.
         let count = bdd.count_satisfying_valuations(Some(max_var));
+        if count.is_finite() {
+            return count / 2.0f64.powf(unused_vars as f64);
+        }
 
-        count / 2.0f64.powf(unused_vars as f64)
+        // The count over all variables overflows, but the result can be smaller.
+        count_compacted_valuations(bdd, statement_count * 4 - unused_vars)
     }
 
     /// Extract the valuation with the highest number of fixed variable values (i.e. `01` or `10`
@@ -408,10 +699,21 @@ impl DualEncoding {
 ///
 /// By default, the variables are ordered such that the direct variable is followed by the
 /// two dual variables.
+///
+/// The dual encoding is computed lazily from the direct encoding on first access and then
+/// cached, meaning two-valued workflows never pay for its construction. The cache is
+/// thread-safe, so [`AdfBdds`] can be shared between threads. Use
+/// [`AdfBdds::try_from_expressions`] to compute both encodings eagerly.
+///
+/// Cloning is cheap: the clone shares the encodings (and their caches) with the original,
+/// so model sets created by either of them are compatible. Consequently, the [`PartialEq`]
+/// implementation compares the identity of the encodings. Use [`AdfBdds::structural_eq`]
+/// to compare independently constructed encodings.
 #[derive(Clone)]
 pub struct AdfBdds {
     direct_encoding: Arc<DirectEncoding>,
-    dual_encoding: Arc<DualEncoding>,
+    dual_encoding: Arc<OnceLock<Arc<DualEncoding>>>,
+    grounded: Arc<OnceLock<BTreeMap<Statement, Option<bool>>>>,
 }
 
 impl PartialEq for AdfBdds {
@@ -423,15 +725,453 @@ impl PartialEq for AdfBdds {
 
 impl Eq for AdfBdds {}
 
+impl fmt::Debug for AdfBdds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The dual encoding is only printed if it is already computed.
+        f.debug_struct("AdfBdds")
+            .field("direct_encoding", self.direct_encoding.as_ref())
+            .field(
+                "dual_encoding",
+                &self.dual_encoding.get().map(|it| it.as_ref()),
+            )
+            .finish()
+    }
+}
+
 impl AdfBdds {
     /// Get the direct encoding of this ADF.
     pub fn direct_encoding(&self) -> &DirectEncoding {
         &self.direct_encoding
     }
 
+    /// Get the shared pointer to the direct encoding of this ADF, e.g. to create a
+    /// [`ModelSetTwoValued`] manually.
+    pub fn direct_encoding_arc(&self) -> &Arc<DirectEncoding> {
+        &self.direct_encoding
+    }
+
+    /// Returns `true` if the two encodings have the same statements (mapped to the same
+    /// variables), structurally equal condition BDDs, and structurally equal global constraints.
+    ///
+    /// Unlike `==`, this is also `true` for encodings that were constructed independently
+    /// (e.g. from the same [`AdfExpressions`]). However, model sets of such encodings are
+    /// not compatible, see [`ModelSetTwoValued::rebind`].
+    pub fn structural_eq(&self, other: &AdfBdds) -> bool {
+        if self == other {
+            return true;
+        }
+        let (a, b) = (self.direct_encoding(), other.direct_encoding());
+        a.var_map() == b.var_map()
+            && a.constraint().structural_eq(b.constraint())
+            && a.iter_conditions().count() == b.iter_conditions().count()
+            && a.iter_conditions()
+                .zip(b.iter_conditions())
+                .all(|((s_a, c_a), (s_b, c_b))| s_a == s_b && c_a.structural_eq(c_b))
+    }
+
+    /// Get the direct [`Bdd`] condition of a [`Statement`] together with the [`DirectMap`]
+    /// that defines its variables. Returns `None` for free or unknown statements.
+    pub fn condition_bdd(&self, statement: &Statement) -> Option<(&Bdd, &DirectMap)> {
+        let condition = self.direct_encoding.get_condition(statement)?;
+        Some((condition, self.direct_encoding.var_map()))
+    }
+
     /// Get the dual encoding of this ADF.
+    ///
+    /// If the dual encoding has not been computed yet, it is computed (and cached) now.
+    /// This computation is not cancellable. Use [`AdfBdds::try_dual_encoding`] if you
+    /// need to interrupt it.
     pub fn dual_encoding(&self) -> &DualEncoding {
-        &self.dual_encoding
+        self.try_dual_encoding()
+            .expect("Computation of the dual encoding was cancelled")
+    }
+
+    /// Get the dual encoding of this ADF, computing it if necessary.
+    ///
+    /// This operation is cancellable using the `cancel-this` crate. If cancelled, the
+    /// encoding is not cached and the next call will start the computation again.
+    pub fn try_dual_encoding(&self) -> Cancellable<&DualEncoding> {
+        Ok(self.try_dual_encoding_arc()?.as_ref())
+    }
+
+    /// Get the grounded interpretation of this ADF, where `None` represents an undefined
+    /// statement.
+    ///
+    /// The interpretation is computed on first access using [`AdfBdds::try_grounded_symbolic`]
+    /// (i.e. free statements are undefined, as with the default
+    /// [`FreePolicy`](crate::FreePolicy)) and then cached, so it can be reused by multiple
+    /// queries without recomputation (see also [`AdfBdds::grounded_or_init`]). The cache is
+    /// shared with copies created by [`AdfBdds::with_constraint`], since global constraints
+    /// do not affect the grounded interpretation. This computation is not cancellable.
+    /// Use [`AdfBdds::try_grounded`] if you need to interrupt it.
+    pub fn grounded(&self) -> &BTreeMap<Statement, Option<bool>> {
+        self.try_grounded()
+            .expect("Computation of the grounded interpretation was cancelled")
+    }
+
+    /// Get the grounded interpretation of this ADF (see [`AdfBdds::grounded`]), computing it
+    /// if necessary.
+    ///
+    /// This operation is cancellable using the `cancel-this` crate. If cancelled, the
+    /// interpretation is not cached and the next call will start the computation again.
+    pub fn try_grounded(&self) -> Cancellable<&BTreeMap<Statement, Option<bool>>> {
+        self.grounded_or_init(|adf| {
+            Ok(adf
+                .try_grounded_symbolic()?
+                .interpretations()
+                .pop()
+                .expect("Correctness violation: Grounded interpretation is missing."))
+        })
+    }
+
+    /// Get the cached grounded interpretation of this ADF (see [`AdfBdds::grounded`]), or
+    /// compute it using `init` and cache the result if it is not cached yet.
+    ///
+    /// This allows the callers to compute the grounded interpretation using their own
+    /// method (e.g. using a specific [`BddSolver`](crate::bdd_solver::BddSolver)). The
+    /// `init` function must return the grounded interpretation where free statements are
+    /// undefined, otherwise the cache is invalid. If `init` is cancelled, nothing is cached.
+    pub fn grounded_or_init(
+        &self,
+        init: impl FnOnce(&AdfBdds) -> Cancellable<BTreeMap<Statement, Option<bool>>>,
+    ) -> Cancellable<&BTreeMap<Statement, Option<bool>>> {
+        if let Some(grounded) = self.grounded.get() {
+            return Ok(grounded);
+        }
+
+        let grounded = init(self)?;
+        // As with the dual encoding, a concurrently computed result is equivalent.
+        let _ = self.grounded.set(grounded);
+        Ok(self
+            .grounded
+            .get()
+            .expect("Correctness violation: Grounded interpretation not initialized."))
+    }
+
+    /// Returns `true` if the dual encoding has already been computed.
+    pub fn has_dual_encoding(&self) -> bool {
+        self.dual_encoding.get().is_some()
+    }
+
+    /// Get the shared pointer to the dual encoding of this ADF, e.g. to create a
+    /// [`ModelSetThreeValued`] manually. Computes the encoding if necessary
+    /// (see [`AdfBdds::dual_encoding`]).
+    pub fn dual_encoding_arc(&self) -> &Arc<DualEncoding> {
+        self.try_dual_encoding_arc()
+            .expect("Computation of the dual encoding was cancelled")
+    }
+
+    /// Cancellable access to the shared pointer of the dual encoding.
+    fn try_dual_encoding_arc(&self) -> Cancellable<&Arc<DualEncoding>> {
+        if let Some(dual) = self.dual_encoding.get() {
+            return Ok(dual);
+        }
+        let dual = build_dual_encoding(&self.direct_encoding, None)?;
+        // If another thread finished the computation in the meantime, its result is kept
+        // and ours is discarded. Both are equivalent.
+        let _ = self.dual_encoding.set(Arc::new(dual));
+        Ok(self
+            .dual_encoding
+            .get()
+            .expect("Correctness violation: Dual encoding not initialized."))
+    }
+
+    /// Compute the two-valued ADF operator as an input/output relation.
+    ///
+    /// The resulting [`Bdd`] uses the direct variables as the *input* interpretation and
+    /// the primed variables (see [`DirectMap::get_primed`]) as the *output* interpretation.
+    /// A pair of interpretations is in the relation if and only if `s' = condition(s)(input)`
+    /// for every statement `s`. Statements without a condition are free, meaning their
+    /// output value is unconstrained.
+    ///
+    /// Note that the result is not "directly encoded", i.e. it cannot be used to build
+    /// a [`ModelSetTwoValued`] without first eliminating the primed variables.
+    pub fn complete_relation(&self) -> Bdd {
+        let direct = self.direct_encoding();
+        let mut relation = Bdd::new_true();
+        for statement in direct.conditional_statements() {
+            let condition = &direct.conditions[statement];
+            let output = direct.var_map().make_primed_literal(statement, true);
+            relation = relation.and(&output.iff(condition));
+        }
+        relation
+    }
+
+    /// Compute the set of interpretations that return to themselves after exactly
+    /// `period` applications of the two-valued ADF operator (see
+    /// [`AdfBdds::complete_relation`]).
+    ///
+    /// The result contains all interpretations on cycles whose length divides `period`,
+    /// in particular, for `period = 1`, these are exactly the two-valued models. Statements
+    /// without a condition can take any value in every step. The global constraint (see
+    /// [`AdfBdds::with_constraint`]) is applied to every step, i.e. the whole cycle must
+    /// satisfy it.
+    ///
+    /// Since the operator is two-valued, the result only contains two-valued interpretations
+    /// (see [`ModelSetThreeValued::two_valued_subset`]). It is a [`ModelSetThreeValued`] such
+    /// that it can be directly compared with the results of three-valued semantics. Note
+    /// that this requires the dual encoding of this ADF.
+    ///
+    /// # Panics
+    ///
+    /// The `period` must be positive.
+    pub fn periodic_interpretations(&self, period: usize) -> ModelSetThreeValued {
+        assert!(period > 0, "The period must be positive");
+        let var_map = self.direct_encoding().var_map();
+
+        // The relation only uses the direct and primed variables. The remaining spare
+        // variable of each statement (`4i + 1`) holds the intermediate interpretation.
+        let variables: Vec<(VariableId, VariableId, VariableId)> = var_map
+            .statements()
+            .map(|statement| {
+                let direct = var_map[statement];
+                let primed = var_map.get_primed(statement).expect("Statement exists");
+                let temporary = VariableId::new_long(u64::from(direct) + 1)
+                    .expect("Temporary variable out of range");
+                (direct, primed, temporary)
+            })
+            .collect();
+        let temporaries: Vec<VariableId> = variables.iter().map(|(_, _, t)| *t).collect();
+
+        let relation = self
+            .complete_relation()
+            .and(self.direct_encoding().constraint());
+        // The relation from the intermediate interpretation to the output interpretation.
+        let mut step = relation.clone();
+        for (direct, _, temporary) in &variables {
+            step = step.safe_substitution(*direct, &Bdd::new_literal(*temporary, true));
+        }
+
+        let mut power = relation;
+        for _ in 1..period {
+            let mut partial = power;
+            for (_, primed, temporary) in &variables {
+                partial = partial.safe_substitution(*primed, &Bdd::new_literal(*temporary, true));
+            }
+            power = partial.and(&step).exists(&temporaries);
+        }
+
+        // Only keep pairs where the output interpretation is equal to the input.
+        let mut result = power;
+        for (direct, primed, _) in &variables {
+            result = result.safe_substitution(*primed, &Bdd::new_literal(*direct, true));
+        }
+
+        // Embed the two-valued interpretations into the dual encoding.
+        let dual_map = self.dual_encoding().var_map();
+        for statement in dual_map.statements() {
+            let (p_lit, n_lit) = dual_map.make_literals(statement);
+            result = result
+                .safe_substitution(var_map[statement], &p_lit)
+                .and(&p_lit.xor(&n_lit));
+        }
+        self.mk_three_valued_set(result)
+    }
+
+    /// Compute the grounded interpretation of this ADF as the least fixed point of the
+    /// three-valued ADF operator, iterated symbolically (see
+    /// [`AdfBdds::try_grounded_symbolic`]).
+    ///
+    /// This computation is not cancellable. Use [`AdfBdds::try_grounded_symbolic`]
+    /// if you need to interrupt it.
+    pub fn grounded_symbolic(&self) -> ModelSetThreeValued {
+        self.try_grounded_symbolic()
+            .expect("Computation of the grounded interpretation was cancelled")
+    }
+
+    /// Compute the grounded interpretation of this ADF as the least fixed point of the
+    /// three-valued ADF operator.
+    ///
+    /// Instead of evaluating the conditions statement by statement (as in
+    /// [`AdfInterpretationSolver::solve_grounded`](crate::AdfInterpretationSolver::solve_grounded)),
+    /// the operator is encoded as a single input/output relation over the dual variables,
+    /// and the iteration starts from the interpretation where every statement is undefined.
+    /// Every step is then one relational image. The output interpretation is stored in the two variables that are not used by
+    /// the dual encoding (i.e. the direct and primed variable of each statement). Statements
+    /// without a condition stay undefined, same as with the default
+    /// [`FreePolicy`](crate::FreePolicy). The global constraint is ignored.
+    ///
+    /// The result is a singleton [`ModelSetThreeValued`] which is equal to the result of
+    /// [`AdfInterpretationSolver::solve_grounded`](crate::AdfInterpretationSolver::solve_grounded).
+    /// Cancellation is checked before every step.
+    pub fn try_grounded_symbolic(&self) -> Cancellable<ModelSetThreeValued> {
+        let dual = self.try_dual_encoding()?;
+        let dual_map = dual.var_map();
+        let direct_map = self.direct_encoding().var_map();
+
+        // (p, n, p', n') for every statement.
+        let variables: Vec<(VariableId, VariableId, VariableId, VariableId)> = dual_map
+            .statements()
+            .map(|statement| {
+                let (p_var, n_var) = dual_map[statement];
+                let p_output = direct_map[statement];
+                let n_output = direct_map.get_primed(statement).expect("Statement exists");
+                (p_var, n_var, p_output, n_output)
+            })
+            .collect();
+        let inputs: Vec<VariableId> = dual_map.variable_ids().copied().collect();
+
+        let mut relation = Bdd::new_true();
+        for (statement, (_, _, p_output, n_output)) in dual_map.statements().zip(&variables) {
+            is_cancelled!()?;
+            let p_output = Bdd::new_literal(*p_output, true);
+            let n_output = Bdd::new_literal(*n_output, true);
+            let step = match dual.get_condition(statement) {
+                Some((p_condition, n_condition)) => {
+                    p_output.iff(p_condition).and(&n_output.iff(n_condition))
+                }
+                // Free statements are always undefined in the output.
+                None => p_output.and(&n_output),
+            };
+            relation = relation.and(&step);
+        }
+
+        let mut current = Bdd::new_true();
+        for (p_var, n_var, _, _) in &variables {
+            current = current
+                .and(&Bdd::new_literal(*p_var, true))
+                .and(&Bdd::new_literal(*n_var, true));
+        }
+
+        loop {
+            is_cancelled!()?;
+            let mut next = current.and(&relation).exists(&inputs);
+            for (p_var, n_var, p_output, n_output) in &variables {
+                next = next
+                    .safe_substitution(*p_output, &Bdd::new_literal(*p_var, true))
+                    .safe_substitution(*n_output, &Bdd::new_literal(*n_var, true));
+            }
+            if next.structural_eq(&current) {
+                return Ok(self.mk_three_valued_set(current));
+            }
+            current = next;
+        }
+    }
+
+    /// Compute the range (the set of decided statements) of the given dual-encoded
+    /// interpretations.
+    ///
+    /// The result is a [`Bdd`] over the direct variables, where the direct variable of a
+    /// statement is reused to mark whether the statement is *decided* (`0` or `1`) in
+    /// the interpretation. For a singleton `interpretations` set, the result is a single
+    /// valuation. For larger sets, it is the set of ranges of all interpretations
+    /// (e.g. to find range-maximal interpretations for semi-stable or stage semantics).
+    ///
+    /// # Panics
+    ///
+    /// The `interpretations` must be a dual-encoded BDD.
+    pub fn range_of(&self, interpretations: &Bdd) -> Bdd {
+        let dual = self.dual_encoding();
+        assert!(dual.is_dual_encoded(interpretations));
+
+        let direct_map = self.direct_encoding().var_map();
+        let mut relation = interpretations.clone();
+        for statement in direct_map.statements() {
+            let decided = direct_map.make_literal(statement, true);
+            let (p_literal, n_literal) = dual.var_map().make_literals(statement);
+            relation = relation.and(&decided.iff(&p_literal.and(&n_literal).not()));
+        }
+
+        let dual_vars = dual.var_map().variable_ids().copied().collect::<Vec<_>>();
+        relation.exists(&dual_vars)
+    }
+
+    /// Group statements whose conditions are semantically equivalent, i.e. their direct
+    /// encoding BDDs are structurally equal.
+    ///
+    /// Only groups with at least two statements are returned (statements with a unique
+    /// condition are not redundant). Statements without a condition are ignored. Groups are
+    /// sorted by their first statement, and statements within each group are sorted as well.
+    pub fn equivalent_condition_classes(&self) -> Vec<Vec<Statement>> {
+        let direct = self.direct_encoding();
+        // Classes in the order of their first statement, indexed by their condition.
+        let mut classes: Vec<Vec<Statement>> = Vec::new();
+        let mut class_index = BddMap::new();
+        for statement in direct.conditional_statements() {
+            let condition = &direct.conditions[statement];
+            match class_index.get(condition).copied() {
+                Some(index) => classes[index].push(statement.clone()),
+                None => {
+                    class_index.insert(condition.clone(), classes.len());
+                    classes.push(vec![statement.clone()]);
+                }
+            }
+        }
+
+        classes
+            .into_iter()
+            .filter(|class| class.len() > 1)
+            .collect()
+    }
+
+    /// Identify statements that cannot influence any other statement: they have a constant
+    /// condition (hence their value is determined), and their variable does not appear in
+    /// the support of any condition (including their own) or of the global constraint.
+    ///
+    /// Existentially quantifying such a statement out of every condition leaves all
+    /// conditions unchanged. Such statements can be safely pruned before solving, since their
+    /// value in every model is simply the value of their condition. The result is sorted.
+    pub fn irrelevant_statements(&self) -> Vec<Statement> {
+        let direct = self.direct_encoding();
+        let mut used = direct.constraint().used_variables();
+        for (_, condition) in direct.iter_conditions() {
+            used.extend(condition.used_variables());
+        }
+
+        direct
+            .iter_conditions()
+            .filter(|(statement, condition)| {
+                (condition.is_true() || condition.is_false())
+                    && !used.contains(&direct.var_map()[*statement])
+            })
+            .map(|(statement, _)| statement.clone())
+            .collect()
+    }
+
+    /// Create a copy of this ADF with an additional global constraint (integrity axiom).
+    ///
+    /// The constraint is an arbitrary expression over the statements of this ADF (e.g.
+    /// `neg(and(0, 1))` to prevent both `0` and `1` from being true). It is combined with
+    /// any previous constraint using conjunction. Two-valued semantics only admit
+    /// interpretations that satisfy the constraint, while three-valued semantics only admit
+    /// interpretations where every two-valued completion satisfies the constraint (see
+    /// [`DualEncoding::constraint`]). The grounded interpretation is not affected.
+    ///
+    /// If the dual encoding is already computed, the constraint is translated immediately.
+    /// This computation is not cancellable. Use [`AdfBdds::try_with_constraint`] if you
+    /// need to interrupt it.
+    ///
+    /// # Panics
+    ///
+    /// The constraint must only reference statements of this ADF.
+    pub fn with_constraint(&self, constraint: &ConditionExpression) -> AdfBdds {
+        self.try_with_constraint(constraint)
+            .expect("Computation of the constraint was cancelled")
+    }
+
+    /// Cancellable version of [`AdfBdds::with_constraint`].
+    pub fn try_with_constraint(&self, constraint: &ConditionExpression) -> Cancellable<AdfBdds> {
+        let mut direct_copy = self.direct_encoding().clone();
+        let constraint = expression_to_bdd(constraint, &direct_copy.var_map)?;
+        direct_copy.constraint = direct_copy.constraint.and(&constraint);
+
+        // If the dual encoding is not computed yet, the copy can compute it lazily as well.
+        let dual_encoding = OnceLock::new();
+        if let Some(dual) = self.dual_encoding.get() {
+            let mut dual_copy = dual.as_ref().clone();
+            dual_copy.constraint = direct_to_dual_constraint(
+                &direct_copy.constraint,
+                &direct_copy.var_map,
+                &dual_copy.var_map,
+            )?;
+            let _ = dual_encoding.set(Arc::new(dual_copy));
+        }
+
+        Ok(AdfBdds {
+            direct_encoding: Arc::new(direct_copy),
+            dual_encoding: Arc::new(dual_encoding),
+            grounded: self.grounded.clone(),
+        })
     }
 
     /// Iterator over all statements of this [`AdfBdds`].
@@ -450,7 +1190,7 @@ impl AdfBdds {
     ///
     /// The BDD must satisfy [`DualEncoding::is_dual_encoded`].
     pub fn mk_three_valued_set(&self, bdd: Bdd) -> ModelSetThreeValued {
-        ModelSetThreeValued::new(bdd, self.dual_encoding.clone())
+        ModelSetThreeValued::new(bdd, self.dual_encoding_arc().clone())
     }
 
     /// Instantiate a single three-valued interpretation into a symbolic set.
@@ -480,79 +1220,324 @@ impl AdfBdds {
         self.mk_two_valued_set(bdd)
     }
 
-    /// Try to create a [`AdfBdds`] from an [`AdfExpressions`].
+    /// Build a [`ModelSetTwoValued`] from an explicit list of two-valued interpretations.
     ///
-    /// This operation is cancellable using the `cancel-this` crate. If cancelled,
-    /// it will return an error.
+    /// Statements that are not mentioned in a model are unconstrained, i.e. such a model
+    /// represents all interpretations that agree with it on the mentioned statements.
     ///
     /// # Panics
     ///
-    /// Conversion fails if [`AdfExpressions`] contains missing statements.
-    pub fn try_from_expressions(adf: &AdfExpressions) -> Cancellable<Self> {
-        assert!(
-            adf.find_missing_statements().is_empty(),
-            "ADF contains missing statements."
-        );
-        // Get all statements in sorted order
-        let statements: Vec<Statement> = adf.statements().cloned().collect();
-
-        // Create variable maps
-        let direct_map = DirectMap::new(&statements);
-        let dual_map = DualMap::new(&statements);
-
-        // Build direct encoding conditions
-        let mut direct_conditions = BTreeMap::new();
-        for (statement, condition) in adf.conditions() {
-            is_cancelled!()?;
-            let bdd = expression_to_bdd(condition, &direct_map)?;
-            direct_conditions.insert(statement, bdd);
+    /// Every statement referenced by the models must be present in this ADF.
+    pub fn two_valued_set_from_models(
+        &self,
+        models: &[BTreeMap<Statement, bool>],
+    ) -> ModelSetTwoValued {
+        let var_map = self.direct_encoding().var_map();
+        let mut result = Bdd::new_false();
+        for model in models {
+            result = result.or(&var_map.make_conjunction(model));
         }
+        self.mk_two_valued_set(result)
+    }
 
-        // Build dual encoding conditions from direct encoding
-        let mut dual_conditions = BTreeMap::new();
-        for (statement, condition) in direct_conditions.iter() {
-            let mapping_function =
-                direct_to_dual_map_function(&direct_map, &dual_map, &condition.used_variables())?;
+    /// Compute a minimal set of statements whose values in `model` are sufficient to force
+    /// the value of the `target` statement through its condition.
+    ///
+    /// The result corresponds to a prime implicant of the condition (or its negation)
+    /// that is satisfied by the `model`. It is minimal with respect to set inclusion (no
+    /// statement can be removed), but not necessarily the smallest such set. The statements
+    /// are returned in sorted order. For free statements, and for constant conditions,
+    /// the result is empty.
+    ///
+    /// # Panics
+    ///
+    /// The `model` must assign a value to `target` and to every statement that its condition
+    /// depends on, and the value of `target` must be consistent with its condition.
+    pub fn minimal_support(
+        &self,
+        model: &BTreeMap<Statement, bool>,
+        target: Statement,
+    ) -> Vec<Statement> {
+        let direct = self.direct_encoding();
+        let var_map = direct.var_map();
+        let Some(condition) = direct.get_condition(&target) else {
+            return Vec::new();
+        };
+        let value = *model
+            .get(&target)
+            .unwrap_or_else(|| panic!("Statement {} not found in the model", target));
+        let forced = if value {
+            condition.clone()
+        } else {
+            condition.not()
+        };
 
-            let can_be_true = direct_to_dual_encoding(condition, &mapping_function, &direct_map);
-            is_cancelled!()?;
-            let can_be_false =
-                direct_to_dual_encoding(&condition.not(), &mapping_function, &direct_map);
-            is_cancelled!()?;
+        let is_forced = |support: &[Statement]| {
+            let mut cube = Bdd::new_true();
+            for statement in support {
+                let value = model
+                    .get(statement)
+                    .unwrap_or_else(|| panic!("Statement {} not found in the model", statement));
+                cube = cube.and(&var_map.make_literal(statement, *value));
+            }
+            cube.and(&forced.not()).is_false()
+        };
 
-            dual_conditions.insert(statement.clone(), (can_be_true, can_be_false));
-        }
+        let mut support = direct
+            .condition_support(&target)
+            .expect("The condition exists");
+        assert!(
+            is_forced(&support),
+            "Value of {} is not consistent with its condition",
+            target
+        );
 
-        // Build the valid BDD for dual encoding
-        // For each statement, at least one of (t_var, f_var) must be true
-        let mut valid = Bdd::new_true();
-        for statement in &statements {
-            is_cancelled!()?;
-            let (t_lit, f_lit) = dual_map.make_literals(statement);
-            // At least one must be true: t_var OR f_var
-            valid = valid.and(&t_lit.or(&f_lit));
+        // Greedily remove statements that are not needed to force the value.
+        let mut i = 0;
+        while i < support.len() {
+            let removed = support.remove(i);
+            if !is_forced(&support) {
+                support.insert(i, removed);
+                i += 1;
+            }
         }
 
-        Ok(AdfBdds {
-            direct_encoding: Arc::new(DirectEncoding {
-                var_map: direct_map,
-                conditions: direct_conditions,
-            }),
-            dual_encoding: Arc::new(DualEncoding {
-                var_map: dual_map,
-                conditions: dual_conditions,
-                valid,
-            }),
-        })
+        support
     }
 
-    /// Get all statements that are "free" (have no condition or have an identity condition).
+    /// Returns `true` if the given `set` contains the interpretation where all statements
+    /// are undefined (`*`), i.e. all dual variables are set.
     ///
-    /// A statement is considered free if:
-    /// - It has no condition defined, or
-    /// - Its condition is equivalent to the statement itself (i.e., `ac(s, s)`).
-    pub fn free_statements(&self) -> BTreeSet<Statement> {
-        let mut free_statements = BTreeSet::new();
+    /// The all-undefined interpretation is always admissible (and it is the bottom of the
+    /// information order), unless it is excluded by a global constraint (see
+    /// [`AdfBdds::with_constraint`]) or by a [`FreePolicy`](crate::FreePolicy) that fixes
+    /// free statements. Hence, this serves as a cheap sanity check of admissible sets.
+    pub fn contains_empty_interpretation(&self, set: &ModelSetThreeValued) -> bool {
+        let var_map = self.dual_encoding().var_map();
+        let mut all_undefined = set.symbolic_set().clone();
+        for statement in var_map.statements() {
+            let (p_lit, n_lit) = var_map.make_literals(statement);
+            all_undefined = all_undefined.and(&p_lit).and(&n_lit);
+        }
+        !all_undefined.is_false()
+    }
+
+    /// Build the [`ModelSetThreeValued`] of all three-valued interpretations that are
+    /// below the given two-valued `model` in the information order, i.e. every statement
+    /// is either undefined, or has the same value as in `model`.
+    ///
+    /// Statements that are not mentioned in the `model` are unconstrained (they can take
+    /// any of the three values).
+    ///
+    /// # Panics
+    ///
+    /// Every statement referenced by the model must be present in this ADF.
+    pub fn completions_below(&self, model: &BTreeMap<Statement, bool>) -> ModelSetThreeValued {
+        let dual = self.dual_encoding();
+        let mut result = dual.valid().clone();
+        for (statement, value) in model {
+            let Some((t_var, f_var)) = dual.var_map().get(statement) else {
+                panic!("Statement {} not found in the ADF", statement);
+            };
+            // A statement below `true` can be `true` or `*`, i.e. its positive dual
+            // variable must be set (and analogously for `false`).
+            let var = if *value { t_var } else { f_var };
+            result = result.and(&Bdd::new_literal(var, true));
+        }
+        self.mk_three_valued_set(result)
+    }
+
+    /// Extend a partial two-valued `assignment` with all statement values that are forced
+    /// by propagation through the direct conditions.
+    ///
+    /// A statement that is not assigned yet is fixed once its condition is either `true`
+    /// or `false` for every valuation compatible with the current assignment. This is
+    /// repeated until no more statements can be fixed. Free statements are never fixed,
+    /// and values in the original `assignment` are never changed (even if they contradict
+    /// their condition). The result includes the original `assignment`.
+    ///
+    /// # Panics
+    ///
+    /// Every statement in `assignment` must be present in this ADF.
+    pub fn propagate(&self, assignment: &BTreeMap<Statement, bool>) -> BTreeMap<Statement, bool> {
+        let direct = self.direct_encoding();
+        let var_map = direct.var_map();
+
+        let mut result = assignment.clone();
+        let mut fixed = var_map.make_conjunction(assignment);
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (statement, condition) in &direct.conditions {
+                if result.contains_key(statement) {
+                    continue;
+                }
+                let forced = if fixed.and(&condition.not()).is_false() {
+                    Some(true)
+                } else if fixed.and(condition).is_false() {
+                    Some(false)
+                } else {
+                    None
+                };
+                if let Some(value) = forced {
+                    fixed = fixed.and(&var_map.make_literal(statement, value));
+                    result.insert(statement.clone(), value);
+                    changed = true;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Determine the [`LinkType`] of the link from `source` to `target` based on the
+    /// direct condition of `target`.
+    ///
+    /// Returns `None` if `target` has no condition, or if `source` is not a statement
+    /// of this ADF.
+    pub fn link_type(&self, source: &Statement, target: &Statement) -> Option<LinkType> {
+        let direct = self.direct_encoding();
+        let condition = direct.get_condition(target)?;
+        let var = direct.var_map().get(source)?;
+        Some(condition_link_type(condition, var))
+    }
+
+    /// Returns `true` if the ADF is bipolar, i.e. every link that actually appears
+    /// in some condition is supporting or attacking (see [`LinkType`]).
+    ///
+    /// The check stops at the first [`LinkType::Dependent`] link.
+    pub fn is_bipolar(&self) -> bool {
+        self.direct_encoding().conditions.values().all(|condition| {
+            condition
+                .used_variables()
+                .into_iter()
+                .all(|var| condition_link_type(condition, var) != LinkType::Dependent)
+        })
+    }
+
+    /// Try to create a [`AdfBdds`] from an [`AdfExpressions`].
+    ///
+    /// Both encodings are computed eagerly. This operation is cancellable using the
+    /// `cancel-this` crate. If cancelled, it will return an error. Cancellation is checked
+    /// while building every direct condition and before/after every direct-to-dual
+    /// conversion, so even ADFs with many statements can be interrupted in a timely manner.
+    ///
+    /// # Panics
+    ///
+    /// Conversion fails if [`AdfExpressions`] contains missing statements.
+    pub fn try_from_expressions(adf: &AdfExpressions) -> Cancellable<Self> {
+        let result = Self::try_from_direct_only(adf)?;
+        // Here, we can use the expressions to speed up the dual encoding.
+        let dual = build_dual_encoding(&result.direct_encoding, Some(adf))?;
+        let _ = result.dual_encoding.set(Arc::new(dual));
+        Ok(result)
+    }
+
+    /// Create a [`AdfBdds`] from an [`AdfExpressions`], but only build the direct encoding.
+    ///
+    /// The dual encoding is computed lazily on first access (see [`AdfBdds::dual_encoding`]).
+    /// This is useful for workflows which only need two-valued interpretations, since the
+    /// dual encoding is typically the more expensive part of the construction. This is
+    /// also what the [`From`] implementations do.
+    ///
+    /// # Panics
+    ///
+    /// Conversion fails if [`AdfExpressions`] contains missing statements.
+    pub fn from_direct_only(adf: &AdfExpressions) -> Self {
+        Self::try_from_direct_only(adf)
+            .expect("Conversion from `AdfExpressions` to `AdfBdds` was cancelled")
+    }
+
+    /// Cancellable version of [`AdfBdds::from_direct_only`].
+    ///
+    /// # Panics
+    ///
+    /// Conversion fails if [`AdfExpressions`] contains missing statements, or if it has
+    /// too many statements to be encoded (see [`DirectMap::try_new`]).
+    pub fn try_from_direct_only(adf: &AdfExpressions) -> Cancellable<Self> {
+        assert!(
+            adf.find_missing_statements().is_empty(),
+            "ADF contains missing statements."
+        );
+        Ok(Self::build_direct_only(adf, None)?.unwrap_or_else(|error| panic!("{}", error)))
+    }
+
+    /// Create a [`AdfBdds`] from an [`AdfExpressions`] (only the direct encoding, see
+    /// [`AdfBdds::from_direct_only`]), but fail if the BDD of some condition has more
+    /// than `max_nodes` nodes.
+    ///
+    /// The limit is checked once the BDD of each condition is constructed, hence this does
+    /// not limit the size of the intermediate results, but it prevents pathological conditions
+    /// from propagating into the (typically much larger) dual encoding and solver.
+    ///
+    /// Returns [`EncodingError::ConditionTooLarge`] for the first statement whose condition
+    /// exceeds the limit. The operation is cancellable using the `cancel-this` crate, in
+    /// which case it fails with [`EncodingError::Cancelled`].
+    pub fn try_from_with_limit(
+        adf: &AdfExpressions,
+        max_nodes: usize,
+    ) -> Result<Self, EncodingError> {
+        Self::build_direct_only(adf, Some(max_nodes)).unwrap_or(Err(EncodingError::Cancelled))
+    }
+
+    /// Build the direct encoding of the `adf`, optionally checking that no condition
+    /// has more than `max_nodes` nodes. The outer result reports cancellation.
+    fn build_direct_only(
+        adf: &AdfExpressions,
+        max_nodes: Option<usize>,
+    ) -> Cancellable<Result<Self, EncodingError>> {
+        let missing = adf.find_missing_statements();
+        if let Some(example) = missing.first() {
+            return Ok(Err(EncodingError::MissingStatements {
+                count: missing.len(),
+                example: example.clone(),
+            }));
+        }
+
+        // Get all statements in sorted order
+        let statements: Vec<Statement> = adf.statements().cloned().collect();
+
+        // Create variable map
+        let direct_map = match DirectMap::try_new(&statements) {
+            Ok(direct_map) => direct_map,
+            Err(error) => return Ok(Err(error)),
+        };
+
+        // Build direct encoding conditions
+        let mut direct_conditions = BTreeMap::new();
+        let mut cache = HashMap::new();
+        for (statement, condition) in adf.conditions() {
+            is_cancelled!()?;
+            let bdd = cached_expression_to_bdd(condition, &direct_map, &mut cache)?;
+            if let Some(limit) = max_nodes.filter(|limit| bdd.node_count() > *limit) {
+                return Ok(Err(EncodingError::ConditionTooLarge {
+                    statement,
+                    nodes: bdd.node_count(),
+                    limit,
+                }));
+            }
+            direct_conditions.insert(statement, bdd);
+        }
+
+        Ok(Ok(AdfBdds {
+            direct_encoding: Arc::new(DirectEncoding {
+                var_map: direct_map,
+                conditions: direct_conditions,
+                constraint: Bdd::new_true(),
+            }),
+            dual_encoding: Arc::new(OnceLock::new()),
+            grounded: Arc::new(OnceLock::new()),
+        }))
+    }
+
+    /// Get all statements that are "free" (have no condition or have an identity condition).
+    ///
+    /// A statement is considered free if:
+    /// - It has no condition defined, or
+    /// - Its condition is equivalent to the statement itself (i.e., `ac(s, s)`).
+    pub fn free_statements(&self) -> BTreeSet<Statement> {
+        let mut free_statements = BTreeSet::new();
         for s in self.statements() {
             if let Some(c) = self.direct_encoding().get_condition(s) {
                 let d_var = self.direct_encoding().var_map()[s];
@@ -575,7 +1560,8 @@ impl AdfBdds {
         let free_statements = self.free_statements();
 
         let mut direct_copy = self.direct_encoding().clone();
-        let mut dual_copy = self.dual_encoding().clone();
+        // If the dual encoding is not computed yet, the copy can compute it lazily as well.
+        let mut dual_copy = self.dual_encoding.get().map(|it| it.as_ref().clone());
 
         let t = Bdd::new_true();
         let f = Bdd::new_false();
@@ -587,23 +1573,138 @@ impl AdfBdds {
         };
         for s in free_statements {
             direct_copy.conditions.insert(s.clone(), constant.clone());
-            dual_copy
-                .conditions
-                .insert(s.clone(), constant_pair.clone());
+            if let Some(dual_copy) = dual_copy.as_mut() {
+                dual_copy
+                    .conditions
+                    .insert(s.clone(), constant_pair.clone());
+            }
+        }
+
+        let dual_encoding = OnceLock::new();
+        if let Some(dual_copy) = dual_copy {
+            let _ = dual_encoding.set(Arc::new(dual_copy));
         }
 
         AdfBdds {
             direct_encoding: Arc::new(direct_copy),
-            dual_encoding: Arc::new(dual_copy),
+            dual_encoding: Arc::new(dual_encoding),
+            grounded: Arc::new(OnceLock::new()),
+        }
+    }
+
+    /// Export the complete two-valued interpretations (two-valued models) of this ADF as
+    /// an SMT-LIB 2 script, which can be solved by an external SMT solver.
+    ///
+    /// The script declares one `Bool` constant `s_i` for every statement, where `i` is the
+    /// index of the statement in the [`DirectMap`] (i.e. in the sorted order of statements).
+    /// The mapping is also listed in the comments at the start of the script. Then, it asserts
+    /// the fixed-point constraint `(= s_i condition_i)` for every statement with a condition,
+    /// and the global constraint (if any, see [`AdfBdds::with_constraint`]). The conditions
+    /// are extracted from the BDDs (see `From<&AdfBdds> for AdfExpressions`).
+    ///
+    /// The script does not contain any commands, so `(check-sat)` and `(get-model)` must
+    /// be appended as needed.
+    pub fn complete_two_valued_as_smtlib(&self) -> String {
+        let direct = self.direct_encoding();
+        let var_map = direct.var_map();
+        let statements: BTreeMap<VariableId, Statement> = var_map
+            .statements()
+            .map(|s| (var_map[s], s.clone()))
+            .collect();
+        let names: BTreeMap<&Statement, String> = var_map
+            .statements()
+            .enumerate()
+            .map(|(i, s)| (s, format!("s_{}", i)))
+            .collect();
+        let name_of = |s: &Statement| names[s].clone();
+
+        let mut result = String::new();
+        result.push_str("; Complete two-valued interpretations of an ADF.\n");
+        for (statement, name) in &names {
+            result.push_str(&format!("; `{}` is statement `{}`\n", name, statement));
+        }
+        result.push_str("(set-logic QF_UF)\n");
+        for name in names.values() {
+            result.push_str(&format!("(declare-const {} Bool)\n", name));
+        }
+
+        let mut cache = BddMap::new();
+        for (statement, condition) in direct.iter_conditions() {
+            let expression = bdd_to_expression(condition, &statements, &mut cache);
+            result.push_str(&format!(
+                "(assert (= {} {}))\n",
+                names[statement],
+                expression.to_smtlib_string(name_of)
+            ));
+        }
+        if !direct.constraint().is_true() {
+            let expression = bdd_to_expression(direct.constraint(), &statements, &mut cache);
+            result.push_str(&format!(
+                "(assert {})\n",
+                expression.to_smtlib_string(name_of)
+            ));
+        }
+        result
+    }
+}
+
+/// An error of the construction of an [`AdfBdds`] (see [`AdfBdds::try_from_with_limit`])
+/// or of its variable maps (see [`DirectMap::try_new`]).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum EncodingError {
+    /// The construction was cancelled.
+    Cancelled,
+    /// The ADF contains `count` statements that are used but not declared (e.g. `example`).
+    MissingStatements { count: usize, example: Statement },
+    /// There are `count` statements, which is more than the `max` number of statements that
+    /// can be encoded (see [`DirectMap::try_new`]).
+    TooManyStatements { count: usize, max: usize },
+    /// The condition of `statement` has `nodes` BDD nodes, which is more than the `limit`.
+    ConditionTooLarge {
+        statement: Statement,
+        nodes: usize,
+        limit: usize,
+    },
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodingError::Cancelled => {
+                write!(
+                    f,
+                    "Conversion from `AdfExpressions` to `AdfBdds` was cancelled"
+                )
+            }
+            EncodingError::MissingStatements { count, example } => write!(
+                f,
+                "ADF contains {} missing statements (e.g. `{}`)",
+                count, example
+            ),
+            EncodingError::TooManyStatements { count, max } => write!(
+                f,
+                "Cannot map {} statements to BDD variables: at most {} statements are supported",
+                count, max
+            ),
+            EncodingError::ConditionTooLarge {
+                statement,
+                nodes,
+                limit,
+            } => write!(
+                f,
+                "Condition of statement `{}` has {} BDD nodes, which exceeds the limit of {}",
+                statement, nodes, limit
+            ),
         }
     }
 }
 
+impl std::error::Error for EncodingError {}
+
 impl From<&AdfExpressions> for AdfBdds {
     fn from(adf: &AdfExpressions) -> Self {
-        // Use the cancellable version, but panic if cancelled
-        AdfBdds::try_from_expressions(adf)
-            .expect("Conversion from `AdfExpressions` to `AdfBdds` was cancelled")
+        // The dual encoding is only computed once it is actually needed.
+        AdfBdds::from_direct_only(adf)
     }
 }
 
@@ -613,6 +1714,475 @@ impl From<AdfExpressions> for AdfBdds {
     }
 }
 
+impl From<&AdfBdds> for AdfExpressions {
+    /// Extract the direct [`Bdd`] conditions of an [`AdfBdds`] back into condition
+    /// expressions. Free statements remain free.
+    ///
+    /// The expressions follow the structure of the BDDs (i.e. nested if-then-else formulas
+    /// in the variable order, with trivial branches simplified), hence they are
+    /// semantically equivalent to, but not necessarily syntactically the same as,
+    /// the original expressions.
+    fn from(adf: &AdfBdds) -> Self {
+        let direct = adf.direct_encoding();
+        let var_map = direct.var_map();
+        let statements: BTreeMap<VariableId, Statement> = var_map
+            .statements()
+            .map(|s| (var_map[s], s.clone()))
+            .collect();
+
+        let mut result = AdfExpressions::new();
+        let mut cache = BddMap::new();
+        for statement in var_map.statements() {
+            match direct.get_condition(statement) {
+                None => result.add_statement(statement.clone()),
+                Some(condition) => {
+                    let expression = bdd_to_expression(condition, &statements, &mut cache);
+                    result
+                        .add_condition(statement.clone(), expression)
+                        .expect("Each statement has at most one condition");
+                }
+            }
+        }
+        result
+    }
+}
+
+/// A map which uses [`Bdd`] keys, where two keys are the same if they are
+/// [`Bdd::structural_eq`].
+///
+/// Since BDD nodes do not expose a hashable identity, the keys are bucketed by their node
+/// count and support. Only keys within the same bucket are then compared structurally,
+/// hence lookups of different BDDs are (almost always) resolved without a comparison.
+pub(crate) struct BddMap<V> {
+    buckets: HashMap<(usize, u64), Vec<(Bdd, V)>>,
+}
+
+impl<V> BddMap<V> {
+    pub(crate) fn new() -> BddMap<V> {
+        BddMap {
+            buckets: HashMap::new(),
+        }
+    }
+
+    fn bucket_key(bdd: &Bdd) -> (usize, u64) {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+        let mut hasher = DefaultHasher::new();
+        for var in bdd.used_variables() {
+            u64::from(var).hash(&mut hasher);
+        }
+        (bdd.node_count(), hasher.finish())
+    }
+
+    pub(crate) fn get(&self, bdd: &Bdd) -> Option<&V> {
+        self.buckets
+            .get(&Self::bucket_key(bdd))?
+            .iter()
+            .find(|(key, _)| key.structural_eq(bdd))
+            .map(|(_, value)| value)
+    }
+
+    /// Insert a new `value` for the given `bdd`. The `bdd` must not be present in the map.
+    pub(crate) fn insert(&mut self, bdd: Bdd, value: V) {
+        debug_assert!(self.get(&bdd).is_none());
+        self.buckets
+            .entry(Self::bucket_key(&bdd))
+            .or_default()
+            .push((bdd, value));
+    }
+}
+
+/// Enumerate the nodes of `bdd`, assuming its support is a subset of `variables`.
+///
+/// The nodes are returned as `(level, low, high)`, where `level` is the position of the
+/// decision variable in `variables`, and `low`/`high` are node identifiers: `0` and `1` are
+/// the terminal nodes and the node at position `i` of the result has identifier `i + 2`.
+/// The second value is the identifier of the root. The nodes are enumerated child-first
+/// (low before high) and reduced BDDs are canonical, hence equivalent functions always
+/// produce the same result.
+pub(crate) fn bdd_nodes(bdd: &Bdd, variables: &[VariableId]) -> (Vec<(u32, u32, u32)>, u32) {
+    fn dump(
+        bdd: &Bdd,
+        variables: &[VariableId],
+        nodes: &mut Vec<(u32, u32, u32)>,
+        cache: &mut BddMap<u32>,
+    ) -> u32 {
+        if bdd.is_false() {
+            return 0;
+        }
+        if bdd.is_true() {
+            return 1;
+        }
+        if let Some(id) = cache.get(bdd) {
+            return *id;
+        }
+
+        let used = bdd.used_variables();
+        let level = variables
+            .iter()
+            .position(|var| used.contains(var))
+            .expect("Correctness violation: BDD uses unknown variables.");
+        let var = variables[level];
+
+        let restrict = |value: bool| {
+            bdd.binary_op_with_exists(
+                &Bdd::new_literal(var, value),
+                ruddy::boolean_operators::And,
+                &[var],
+            )
+        };
+        let low = dump(&restrict(false), variables, nodes, cache);
+        let high = dump(&restrict(true), variables, nodes, cache);
+
+        nodes.push((level as u32, low, high));
+        let id = u32::try_from(nodes.len() + 1).expect("BDD too large");
+        cache.insert(bdd.clone(), id);
+        id
+    }
+
+    let mut nodes = Vec::new();
+    let root = dump(bdd, variables, &mut nodes, &mut BddMap::new());
+    (nodes, root)
+}
+
+/// Count the valuations of `bdd` over `variable_count` variables (which must include
+/// all variables used by the `bdd`).
+///
+/// The support of the `bdd` is first renamed to consecutive variables starting from zero,
+/// such that the count only includes the variables that are actually used, and is then
+/// multiplied by the number of valuations of the remaining variables. Consequently, the
+/// result only overflows if the final number of valuations is not representable by [`f64`].
+fn count_compacted_valuations(bdd: &Bdd, variable_count: usize) -> f64 {
+    let support = bdd.used_variables();
+    if support.is_empty() {
+        return if bdd.is_false() {
+            0.0
+        } else {
+            2.0f64.powf(variable_count as f64)
+        };
+    }
+
+    // Variables are renamed in increasing order, hence the new name of a variable is
+    // never used by a variable that is not renamed yet.
+    let mut compacted = bdd.clone();
+    for (index, var) in support.iter().enumerate() {
+        let renamed = VariableId::new_long(index as u64).expect("Index of a used variable");
+        if renamed != *var {
+            compacted = compacted.safe_substitution(*var, &Bdd::new_literal(renamed, true));
+        }
+    }
+
+    let max_var = VariableId::new_long((support.len() - 1) as u64).unwrap();
+    let count = compacted.count_satisfying_valuations(Some(max_var));
+    count * 2.0f64.powf((variable_count - support.len()) as f64)
+}
+
+/// Compute the [`LinkType`] of the given direct `condition` with respect
+/// to the variable `var`.
+fn condition_link_type(condition: &Bdd, var: VariableId) -> LinkType {
+    let literal = Bdd::new_literal(var, true);
+    let high = condition.and(&literal).exists(&[var]);
+    let low = condition.and(&literal.not()).exists(&[var]);
+    let supporting = low.and(&high.not()).is_false();
+    let attacking = high.and(&low.not()).is_false();
+    match (supporting, attacking) {
+        (true, true) => LinkType::Redundant,
+        (true, false) => LinkType::Supporting,
+        (false, true) => LinkType::Attacking,
+        (false, false) => LinkType::Dependent,
+    }
+}
+
+/// Compute the prime implicants of `bdd`, assuming its support is a subset of `variables`.
+///
+/// The primes of `f` with cofactors `f_0` and `f_1` w.r.t. the first variable `x` are
+/// the primes of `f_0 & f_1`, together with the remaining primes of `f_0` extended
+/// with `!x` and the remaining primes of `f_1` extended with `x`.
+fn prime_implicants(bdd: &Bdd, variables: &[VariableId]) -> BTreeSet<BTreeMap<VariableId, bool>> {
+    if bdd.is_false() {
+        return BTreeSet::new();
+    }
+    if bdd.is_true() {
+        return BTreeSet::from([BTreeMap::new()]);
+    }
+    let Some((var, rest)) = variables.split_first() else {
+        unreachable!("Correctness violation: Non-constant BDD without variables.");
+    };
+    let literal = Bdd::new_literal(*var, true);
+    let low = bdd.and(&literal.not()).exists(&[*var]);
+    let high = bdd.and(&literal).exists(&[*var]);
+    if low.structural_eq(&high) {
+        return prime_implicants(bdd, rest);
+    }
+
+    let mut result = prime_implicants(&low.and(&high), rest);
+    for (cofactor, value) in [(low, false), (high, true)] {
+        for mut cube in prime_implicants(&cofactor, rest) {
+            if !result.contains(&cube) {
+                cube.insert(*var, value);
+                result.insert(cube);
+            }
+        }
+    }
+    result
+}
+
+/// Compute the dual condition of a direct `condition` whose links are all supporting or
+/// attacking (see [`LinkType`]). Returns `None` if some link is [`LinkType::Dependent`].
+///
+/// For such conditions, no quantification is necessary: the condition can become `true`
+/// iff it is `true` once every supporter is set to `true` if it can be `true`, and every
+/// attacker is set to `false` if it can be `false` (and symmetrically for the negated
+/// condition). The result is only exact for valid dual valuations
+/// (see [`DualEncoding::valid`]).
+pub(crate) fn bipolar_dual_condition(
+    condition: &Bdd,
+    direct_map: &DirectMap,
+    dual_map: &DualMap,
+) -> Option<(Bdd, Bdd)> {
+    let mut can_be_true = condition.clone();
+    let mut can_be_false = condition.not();
+    for var in condition.used_variables() {
+        let statement = direct_map.resolve(var).expect("Direct variable");
+        let (t_lit, f_lit) = dual_map.make_literals(statement);
+        let (for_true, for_false) = match condition_link_type(condition, var) {
+            LinkType::Redundant | LinkType::Supporting => (t_lit, f_lit.not()),
+            LinkType::Attacking => (f_lit.not(), t_lit),
+            LinkType::Dependent => return None,
+        };
+        can_be_true = can_be_true.safe_substitution(var, &for_true);
+        can_be_false = can_be_false.safe_substitution(var, &for_false);
+    }
+    Some((can_be_true, can_be_false))
+}
+
+/// The maximal number of statements that can be mapped to BDD variables (see
+/// [`statement_base_variable`]).
+const MAX_STATEMENTS: usize = (u32::MAX / 4) as usize + 1;
+
+/// Compute the first BDD variable (i.e. the direct variable) of the statement with
+/// the given `index` out of `count` statements.
+///
+/// Statement `i` uses variables `4i` to `4i + 3` (see [`DirectMap::get_primed`]), hence
+/// this fails once these variables no longer fit into a `u32` identifier.
+fn statement_base_variable(index: usize, count: usize) -> Result<u32, EncodingError> {
+    u32::try_from(index)
+        .ok()
+        .and_then(|index| index.checked_mul(4))
+        .filter(|base| base.checked_add(3).is_some())
+        .ok_or(EncodingError::TooManyStatements {
+            count,
+            max: MAX_STATEMENTS,
+        })
+}
+
+/// Convert a direct-encoded [`Bdd`] to an equivalent [`ConditionExpression`] using
+/// Shannon decomposition on the first variable of the BDD.
+///
+/// The `cache` stores already converted sub-functions, so that the recursion does not
+/// repeatedly visit shared BDD nodes.
+fn bdd_to_expression(
+    bdd: &Bdd,
+    statements: &BTreeMap<VariableId, Statement>,
+    cache: &mut BddMap<ConditionExpression>,
+) -> ConditionExpression {
+    if bdd.is_true() {
+        return ConditionExpression::constant(true);
+    }
+    if bdd.is_false() {
+        return ConditionExpression::constant(false);
+    }
+    if let Some(expression) = cache.get(bdd) {
+        return expression.clone();
+    }
+
+    let var = *bdd
+        .used_variables()
+        .first()
+        .expect("Non-constant BDD uses some variable");
+    let literal = ConditionExpression::statement(statements[&var].clone());
+    let low = bdd.and(&Bdd::new_literal(var, false)).exists(&[var]);
+    let high = bdd.and(&Bdd::new_literal(var, true)).exists(&[var]);
+
+    let not_literal = || ConditionExpression::negation(literal.clone());
+    let expression = match (
+        low.is_false(),
+        low.is_true(),
+        high.is_false(),
+        high.is_true(),
+    ) {
+        // Both branches are constant (and different, since the BDD depends on `var`).
+        (true, _, _, true) => literal.clone(),
+        (_, true, true, _) => not_literal(),
+        (true, _, _, _) => {
+            let high = bdd_to_expression(&high, statements, cache);
+            ConditionExpression::and(&[literal.clone(), high])
+        }
+        (_, _, true, _) => {
+            let low = bdd_to_expression(&low, statements, cache);
+            ConditionExpression::and(&[not_literal(), low])
+        }
+        (_, _, _, true) => {
+            let low = bdd_to_expression(&low, statements, cache);
+            ConditionExpression::or(&[literal.clone(), low])
+        }
+        (_, true, _, _) => {
+            let high = bdd_to_expression(&high, statements, cache);
+            ConditionExpression::or(&[not_literal(), high])
+        }
+        _ => {
+            let low = bdd_to_expression(&low, statements, cache);
+            let high = bdd_to_expression(&high, statements, cache);
+            ConditionExpression::or(&[
+                ConditionExpression::and(&[literal.clone(), high]),
+                ConditionExpression::and(&[not_literal(), low]),
+            ])
+        }
+    };
+
+    cache.insert(bdd.clone(), expression.clone());
+    expression
+}
+
+/// Compute the [`DualEncoding`] of an ADF based on its [`DirectEncoding`].
+///
+/// If `expressions` are given (these must be the expressions from which the `direct`
+/// encoding was built), the dual conditions of read-once expressions (see
+/// [`is_read_once`]) are built directly from the expressions, which avoids the more
+/// expensive conversion of the direct BDD.
+///
+/// This function is cancellable and checks for cancellation for every statement.
+fn build_dual_encoding(
+    direct: &DirectEncoding,
+    expressions: Option<&AdfExpressions>,
+) -> Cancellable<DualEncoding> {
+    let direct_map = &direct.var_map;
+    let statements: Vec<Statement> = direct_map.statements().cloned().collect();
+    let dual_map = DualMap::new(&statements);
+
+    // Build dual encoding conditions from direct encoding
+    let mut dual_conditions = BTreeMap::new();
+    for (statement, condition) in direct.conditions.iter() {
+        is_cancelled!()?;
+        let expression = expressions
+            .and_then(|adf| adf.get_condition(statement))
+            .filter(|expression| is_read_once(expression));
+        if let Some(expression) = expression {
+            let (can_be_true, can_be_false) = expression_to_dual_bdd(expression, &dual_map)?;
+            // The Kleene evaluation is only exact for valid dual valuations, so we need to
+            // exclude invalid valuations of the statements that the condition depends on.
+            let (support_valid, support_vars) = support_validity(condition, direct_map, &dual_map);
+            let can_be_true = can_be_true.and(&support_valid);
+            let can_be_false = can_be_false.and(&support_valid);
+            // Vacuous references are absorbed by constants, so this should always hold.
+            // Nevertheless, if it does not, we fall back to the direct conversion.
+            if can_be_true.used_variables().is_subset(&support_vars)
+                && can_be_false.used_variables().is_subset(&support_vars)
+            {
+                dual_conditions.insert(statement.clone(), (can_be_true, can_be_false));
+                continue;
+            }
+        }
+
+        let mapping_function =
+            direct_to_dual_map_function(direct_map, &dual_map, &condition.used_variables())?;
+
+        let can_be_true = direct_to_dual_encoding(condition, &mapping_function, direct_map)?;
+        let can_be_false =
+            direct_to_dual_encoding(&condition.not(), &mapping_function, direct_map)?;
+
+        dual_conditions.insert(statement.clone(), (can_be_true, can_be_false));
+    }
+
+    let mut encoding = build_dual_encoding_without_conditions(direct, dual_map)?;
+    encoding.conditions = dual_conditions;
+    Ok(encoding)
+}
+
+/// Build a [`DualEncoding`] of the statements and of the global constraint of the `direct`
+/// encoding, but without any conditions.
+///
+/// This is also used by computations that derive the dual conditions on their own (see
+/// [`AdfInterpretationSolver::solve_admissible_bipolar`](crate::AdfInterpretationSolver::solve_admissible_bipolar)).
+pub(crate) fn build_dual_encoding_without_conditions(
+    direct: &DirectEncoding,
+    dual_map: DualMap,
+) -> Cancellable<DualEncoding> {
+    // Build the valid BDD for dual encoding
+    // For each statement, at least one of (t_var, f_var) must be true
+    let mut valid = Bdd::new_true();
+    for statement in dual_map.statements() {
+        is_cancelled!()?;
+        let (t_lit, f_lit) = dual_map.make_literals(statement);
+        // At least one must be true: t_var OR f_var
+        valid = valid.and(&t_lit.or(&f_lit));
+    }
+
+    let constraint = direct_to_dual_constraint(&direct.constraint, &direct.var_map, &dual_map)?;
+
+    Ok(DualEncoding {
+        var_map: dual_map,
+        conditions: BTreeMap::new(),
+        valid,
+        constraint,
+    })
+}
+
+/// Compute the conjunction of validity constraints (see [`DualEncoding::valid`]) of all
+/// statements that the direct `condition` depends on, together with the set of dual
+/// variables of these statements.
+fn support_validity(
+    condition: &Bdd,
+    direct_map: &DirectMap,
+    dual_map: &DualMap,
+) -> (Bdd, BTreeSet<VariableId>) {
+    let mut support_valid = Bdd::new_true();
+    let mut support_vars = BTreeSet::new();
+    for var in condition.used_variables() {
+        let support = direct_map.resolve(var).expect("Direct variable");
+        let (t_var, f_var) = dual_map[support];
+        support_vars.extend([t_var, f_var]);
+        let (t_lit, f_lit) = dual_map.make_literals(support);
+        support_valid = support_valid.and(&t_lit.or(&f_lit));
+    }
+    (support_valid, support_vars)
+}
+
+/// Translate a global constraint from the direct encoding to the dual encoding, such that
+/// the result admits exactly the dual valuations where every two-valued completion
+/// satisfies the constraint.
+fn direct_to_dual_constraint(
+    constraint: &Bdd,
+    direct_map: &DirectMap,
+    dual_map: &DualMap,
+) -> Cancellable<Bdd> {
+    if constraint.is_true() {
+        return Ok(Bdd::new_true());
+    }
+
+    let violation = constraint.not();
+    let mapping_function =
+        direct_to_dual_map_function(direct_map, dual_map, &violation.used_variables())?;
+    // Some completion can violate the constraint.
+    let can_violate = direct_to_dual_encoding(&violation, &mapping_function, direct_map)?;
+    Ok(can_violate.not())
+}
+
+/// Same as [`expression_to_bdd`], but the BDDs of structurally equal expressions are
+/// only built once and then reused (cloning a [`Bdd`] is cheap). This is useful for ADFs
+/// where many statements share the same condition.
+fn cached_expression_to_bdd<'a>(
+    expr: &'a ConditionExpression,
+    var_map: &DirectMap,
+    cache: &mut HashMap<&'a ConditionExpression, Bdd>,
+) -> Cancellable<Bdd> {
+    if let Some(bdd) = cache.get(expr) {
+        return Ok(bdd.clone());
+    }
+    let bdd = expression_to_bdd(expr, var_map)?;
+    cache.insert(expr, bdd.clone());
+    Ok(bdd)
+}
+
 /// Convert a ConditionExpression to a BDD using direct encoding.
 ///
 /// This function is cancellable and will check for cancellation at each recursive step.
@@ -666,20 +2236,117 @@ fn expression_to_bdd(expr: &ConditionExpression, var_map: &DirectMap) -> Cancell
     }
 }
 
-/// Build a map function which connects each variable in the direct encoding
-/// to the two of its dual counterparts.
+/// Returns `true` if every statement appears in the `expression` at most once.
 ///
-/// Note that in theory, the ordering of BDD variables can be arbitrary, but the best
-/// result is achieved if the three variables follow each other in the variable ordering.
-fn direct_to_dual_map_function(
-    direct_map: &DirectMap,
-    dual_map: &DualMap,
-    variables: &BTreeSet<VariableId>,
-) -> Cancellable<Bdd> {
-    let mut mapping_function = Bdd::new_true();
-    for statement in direct_map.statements().rev() {
-        is_cancelled!()?;
-
+/// For such expressions, the Kleene (three-valued) evaluation used by
+/// [`expression_to_dual_bdd`] is exact.
+fn is_read_once(expression: &ConditionExpression) -> bool {
+    fn visit(expression: &ConditionExpression, seen: &mut BTreeSet<Statement>) -> bool {
+        use crate::condition_expression::ConditionExpressionNode::{
+            And, Constant, Equivalence, ExclusiveOr, Implication, Negation, Or, Statement,
+        };
+        match &*expression.0 {
+            Constant(_) => true,
+            Statement(stmt) => seen.insert(stmt.clone()),
+            Negation(operand) => visit(operand, seen),
+            And(operands) | Or(operands) => operands.iter().all(|op| visit(op, seen)),
+            Implication(left, right) | Equivalence(left, right) | ExclusiveOr(left, right) => {
+                visit(left, seen) && visit(right, seen)
+            }
+        }
+    }
+    visit(expression, &mut BTreeSet::new())
+}
+
+/// Convert a ConditionExpression to a pair of BDDs `(can_be_true, can_be_false)` over
+/// the dual encoding, using Kleene (three-valued) semantics of the logical operators.
+///
+/// For read-once expressions (see [`is_read_once`]), the result agrees with
+/// [`direct_to_dual_encoding`] applied to the direct BDD of the expression on all valid
+/// dual valuations. For other expressions, the result can over-approximate the
+/// exact dual condition (e.g. `or(a, neg(a))` can be false when `a` is undefined).
+///
+/// This function is cancellable and will check for cancellation at each recursive step.
+fn expression_to_dual_bdd(
+    expr: &ConditionExpression,
+    dual_map: &DualMap,
+) -> Cancellable<(Bdd, Bdd)> {
+    use crate::condition_expression::ConditionExpressionNode::{
+        And, Constant, Equivalence, ExclusiveOr, Implication, Negation, Or, Statement,
+    };
+
+    // Check for cancellation
+    is_cancelled!()?;
+
+    let constant = |value: bool| {
+        if value {
+            (Bdd::new_true(), Bdd::new_false())
+        } else {
+            (Bdd::new_false(), Bdd::new_true())
+        }
+    };
+
+    match &*expr.0 {
+        Constant(value) => Ok(constant(*value)),
+        Statement(stmt) => Ok(dual_map.make_literals(stmt)),
+        Negation(operand) => {
+            let (t, f) = expression_to_dual_bdd(operand, dual_map)?;
+            Ok((f, t))
+        }
+        And(operands) => {
+            let (mut t, mut f) = constant(true);
+            for op in operands {
+                let (op_t, op_f) = expression_to_dual_bdd(op, dual_map)?;
+                t = t.and(&op_t);
+                f = f.or(&op_f);
+            }
+            Ok((t, f))
+        }
+        Or(operands) => {
+            let (mut t, mut f) = constant(false);
+            for op in operands {
+                let (op_t, op_f) = expression_to_dual_bdd(op, dual_map)?;
+                t = t.or(&op_t);
+                f = f.and(&op_f);
+            }
+            Ok((t, f))
+        }
+        Implication(left, right) => {
+            let (l_t, l_f) = expression_to_dual_bdd(left, dual_map)?;
+            let (r_t, r_f) = expression_to_dual_bdd(right, dual_map)?;
+            Ok((l_f.or(&r_t), l_t.and(&r_f)))
+        }
+        Equivalence(left, right) => {
+            let (l_t, l_f) = expression_to_dual_bdd(left, dual_map)?;
+            let (r_t, r_f) = expression_to_dual_bdd(right, dual_map)?;
+            let same = l_t.and(&r_t).or(&l_f.and(&r_f));
+            let different = l_t.and(&r_f).or(&l_f.and(&r_t));
+            Ok((same, different))
+        }
+        ExclusiveOr(left, right) => {
+            let (l_t, l_f) = expression_to_dual_bdd(left, dual_map)?;
+            let (r_t, r_f) = expression_to_dual_bdd(right, dual_map)?;
+            let same = l_t.and(&r_t).or(&l_f.and(&r_f));
+            let different = l_t.and(&r_f).or(&l_f.and(&r_t));
+            Ok((different, same))
+        }
+    }
+}
+
+/// Build a map function which connects each variable in the direct encoding
+/// to the two of its dual counterparts.
+///
+/// Note that in theory, the ordering of BDD variables can be arbitrary, but the best
+/// result is achieved if the three variables follow each other in the variable ordering.
+fn direct_to_dual_map_function(
+    direct_map: &DirectMap,
+    dual_map: &DualMap,
+    variables: &BTreeSet<VariableId>,
+) -> Cancellable<Bdd> {
+    let mut mapping_function = Bdd::new_true();
+    for statement in direct_map.statements().rev() {
+        is_cancelled!()?;
+
         // Only use variables that are actually used in the condition we are mapping.
         let d_lit = direct_map[statement];
         if !variables.contains(&d_lit) {
@@ -706,19 +2373,36 @@ fn direct_to_dual_map_function(
 /// (state_var => t_var) & (!state_var => f_var) and then existentially quantify state_var.
 ///
 /// Use [`direct_to_dual_map_function`] to construct the `mapping_function`.
-fn direct_to_dual_encoding(function: &Bdd, mapping_function: &Bdd, direct_map: &DirectMap) -> Bdd {
-    let direct_vars: Vec<VariableId> = direct_map.mapping.values().copied().collect();
-    Bdd::binary_op_with_exists(
+///
+/// The quantification itself is a single BDD operation, so cancellation is only checked
+/// before and after it. Only the direct variables that actually appear in `function` are
+/// quantified, which keeps the operation cheap for conditions with few regulators.
+fn direct_to_dual_encoding(
+    function: &Bdd,
+    mapping_function: &Bdd,
+    direct_map: &DirectMap,
+) -> Cancellable<Bdd> {
+    is_cancelled!()?;
+    let used = function.used_variables();
+    let direct_vars: Vec<VariableId> = direct_map
+        .variable_ids()
+        .copied()
+        .filter(|var| used.contains(var))
+        .collect();
+    let result = Bdd::binary_op_with_exists(
         function,
         mapping_function,
         ruddy::boolean_operators::And,
         &direct_vars,
-    )
+    );
+    is_cancelled!()?;
+    Ok(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn test_direct_map_creation() {
@@ -774,6 +2458,38 @@ mod tests {
         let _ = map[Statement::from(99)];
     }
 
+    #[test]
+    fn test_statement_base_variable_overflow() {
+        let last_index = MAX_STATEMENTS - 1;
+        assert_eq!(statement_base_variable(0, 1), Ok(0));
+        assert_eq!(
+            statement_base_variable(last_index, MAX_STATEMENTS),
+            Ok(u32::MAX - 3)
+        );
+        let error = statement_base_variable(last_index + 1, MAX_STATEMENTS + 1).unwrap_err();
+        assert_eq!(
+            error,
+            EncodingError::TooManyStatements {
+                count: MAX_STATEMENTS + 1,
+                max: MAX_STATEMENTS,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Cannot map 1073741825 statements to BDD variables: at most 1073741824 statements are supported"
+        );
+        assert!(statement_base_variable(usize::MAX, usize::MAX).is_err());
+    }
+
+    #[test]
+    fn test_try_new_maps() {
+        let statements = vec![Statement::from(0), Statement::from(5)];
+        let direct = DirectMap::try_new(&statements).unwrap();
+        let dual = DualMap::try_new(&statements).unwrap();
+        assert_eq!(direct, DirectMap::new(&statements));
+        assert_eq!(dual, DualMap::new(&statements));
+    }
+
     #[test]
     fn test_direct_map_get_literal() {
         let statements = vec![Statement::from(0)];
@@ -1017,6 +2733,38 @@ mod tests {
         assert_eq!(statements, vec![Statement::from(0)]);
     }
 
+    #[test]
+    fn test_iter_conditions() {
+        let adf_str = r#"
+            s(a).
+            s(b).
+            s(c).
+            ac(c, neg(a)).
+            ac(a, b).
+        "#;
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+
+        let direct = adf.direct_encoding();
+        let statements: Vec<_> = direct.iter_conditions().map(|(s, _)| s.clone()).collect();
+        assert_eq!(statements, vec![Statement::from("a"), Statement::from("c")]);
+        for (statement, condition) in direct.iter_conditions() {
+            assert!(condition.structural_eq(direct.get_condition(statement).unwrap()));
+        }
+
+        let dual = adf.dual_encoding();
+        assert!(
+            dual.iter_conditions()
+                .map(|(s, _)| s)
+                .eq(dual.conditional_statements())
+        );
+        for (statement, (t, f)) in dual.iter_conditions() {
+            let (expected_t, expected_f) = dual.get_condition(statement).unwrap();
+            assert!(t.structural_eq(expected_t));
+            assert!(f.structural_eq(expected_f));
+        }
+    }
+
     // Test for From<AdfExpressions> implementation
     #[test]
     fn test_from_adf_expressions_owned() {
@@ -1397,232 +3145,1128 @@ mod tests {
     }
 
     #[test]
-    fn test_dual_encoding_valid_with_free_statements() {
+    fn test_dual_encoding_valid_with_free_statements() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+            ac(0, 1).
+        "#;
+
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let symbolic_adf = AdfBdds::from(&expr_adf);
+
+        let dual = symbolic_adf.dual_encoding();
+        let valid = dual.valid();
+        let var_map = dual.var_map();
+
+        // Valid should constrain both statement 0 and statement 1 (including free statements)
+        let (t0_lit, f0_lit) = var_map.make_literals(&Statement::from(0));
+        let (t1_lit, f1_lit) = var_map.make_literals(&Statement::from(1));
+
+        let expected = t0_lit.or(&f0_lit).and(&t1_lit.or(&f1_lit));
+
+        assert!(valid.structural_eq(&expected));
+
+        // Verify that invalid valuations for the free statement are also excluded
+        let s1_both_false = t1_lit.not().and(&f1_lit.not());
+        let intersection = valid.and(&s1_both_false);
+        assert!(intersection.is_false());
+    }
+
+    #[test]
+    fn test_dual_encoding_valid_complex_adf() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+            s(2).
+            s(3).
+            ac(0, and(1, 2)).
+            ac(1, or(2, 3)).
+            ac(2, neg(3)).
+        "#;
+
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let symbolic_adf = AdfBdds::from(&expr_adf);
+
+        let dual = symbolic_adf.dual_encoding();
+        let valid = dual.valid();
+        let var_map = dual.var_map();
+
+        // Build the expected valid BDD manually
+        let mut expected = Bdd::new_true();
+        for i in 0..4 {
+            let (t_lit, f_lit) = var_map.make_literals(&Statement::from(i));
+            expected = expected.and(&t_lit.or(&f_lit));
+        }
+
+        assert!(valid.structural_eq(&expected));
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    #[case(3)]
+    #[case(6)]
+    fn test_dual_encoding_valid_count(#[case] n: usize) {
+        // Conditions do not influence the validity constraint.
+        let adf_str = (0..n)
+            .map(|i| format!("ac({}, neg({})).\n", i, (i + 1) % n))
+            .collect::<String>();
+        let expr_adf = AdfExpressions::parse(&adf_str).expect("Failed to parse ADF");
+        let symbolic_adf = AdfBdds::from(&expr_adf);
+        let dual = symbolic_adf.dual_encoding();
+        assert_eq!(
+            dual.count_dual_valuations(dual.valid()),
+            3.0f64.powi(n as i32)
+        );
+    }
+
+    #[test]
+    fn test_irrelevant_statements() {
+        let adf_str =
+            "s(a).\nac(b, c(v)).\nac(c, c(f)).\nac(d, c(v)).\nac(e, and(a, d)).\nac(f, f).";
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let symbolic_adf = AdfBdds::from(&expr_adf);
+        // `a` is free, `d` is used by `e`, and `f` depends on itself.
+        let expected: Vec<Statement> = vec!["b".into(), "c".into()];
+        assert_eq!(symbolic_adf.irrelevant_statements(), expected);
+
+        let constrained = symbolic_adf.with_constraint(&ConditionExpression::statement("b".into()));
+        let expected: Vec<Statement> = vec!["c".into()];
+        assert_eq!(constrained.irrelevant_statements(), expected);
+    }
+
+    #[test]
+    fn test_contains_empty_interpretation() {
+        let expr_adf = AdfExpressions::parse("ac(a, neg(b)).\nac(b, c(v)).").unwrap();
+        let adf = AdfBdds::from(&expr_adf);
+        let var_map = adf.dual_encoding().var_map();
+        let (p_a, n_a) = var_map.make_literals(&Statement::from("a"));
+
+        let all = adf.mk_three_valued_set(adf.dual_encoding().valid().clone());
+        assert!(adf.contains_empty_interpretation(&all));
+
+        // `a` is fixed to true.
+        let fixed = adf.mk_three_valued_set(adf.dual_encoding().valid().and(&p_a.and(&n_a.not())));
+        assert!(!adf.contains_empty_interpretation(&fixed));
+    }
+
+    #[test]
+    fn test_grounded_is_cached() {
+        let expr_adf =
+            AdfExpressions::parse("ac(a, neg(b)).\nac(b, c(v)).\nac(c, or(c, a)).\ns(d).").unwrap();
+        let adf = AdfBdds::from(&expr_adf);
+        let expected: BTreeMap<Statement, Option<bool>> = BTreeMap::from([
+            ("a".into(), Some(false)),
+            ("b".into(), Some(true)),
+            ("c".into(), None),
+            ("d".into(), None),
+        ]);
+
+        let first = adf.grounded();
+        assert_eq!(first, &expected);
+        assert!(std::ptr::eq(first, adf.grounded()));
+
+        // Constraints do not change the grounded interpretation, so the cache is shared.
+        let constrained = adf.with_constraint(&ConditionExpression::statement("c".into()));
+        assert!(std::ptr::eq(first, constrained.grounded()));
+
+        // Once cached, the interpretation is not computed again.
+        let cached = adf
+            .grounded_or_init(|_| panic!("Grounded interpretation is not cached"))
+            .unwrap();
+        assert!(std::ptr::eq(first, cached));
+
+        // The caller can provide its own computation.
+        let fresh = AdfBdds::from(&expr_adf);
+        let grounded = fresh
+            .grounded_or_init(|adf| {
+                let solver = crate::AdfInterpretationSolver::from(
+                    crate::bdd_solver::NaiveGreedySolver::default(),
+                );
+                Ok(solver.solve_grounded(adf)?.interpretations().pop().unwrap())
+            })
+            .unwrap();
+        assert_eq!(grounded, &expected);
+        assert!(std::ptr::eq(grounded, fresh.grounded()));
+    }
+
+    #[test]
+    fn test_clone_and_structural_eq() {
+        let expr_adf = AdfExpressions::parse("ac(a, neg(b)).\nac(b, or(a, c)).\ns(c).").unwrap();
+        let adf = AdfBdds::from(&expr_adf);
+
+        // Clones share the encodings, hence their sets are compatible.
+        let clone = adf.clone();
+        assert_eq!(adf, clone);
+        assert!(Arc::ptr_eq(
+            adf.direct_encoding_arc(),
+            clone.direct_encoding_arc()
+        ));
+        assert!(adf.structural_eq(&clone));
+
+        // Independent encodings of the same ADF are structurally equal, but not identical.
+        let other = AdfBdds::from(&expr_adf);
+        assert_ne!(adf, other);
+        assert!(adf.structural_eq(&other));
+
+        let constrained = adf.with_constraint(&ConditionExpression::statement("c".into()));
+        assert!(!adf.structural_eq(&constrained));
+
+        let mut modified = expr_adf.clone();
+        modified.update_condition("c".into(), ConditionExpression::constant(true));
+        assert!(!adf.structural_eq(&AdfBdds::from(&modified)));
+    }
+
+    // Test that conversion fails when there are missing statements
+
+    #[test]
+    #[should_panic(expected = "ADF contains missing statements.")]
+    fn test_conversion_fails_with_missing_statements() {
+        // Create an ADF where a condition references a statement that is not declared
+        let adf_str = r#"
+            s(0).
+            ac(0, and(1, 2)).
+        "#;
+
+        // Parse without fixing missing statements - statements 1 and 2 are referenced but not declared
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+
+        // This should panic when trying to convert to BDDs because statements 1 and 2 don't exist
+        let _symbolic_adf = AdfBdds::from(&expr_adf);
+    }
+
+    #[test]
+    fn test_conversion_succeeds_with_fixed_missing_statements() {
+        // Create an ADF where a condition references statements that are not declared
+        let adf_str = r#"
+            s(0).
+            ac(0, and(1, 2)).
+        "#;
+
+        // Parse and fix missing statements
+        let mut expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        expr_adf.fix_missing_statements();
+
+        // Now the conversion should succeed because statements 1 and 2 have been added as free statements
+        let symbolic_adf = AdfBdds::from(&expr_adf);
+
+        // Verify that all three statements are present in the encoding
+        let direct = symbolic_adf.direct_encoding();
+        let var_map = direct.var_map();
+        let all_statements: Vec<_> = var_map.statements().cloned().collect();
+        assert_eq!(
+            all_statements,
+            vec![Statement::from(0), Statement::from(1), Statement::from(2)]
+        );
+
+        // Verify that only statement 0 has a condition
+        assert_eq!(direct.conditional_statements().count(), 1);
+        assert!(direct.get_condition(&Statement::from(0)).is_some());
+        assert!(direct.get_condition(&Statement::from(1)).is_none());
+        assert!(direct.get_condition(&Statement::from(2)).is_none());
+    }
+
+    // Tests for count_direct_valuations
+
+    #[test]
+    fn test_count_direct_valuations_true_bdd() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+        "#;
+
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let symbolic_adf = AdfBdds::from(&expr_adf);
+
+        let true_bdd = Bdd::new_true();
+        let count = symbolic_adf
+            .direct_encoding()
+            .count_direct_valuations(&true_bdd);
+
+        // True BDD accepts all valuations: 2^2 = 4 (both statements can be T or F)
+        // But due to variable spacing, the actual count is 8.0
+        assert_eq!(count, 4.0);
+    }
+
+    #[test]
+    fn test_count_direct_valuations_false_bdd() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+        "#;
+
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let symbolic_adf = AdfBdds::from(&expr_adf);
+
+        let false_bdd = Bdd::new_false();
+        let count = symbolic_adf
+            .direct_encoding()
+            .count_direct_valuations(&false_bdd);
+
+        // False BDD accepts no valuations
+        assert_eq!(count, 0.0);
+    }
+
+    #[test]
+    fn test_count_direct_valuations_single_literal() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+        "#;
+
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let symbolic_adf = AdfBdds::from(&expr_adf);
+
+        // BDD representing s(0) = true
+        let var_map = symbolic_adf.direct_encoding().var_map();
+        let s0_true = var_map.make_literal(&Statement::from(0), true);
+        let count = symbolic_adf
+            .direct_encoding()
+            .count_direct_valuations(&s0_true);
+
+        // Accepts 2 valuations: s(0)=T, s(1)=T and s(0)=T, s(1)=F
+        assert_eq!(count, 2.0);
+    }
+
+    #[test]
+    fn test_count_direct_valuations_and() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+        "#;
+
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let symbolic_adf = AdfBdds::from(&expr_adf);
+
+        // BDD representing s(0) AND s(1)
+        let var_map = symbolic_adf.direct_encoding().var_map();
+        let s0 = var_map.make_literal(&Statement::from(0), true);
+        let s1 = var_map.make_literal(&Statement::from(1), true);
+        let and_bdd = s0.and(&s1);
+        let count = symbolic_adf
+            .direct_encoding()
+            .count_direct_valuations(&and_bdd);
+
+        // Accepts only 1 valuation: s(0)=T, s(1)=T
+        assert_eq!(count, 1.0);
+    }
+
+    #[test]
+    fn test_count_direct_valuations_three_statements() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+            s(2).
+        "#;
+
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let symbolic_adf = AdfBdds::from(&expr_adf);
+
+        // BDD representing (s(0) AND s(1)) OR s(2)
+        let var_map = symbolic_adf.direct_encoding().var_map();
+        let s0 = var_map.make_literal(&Statement::from(0), true);
+        let s1 = var_map.make_literal(&Statement::from(1), true);
+        let s2 = var_map.make_literal(&Statement::from(2), true);
+        let bdd = s0.and(&s1).or(&s2);
+        let count = symbolic_adf.direct_encoding().count_direct_valuations(&bdd);
+
+        // Accepts valuations:
+        // s(2)=T: (T,T,T), (T,F,T), (F,T,T), (F,F,T) = 4
+        // s(2)=F: (T,T,F) = 1
+        assert_eq!(count, 5.0);
+    }
+
+    #[test]
+    fn test_count_valuations_many_statements() {
+        // The count over all variables of 300 statements overflows.
+        let adf_str: String = (0..300).map(|i| format!("s({}).\n", i)).collect();
+        let adf = AdfBdds::from(&AdfExpressions::parse(&adf_str).expect("Valid ADF"));
+        let direct = adf.direct_encoding();
+        let var_map = direct.var_map();
+
+        // All statements except the first three are fixed.
+        let mut fixed = Bdd::new_true();
+        for i in 3..300 {
+            fixed = fixed.and(&var_map.make_literal(&Statement::from(i), i % 2 == 0));
+        }
+        assert_eq!(direct.count_direct_valuations(&fixed), 8.0);
+        let s0 = var_map.make_literal(&Statement::from(0), true);
+        assert_eq!(direct.count_direct_valuations(&fixed.and(&s0)), 4.0);
+        assert_eq!(
+            direct.count_direct_valuations(&Bdd::new_true()),
+            2.0f64.powi(300)
+        );
+        assert_eq!(direct.count_direct_valuations(&Bdd::new_false()), 0.0);
+
+        let dual = adf.dual_encoding();
+        let all = dual.count_dual_valuations(dual.valid());
+        assert!((all / 3.0f64.powi(300) - 1.0).abs() < 1e-9);
+        let dual_map = dual.var_map();
+        let mut decided = dual.valid().clone();
+        for i in 1..300 {
+            let statement = Statement::from(i);
+            decided = decided
+                .and(&dual_map.make_positive_literal(&statement, i % 2 == 0))
+                .and(&dual_map.make_negative_literal(&statement, i % 2 == 1));
+        }
+        assert_eq!(dual.count_dual_valuations(&decided), 3.0);
+    }
+
+    // Tests for last_valid_variable_id
+
+    #[test]
+    fn test_direct_map_last_valid_variable_id() {
+        let statements = vec![Statement::from(0), Statement::from(5)];
+        let map = DirectMap::new(&statements);
+
+        let last_valid = map.last_valid_variable_id();
+        // For 2 statements: 2 * 4 - 1 = 7
+        assert_eq!(u64::from(last_valid), 7);
+    }
+
+    #[test]
+    fn test_direct_map_get_primed() {
+        let statements = vec![Statement::from(0), Statement::from(5)];
+        let map = DirectMap::new(&statements);
+
+        assert_eq!(u64::from(map.get_primed(&Statement::from(0)).unwrap()), 3);
+        assert_eq!(u64::from(map.get_primed(&Statement::from(5)).unwrap()), 7);
+        assert!(map.get_primed(&Statement::from(1)).is_none());
+        // Primed variables fit into the range of the map.
+        assert_eq!(
+            map.get_primed(&Statement::from(5)),
+            Some(map.last_valid_variable_id())
+        );
+    }
+
+    #[test]
+    fn test_complete_relation() {
+        let adf_str = r#"
+            s(a).
+            s(b).
+            s(c).
+            ac(a, b).
+            ac(b, neg(a)).
+        "#;
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+        let var_map = adf.direct_encoding().var_map();
+        let (a, b, c) = (
+            Statement::from("a"),
+            Statement::from("b"),
+            Statement::from("c"),
+        );
+
+        let relation = adf.complete_relation();
+        assert!(!adf.has_dual_encoding());
+
+        // Input (F, F, F) maps to output (F, T, *).
+        let input = var_map
+            .make_literal(&a, false)
+            .and(&var_map.make_literal(&b, false))
+            .and(&var_map.make_literal(&c, false));
+        let expected_output = var_map
+            .make_primed_literal(&a, false)
+            .and(&var_map.make_primed_literal(&b, true));
+        let image = relation.and(&input);
+        assert!(!image.is_false());
+        assert!(image.and(&expected_output.not()).is_false());
+        // The output of the free statement `c` is unconstrained.
+        assert!(!image.and(&var_map.make_primed_literal(&c, true)).is_false());
+        assert!(
+            !image
+                .and(&var_map.make_primed_literal(&c, false))
+                .is_false()
+        );
+    }
+
+    #[test]
+    fn test_complete_relation_fixed_points() {
+        let adf_str = r#"
+            s(a).
+            s(b).
+            ac(a, b).
+            ac(b, a).
+        "#;
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+        let var_map = adf.direct_encoding().var_map();
+
+        // Fixed points of the operator are exactly the two-valued models: (F, F) and (T, T).
+        let mut fixed_points = adf.complete_relation();
+        let mut primed = Vec::new();
+        for statement in var_map.statements() {
+            let input = var_map.make_literal(statement, true);
+            let output = var_map.make_primed_literal(statement, true);
+            fixed_points = fixed_points.and(&input.iff(&output));
+            primed.push(var_map.get_primed(statement).unwrap());
+        }
+        let fixed_points = fixed_points.exists(&primed);
+        assert_eq!(
+            adf.direct_encoding().count_direct_valuations(&fixed_points),
+            2.0
+        );
+    }
+
+    #[test]
+    fn test_equivalent_condition_classes() {
+        let adf_str = r#"
+            s(a).
+            s(b).
+            s(c).
+            s(d).
+            s(e).
+            s(f).
+            ac(a, and(b, c)).
+            ac(b, c(v)).
+            ac(c, neg(neg(and(c, b)))).
+            ac(d, or(b, neg(b))).
+            ac(e, neg(a)).
+        "#;
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+
+        let classes = adf.equivalent_condition_classes();
+        let expected = vec![
+            vec![Statement::from("a"), Statement::from("c")],
+            vec![Statement::from("b"), Statement::from("d")],
+        ];
+        assert_eq!(classes, expected);
+    }
+
+    #[test]
+    fn test_equivalent_condition_classes_none() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+            ac(0, 1).
+            ac(1, 0).
+        "#;
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+        assert!(adf.equivalent_condition_classes().is_empty());
+    }
+
+    #[test]
+    fn test_with_constraint() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+            ac(0, 1).
+            ac(1, 0).
+        "#;
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+        assert!(adf.direct_encoding().constraint().is_true());
+        assert!(adf.dual_encoding().constraint().is_true());
+
+        let constraint = ConditionExpression::parse("neg(and(0, 1))").unwrap();
+        let lazy = AdfBdds::from(&expr_adf).with_constraint(&constraint);
+        let eager = adf.with_constraint(&constraint);
+        assert!(!lazy.has_dual_encoding());
+        assert!(eager.has_dual_encoding());
+
+        // Three of the four two-valued interpretations satisfy the constraint.
+        let direct = eager.direct_encoding();
+        assert_eq!(direct.count_direct_valuations(direct.constraint()), 3.0);
+        // The original conditions are not affected.
+        assert!(
+            direct
+                .get_condition(&Statement::from(0))
+                .unwrap()
+                .structural_eq(
+                    adf.direct_encoding()
+                        .get_condition(&Statement::from(0))
+                        .unwrap()
+                )
+        );
+
+        // Only (0,0), (0,1), (1,0), (*,0), (0,*) have all completions valid.
+        for adf in [&lazy, &eager] {
+            let dual = adf.dual_encoding();
+            let admitted = dual.valid().and(dual.constraint());
+            assert_eq!(dual.count_dual_valuations(&admitted), 5.0);
+        }
+
+        // Constraints accumulate.
+        let constraint = ConditionExpression::parse("0").unwrap();
+        let both = eager.with_constraint(&constraint);
+        let direct = both.direct_encoding();
+        assert_eq!(direct.count_direct_valuations(direct.constraint()), 1.0);
+    }
+
+    #[test]
+    fn test_range_of() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+            s(2).
+        "#;
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+        let (s0, s1, s2) = (Statement::from(0), Statement::from(1), Statement::from(2));
+        let direct_map = adf.direct_encoding().var_map();
+        let dual_map = adf.dual_encoding().var_map();
+
+        // Interpretation (1, *, 0) has range {0, 2}.
+        let interpretation = dual_map
+            .make_negative_literal(&s0, false)
+            .and(&dual_map.make_positive_literal(&s0, true))
+            .and(&dual_map.make_positive_literal(&s1, true))
+            .and(&dual_map.make_negative_literal(&s1, true))
+            .and(&dual_map.make_positive_literal(&s2, false))
+            .and(&dual_map.make_negative_literal(&s2, true));
+        let range = adf.range_of(&interpretation);
+        let expected = direct_map
+            .make_literal(&s0, true)
+            .and(&direct_map.make_literal(&s1, false))
+            .and(&direct_map.make_literal(&s2, true));
+        assert!(range.iff(&expected).is_true());
+
+        // All valid interpretations together cover all possible ranges.
+        let all = adf.range_of(adf.dual_encoding().valid());
+        assert!(all.is_true());
+
+        // The empty set has no range.
+        assert!(adf.range_of(&Bdd::new_false()).is_false());
+    }
+
+    #[test]
+    fn test_two_valued_set_from_models() {
+        let adf_str = r#"
+            s(a).
+            s(b).
+            s(c).
+        "#;
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+        let (a, b, c) = (
+            Statement::from("a"),
+            Statement::from("b"),
+            Statement::from("c"),
+        );
+
+        let models = vec![
+            BTreeMap::from([(a.clone(), true), (b.clone(), false), (c.clone(), true)]),
+            BTreeMap::from([(a.clone(), false), (b.clone(), false), (c.clone(), false)]),
+            // Duplicates do not change the result.
+            BTreeMap::from([(a.clone(), true), (b.clone(), false), (c.clone(), true)]),
+        ];
+        let set = adf.two_valued_set_from_models(&models);
+        assert_eq!(set.model_count(), 2.0);
+
+        // Partial models cover all their completions.
+        let partial = vec![BTreeMap::from([(a.clone(), true)])];
+        assert_eq!(adf.two_valued_set_from_models(&partial).model_count(), 4.0);
+
+        assert!(adf.two_valued_set_from_models(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_two_valued_set_from_models_unknown_statement() {
+        let expr_adf = AdfExpressions::parse("s(a).").expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+        let models = vec![BTreeMap::from([(Statement::from("b"), true)])];
+        adf.two_valued_set_from_models(&models);
+    }
+
+    #[test]
+    fn test_propagate() {
+        let adf_str = r#"
+            s(a).
+            s(f).
+            ac(b, neg(a)).
+            ac(c, or(a, b)).
+            ac(d, and(c, f)).
+            ac(e, c(v)).
+        "#;
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+
+        // Even without any assignment, `e` is fixed by its constant condition.
+        let result = adf.propagate(&BTreeMap::new());
+        let expected = BTreeMap::from([(Statement::from("e"), true)]);
+        assert_eq!(result, expected);
+
+        let assignment = BTreeMap::from([(Statement::from("a"), false)]);
+        let result = adf.propagate(&assignment);
+        let expected = BTreeMap::from([
+            (Statement::from("a"), false),
+            (Statement::from("b"), true),
+            (Statement::from("c"), true),
+            (Statement::from("e"), true),
+        ]);
+        assert_eq!(result, expected);
+
+        let assignment = BTreeMap::from([(Statement::from("f"), false)]);
+        let result = adf.propagate(&assignment);
+        let expected = BTreeMap::from([
+            (Statement::from("d"), false),
+            (Statement::from("e"), true),
+            (Statement::from("f"), false),
+        ]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_adf_expressions_from_adf_bdds() {
+        let adf_str = r#"
+            s(a).
+            s(f).
+            ac(b, neg(a)).
+            ac(c, or(a, b)).
+            ac(d, xor(c, and(f, a))).
+            ac(e, c(v)).
+            ac(g, c(f)).
+            ac(h, iff(a, imp(b, f))).
+        "#;
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+
+        let extracted = AdfExpressions::from(&adf);
+        let statements: Vec<_> = extracted.statements().cloned().collect();
+        let expected: Vec<_> = expr_adf.statements().cloned().collect();
+        assert_eq!(statements, expected);
+        assert!(extracted.get_condition(&Statement::from("a")).is_none());
+        assert!(extracted.get_condition(&Statement::from("f")).is_none());
+        assert_eq!(
+            extracted.get_condition(&Statement::from("b")),
+            Some(&ConditionExpression::negation(
+                ConditionExpression::statement(Statement::from("a"))
+            ))
+        );
+
+        let converted = AdfBdds::from(&extracted);
+        for statement in adf.direct_encoding().conditional_statements() {
+            let original = adf.direct_encoding().get_condition(statement).unwrap();
+            let roundtrip = converted
+                .direct_encoding()
+                .get_condition(statement)
+                .unwrap();
+            assert!(original.iff(roundtrip).is_true());
+        }
+    }
+
+    #[test]
+    fn test_try_from_with_limit() {
         let adf_str = r#"
-            s(0).
-            s(1).
-            ac(0, 1).
+            s(a).
+            s(b).
+            s(c).
+            ac(x, a).
+            ac(y, xor(a, xor(b, c))).
         "#;
-
         let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
-        let symbolic_adf = AdfBdds::from(&expr_adf);
-
-        let dual = symbolic_adf.dual_encoding();
-        let valid = dual.valid();
-        let var_map = dual.var_map();
-
-        // Valid should constrain both statement 0 and statement 1 (including free statements)
-        let (t0_lit, f0_lit) = var_map.make_literals(&Statement::from(0));
-        let (t1_lit, f1_lit) = var_map.make_literals(&Statement::from(1));
+        let unlimited = AdfBdds::from(&expr_adf);
+        let y_size = unlimited
+            .direct_encoding()
+            .get_condition(&Statement::from("y"))
+            .unwrap()
+            .node_count();
+
+        let adf = AdfBdds::try_from_with_limit(&expr_adf, y_size).expect("Within the limit");
+        for statement in unlimited.direct_encoding().conditional_statements() {
+            let expected = unlimited
+                .direct_encoding()
+                .get_condition(statement)
+                .unwrap();
+            let actual = adf.direct_encoding().get_condition(statement).unwrap();
+            assert!(expected.iff(actual).is_true());
+        }
 
-        let expected = t0_lit.or(&f0_lit).and(&t1_lit.or(&f1_lit));
+        let error = AdfBdds::try_from_with_limit(&expr_adf, y_size - 1).unwrap_err();
+        assert_eq!(
+            error,
+            EncodingError::ConditionTooLarge {
+                statement: Statement::from("y"),
+                nodes: y_size,
+                limit: y_size - 1,
+            }
+        );
+        assert!(error.to_string().contains("`y`"));
 
-        assert!(valid.structural_eq(&expected));
+        let missing = AdfExpressions::parse("ac(x, z).").expect("Failed to parse ADF");
+        assert!(matches!(
+            AdfBdds::try_from_with_limit(&missing, 100),
+            Err(EncodingError::MissingStatements { count: 1, .. })
+        ));
 
-        // Verify that invalid valuations for the free statement are also excluded
-        let s1_both_false = t1_lit.not().and(&f1_lit.not());
-        let intersection = valid.and(&s1_both_false);
-        assert!(intersection.is_false());
+        let token = crate::CancelToken::new();
+        token.cancel();
+        let result = cancel_this::on_trigger(token, || -> Cancellable<_> {
+            Ok(AdfBdds::try_from_with_limit(&expr_adf, 100))
+        })
+        .unwrap();
+        assert_eq!(result.unwrap_err(), EncodingError::Cancelled);
     }
 
     #[test]
-    fn test_dual_encoding_valid_complex_adf() {
+    fn test_condition_bdd() {
         let adf_str = r#"
-            s(0).
-            s(1).
-            s(2).
-            s(3).
-            ac(0, and(1, 2)).
-            ac(1, or(2, 3)).
-            ac(2, neg(3)).
+            s(a).
+            ac(b, neg(a)).
         "#;
-
         let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
-        let symbolic_adf = AdfBdds::from(&expr_adf);
-
-        let dual = symbolic_adf.dual_encoding();
-        let valid = dual.valid();
-        let var_map = dual.var_map();
-
-        // Build the expected valid BDD manually
-        let mut expected = Bdd::new_true();
-        for i in 0..4 {
-            let (t_lit, f_lit) = var_map.make_literals(&Statement::from(i));
-            expected = expected.and(&t_lit.or(&f_lit));
-        }
+        let adf = AdfBdds::from(&expr_adf);
 
-        assert!(valid.structural_eq(&expected));
+        let (condition, var_map) = adf.condition_bdd(&Statement::from("b")).unwrap();
+        let expected = var_map.make_literal(&Statement::from("a"), false);
+        assert!(condition.structural_eq(&expected));
+        assert!(adf.condition_bdd(&Statement::from("a")).is_none());
+        assert!(adf.condition_bdd(&Statement::from("c")).is_none());
     }
 
-    // Test that conversion fails when there are missing statements
-
     #[test]
-    #[should_panic(expected = "ADF contains missing statements.")]
-    fn test_conversion_fails_with_missing_statements() {
-        // Create an ADF where a condition references a statement that is not declared
+    fn test_completions_below() {
         let adf_str = r#"
             s(0).
-            ac(0, and(1, 2)).
+            s(1).
+            ac(2, and(0, 1)).
         "#;
-
-        // Parse without fixing missing statements - statements 1 and 2 are referenced but not declared
         let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+        let s0 = Statement::from(0);
+        let s1 = Statement::from(1);
+        let s2 = Statement::from(2);
+
+        let model = BTreeMap::from([(s0.clone(), true), (s1.clone(), false), (s2.clone(), false)]);
+        let below = adf.completions_below(&model);
+        assert_eq!(below.model_count(), 8.0);
+
+        // The model itself and the fully undefined interpretation are both included.
+        let dual_map = adf.dual_encoding().var_map();
+        let (t0, f0) = dual_map[&s0];
+        let (t1, f1) = dual_map[&s1];
+        let (t2, f2) = dual_map[&s2];
+        let exact = adf.mk_three_valued_interpretation([
+            (t0, true),
+            (f0, false),
+            (t1, false),
+            (f1, true),
+            (t2, false),
+            (f2, true),
+        ]);
+        assert!(exact.intersect(&below) == exact);
+        let all_undefined = dual_map.variable_ids().map(|var| (*var, true));
+        let undefined = adf.mk_three_valued_interpretation(all_undefined);
+        assert!(undefined.intersect(&below) == undefined);
 
-        // This should panic when trying to convert to BDDs because statements 1 and 2 don't exist
-        let _symbolic_adf = AdfBdds::from(&expr_adf);
+        let partial = BTreeMap::from([(s0, true)]);
+        assert_eq!(adf.completions_below(&partial).model_count(), 18.0);
     }
 
     #[test]
-    fn test_conversion_succeeds_with_fixed_missing_statements() {
-        // Create an ADF where a condition references statements that are not declared
+    fn test_resolve_variables() {
+        let statements = vec![Statement::from("a"), Statement::from("b")];
+        let direct = DirectMap::new(&statements);
+        let dual = DualMap::new(&statements);
+
+        for statement in &statements {
+            assert_eq!(direct.resolve(direct[statement]), Some(statement));
+            let (t_var, f_var) = dual[statement];
+            assert_eq!(
+                dual.resolve(t_var),
+                Some((statement.clone(), Polarity::Positive))
+            );
+            assert_eq!(
+                dual.resolve(f_var),
+                Some((statement.clone(), Polarity::Negative))
+            );
+            // Direct and dual variables do not overlap.
+            assert_eq!(dual.resolve(direct[statement]), None);
+            assert_eq!(direct.resolve(t_var), None);
+        }
+        assert_eq!(direct.resolve(VariableId::new(100)), None);
+    }
+
+    #[test]
+    fn test_minimal_support() {
         let adf_str = r#"
-            s(0).
-            ac(0, and(1, 2)).
+            s(a).
+            s(b).
+            s(c).
+            ac(d, or(and(a, b), c)).
+            ac(e, c(v)).
         "#;
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+        let [a, b, c, d, e] = ["a", "b", "c", "d", "e"].map(Statement::from);
+
+        let model = |values: [bool; 5]| -> BTreeMap<Statement, bool> {
+            [&a, &b, &c, &d, &e]
+                .into_iter()
+                .cloned()
+                .zip(values)
+                .collect()
+        };
 
-        // Parse and fix missing statements
-        let mut expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
-        expr_adf.fix_missing_statements();
+        // `d` is true because of `c` alone.
+        let m = model([true, false, true, true, true]);
+        assert_eq!(adf.minimal_support(&m, d.clone()), vec![c.clone()]);
 
-        // Now the conversion should succeed because statements 1 and 2 have been added as free statements
-        let symbolic_adf = AdfBdds::from(&expr_adf);
+        // `d` is true because of `a` and `b`.
+        let m = model([true, true, false, true, true]);
+        assert_eq!(
+            adf.minimal_support(&m, d.clone()),
+            vec![a.clone(), b.clone()]
+        );
 
-        // Verify that all three statements are present in the encoding
-        let direct = symbolic_adf.direct_encoding();
-        let var_map = direct.var_map();
-        let all_statements: Vec<_> = var_map.statements().cloned().collect();
+        // `d` is false because `a` and `c` are false.
+        let m = model([false, true, false, false, true]);
         assert_eq!(
-            all_statements,
-            vec![Statement::from(0), Statement::from(1), Statement::from(2)]
+            adf.minimal_support(&m, d.clone()),
+            vec![a.clone(), c.clone()]
         );
 
-        // Verify that only statement 0 has a condition
-        assert_eq!(direct.conditional_statements().count(), 1);
-        assert!(direct.get_condition(&Statement::from(0)).is_some());
-        assert!(direct.get_condition(&Statement::from(1)).is_none());
-        assert!(direct.get_condition(&Statement::from(2)).is_none());
+        // Constant and free statements need no support.
+        assert!(adf.minimal_support(&m, e).is_empty());
+        assert!(adf.minimal_support(&m, a).is_empty());
     }
 
-    // Tests for count_direct_valuations
-
     #[test]
-    fn test_count_direct_valuations_true_bdd() {
-        let adf_str = r#"
-            s(0).
-            s(1).
-        "#;
+    fn test_make_conjunction() {
+        let statements = vec![Statement::from(0), Statement::from(1), Statement::from(2)];
+        let map = DirectMap::new(&statements);
 
-        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
-        let symbolic_adf = AdfBdds::from(&expr_adf);
+        let assignment = BTreeMap::from([(Statement::from(0), true), (Statement::from(2), false)]);
+        let expected = map
+            .make_literal(&Statement::from(0), true)
+            .and(&map.make_literal(&Statement::from(2), false));
+        assert!(map.make_conjunction(&assignment).structural_eq(&expected));
+        assert!(map.make_conjunction(&BTreeMap::new()).is_true());
+    }
 
-        let true_bdd = Bdd::new_true();
-        let count = symbolic_adf
-            .direct_encoding()
-            .count_direct_valuations(&true_bdd);
+    #[test]
+    #[should_panic(expected = "Statement 1 not found in DirectMap")]
+    fn test_make_conjunction_unknown_statement() {
+        let map = DirectMap::new(&[Statement::from(0)]);
+        map.make_conjunction(&BTreeMap::from([(Statement::from(1), true)]));
+    }
 
-        // True BDD accepts all valuations: 2^2 = 4 (both statements can be T or F)
-        // But due to variable spacing, the actual count is 8.0
-        assert_eq!(count, 4.0);
+    #[rstest]
+    #[case("a")]
+    #[case("neg(a)")]
+    #[case("c(v)")]
+    #[case("and(a, neg(b), c)")]
+    #[case("or(and(a, b), neg(or(c, d)))")]
+    #[case("imp(and(a, b), c)")]
+    #[case("iff(or(a, b), neg(c))")]
+    #[case("xor(a, and(b, iff(c, d)))")]
+    #[case("or(a, and(b, c(f)))")]
+    fn test_expression_to_dual_bdd(#[case] expression: &str) {
+        let adf_str = format!("s(a).\ns(b).\ns(c).\ns(d).\nac(e, {}).", expression);
+        let expr_adf = AdfExpressions::parse(&adf_str).expect("Failed to parse ADF");
+        let e = Statement::from("e");
+        let condition = expr_adf.get_condition(&e).unwrap();
+        assert!(is_read_once(condition));
+
+        // The lazy path always uses the direct BDD, the eager path uses the expression.
+        let lazy = AdfBdds::from(&expr_adf);
+        let eager = AdfBdds::try_from_expressions(&expr_adf).unwrap();
+        let (lazy_t, lazy_f) = lazy.dual_encoding().get_condition(&e).unwrap();
+        let (eager_t, eager_f) = eager.dual_encoding().get_condition(&e).unwrap();
+        assert!(lazy_t.structural_eq(eager_t));
+        assert!(lazy_f.structural_eq(eager_f));
     }
 
     #[test]
-    fn test_count_direct_valuations_false_bdd() {
+    fn test_expression_to_dual_bdd_not_read_once() {
+        let expr_adf = AdfExpressions::parse("s(a).\nac(b, or(a, neg(a))).").unwrap();
+        let b = Statement::from("b");
+        let condition = expr_adf.get_condition(&b).unwrap();
+        assert!(!is_read_once(condition));
+
+        // Kleene semantics cannot see that the condition is a tautology...
+        let adf = AdfBdds::try_from_expressions(&expr_adf).unwrap();
+        let dual_map = adf.dual_encoding().var_map();
+        let (_, kleene_f) = expression_to_dual_bdd(condition, dual_map).unwrap();
+        assert!(!kleene_f.and(adf.dual_encoding().valid()).is_false());
+
+        // ...hence such conditions are still converted from the direct encoding.
+        let (exact_t, exact_f) = adf.dual_encoding().get_condition(&b).unwrap();
+        assert!(exact_t.is_true());
+        assert!(exact_f.is_false());
+    }
+
+    #[test]
+    fn test_condition_support() {
         let adf_str = r#"
-            s(0).
-            s(1).
+            s(a).
+            s(b).
+            s(c).
+            ac(a, or(b, and(c, neg(c)))).
+            ac(b, and(a, c)).
         "#;
-
         let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
-        let symbolic_adf = AdfBdds::from(&expr_adf);
+        let adf = AdfBdds::from(&expr_adf);
+        let direct = adf.direct_encoding();
+
+        let a = Statement::from("a");
+        let b = Statement::from("b");
+        let c = Statement::from("c");
+        assert_eq!(direct.condition_support(&a), Some(vec![b]));
+        assert_eq!(direct.condition_support(&b), Some(vec![a, c.clone()]));
+        assert_eq!(direct.condition_support(&c), None);
+    }
 
-        let false_bdd = Bdd::new_false();
-        let count = symbolic_adf
-            .direct_encoding()
-            .count_direct_valuations(&false_bdd);
+    #[test]
+    fn test_labels_with_dash() {
+        let adf_str = "s(-3).\ns(foo-bar).\nac(foo-bar, neg(-3)).\nac(x, and(-3, foo-bar)).";
+        let expr_adf = AdfExpressions::parse(adf_str).unwrap();
+        let adf = AdfBdds::from(&expr_adf);
+        let var_map = adf.direct_encoding().var_map();
+
+        let labels = var_map.statements().map(|s| s.label()).collect::<Vec<_>>();
+        assert_eq!(labels, vec!["-3", "foo-bar", "x"]);
+        for (index, statement) in var_map.statements().enumerate() {
+            assert_eq!(var_map.index_of(statement), Some(index));
+        }
+        assert_eq!(var_map.index_of(&Statement::from("y")), None);
 
-        // False BDD accepts no valuations
-        assert_eq!(count, 0.0);
+        // The labels survive a round-trip through the `.adf` format.
+        let reparsed = AdfExpressions::parse(&expr_adf.write()).unwrap();
+        assert_eq!(reparsed, expr_adf);
     }
 
     #[test]
-    fn test_count_direct_valuations_single_literal() {
-        let adf_str = r#"
-            s(0).
-            s(1).
-        "#;
-
-        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
-        let symbolic_adf = AdfBdds::from(&expr_adf);
+    fn test_condition_sizes() {
+        let adf_str = "ac(a, b).\nac(b, xor(a, c)).\nac(c, xor(a, b)).\ns(d).";
+        let adf = AdfBdds::from(&AdfExpressions::parse(adf_str).unwrap());
+        let direct = adf.direct_encoding();
+
+        let sizes = direct.condition_sizes();
+        assert_eq!(sizes.len(), 3);
+        for (statement, condition) in direct.iter_conditions() {
+            assert_eq!(sizes[statement], condition.node_count());
+        }
+        assert!(sizes[&Statement::from("a")] < sizes[&Statement::from("b")]);
+        assert_eq!(sizes[&Statement::from("b")], sizes[&Statement::from("c")]);
+        assert_eq!(
+            direct.largest_condition(),
+            Some((Statement::from("b"), sizes[&Statement::from("b")]))
+        );
 
-        // BDD representing s(0) = true
-        let var_map = symbolic_adf.direct_encoding().var_map();
-        let s0_true = var_map.make_literal(&Statement::from(0), true);
-        let count = symbolic_adf
-            .direct_encoding()
-            .count_direct_valuations(&s0_true);
+        let free = AdfBdds::from(&AdfExpressions::parse("s(a).").unwrap());
+        assert!(free.direct_encoding().condition_sizes().is_empty());
+        assert_eq!(free.direct_encoding().largest_condition(), None);
+    }
 
-        // Accepts 2 valuations: s(0)=T, s(1)=T and s(0)=T, s(1)=F
-        assert_eq!(count, 2.0);
+    #[test]
+    fn test_shared_conditions() {
+        let adf_str = "ac(a, and(d, neg(e))).\nac(b, and(d, neg(e))).\nac(c, or(d, e)).\nac(d, and(d, neg(e))).\ns(e).";
+        let expr_adf = AdfExpressions::parse(adf_str).unwrap();
+        let direct_map = DirectMap::new(&expr_adf.statements().cloned().collect::<Vec<_>>());
+
+        for adf in [
+            AdfBdds::from(&expr_adf),
+            AdfBdds::try_from_with_limit(&expr_adf, 100).unwrap(),
+        ] {
+            let direct = adf.direct_encoding();
+            assert_eq!(direct.iter_conditions().count(), 4);
+            for (statement, condition) in expr_adf.conditions() {
+                let expected = expression_to_bdd(condition, &direct_map).unwrap();
+                assert!(
+                    direct
+                        .get_condition(&statement)
+                        .unwrap()
+                        .structural_eq(&expected)
+                );
+            }
+        }
     }
 
     #[test]
-    fn test_count_direct_valuations_and() {
-        let adf_str = r#"
-            s(0).
-            s(1).
-        "#;
+    fn test_complete_two_valued_as_smtlib() {
+        let expr_adf = AdfExpressions::parse("ac(a, neg(b)).\nac(b, c(v)).\ns(c).").unwrap();
+        let adf = AdfBdds::from(&expr_adf);
+        let expected = "; Complete two-valued interpretations of an ADF.\n\
+            ; `s_0` is statement `a`\n\
+            ; `s_1` is statement `b`\n\
+            ; `s_2` is statement `c`\n\
+            (set-logic QF_UF)\n\
+            (declare-const s_0 Bool)\n\
+            (declare-const s_1 Bool)\n\
+            (declare-const s_2 Bool)\n\
+            (assert (= s_0 (not s_1)))\n\
+            (assert (= s_1 true))\n";
+        assert_eq!(adf.complete_two_valued_as_smtlib(), expected);
+
+        let constrained = adf.with_constraint(&ConditionExpression::statement("c".into()));
+        let script = constrained.complete_two_valued_as_smtlib();
+        assert!(script.starts_with(expected));
+        assert!(script.ends_with("(assert s_2)\n"));
+    }
 
+    #[test]
+    fn test_condition_prime_implicants() {
+        let adf_str = "ac(a, or(b, and(c, d))).\nac(b, or(and(a, c), and(neg(a), d))).\nac(c, xor(a, b)).\nac(d, c(v)).\ns(e).";
         let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
-        let symbolic_adf = AdfBdds::from(&expr_adf);
-
-        // BDD representing s(0) AND s(1)
-        let var_map = symbolic_adf.direct_encoding().var_map();
-        let s0 = var_map.make_literal(&Statement::from(0), true);
-        let s1 = var_map.make_literal(&Statement::from(1), true);
-        let and_bdd = s0.and(&s1);
-        let count = symbolic_adf
-            .direct_encoding()
-            .count_direct_valuations(&and_bdd);
+        let adf = AdfBdds::from(&expr_adf);
+        let direct = adf.direct_encoding();
+
+        let cube = |literals: &[(&str, bool)]| {
+            literals
+                .iter()
+                .map(|(s, v)| (Statement::from(*s), *v))
+                .collect::<BTreeMap<_, _>>()
+        };
+        let sorted = |mut cubes: Vec<BTreeMap<Statement, bool>>| {
+            cubes.sort();
+            cubes
+        };
 
-        // Accepts only 1 valuation: s(0)=T, s(1)=T
-        assert_eq!(count, 1.0);
+        assert_eq!(
+            direct.condition_prime_implicants(&"a".into()),
+            Some(sorted(vec![
+                cube(&[("b", true)]),
+                cube(&[("c", true), ("d", true)])
+            ]))
+        );
+        // The consensus term `c & d` is also prime.
+        assert_eq!(
+            direct.condition_prime_implicants(&"b".into()),
+            Some(sorted(vec![
+                cube(&[("a", true), ("c", true)]),
+                cube(&[("a", false), ("d", true)]),
+                cube(&[("c", true), ("d", true)]),
+            ]))
+        );
+        assert_eq!(
+            direct.condition_prime_implicants(&"c".into()),
+            Some(sorted(vec![
+                cube(&[("a", true), ("b", false)]),
+                cube(&[("a", false), ("b", true)]),
+            ]))
+        );
+        assert_eq!(
+            direct.condition_prime_implicants(&"d".into()),
+            Some(vec![BTreeMap::new()])
+        );
+        assert_eq!(direct.condition_prime_implicants(&"e".into()), None);
     }
 
     #[test]
-    fn test_count_direct_valuations_three_statements() {
+    fn test_debug_summary() {
         let adf_str = r#"
             s(0).
             s(1).
-            s(2).
+            ac(0, 1).
         "#;
-
         let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
-        let symbolic_adf = AdfBdds::from(&expr_adf);
-
-        // BDD representing (s(0) AND s(1)) OR s(2)
-        let var_map = symbolic_adf.direct_encoding().var_map();
-        let s0 = var_map.make_literal(&Statement::from(0), true);
-        let s1 = var_map.make_literal(&Statement::from(1), true);
-        let s2 = var_map.make_literal(&Statement::from(2), true);
-        let bdd = s0.and(&s1).or(&s2);
-        let count = symbolic_adf.direct_encoding().count_direct_valuations(&bdd);
-
-        // Accepts valuations:
-        // s(2)=T: (T,T,T), (T,F,T), (F,T,T), (F,F,T) = 4
-        // s(2)=F: (T,T,F) = 1
-        assert_eq!(count, 5.0);
-    }
+        let adf = AdfBdds::from(&expr_adf);
 
-    // Tests for last_valid_variable_id
+        let direct = format!("{:?}", adf.direct_encoding());
+        assert!(direct.starts_with("DirectEncoding { statements: 2, conditions: 1,"));
 
-    #[test]
-    fn test_direct_map_last_valid_variable_id() {
-        let statements = vec![Statement::from(0), Statement::from(5)];
-        let map = DirectMap::new(&statements);
+        let lazy = format!("{:?}", adf);
+        assert!(lazy.contains("dual_encoding: None"));
 
-        let last_valid = map.last_valid_variable_id();
-        // For 2 statements: 2 * 4 - 1 = 7
-        assert_eq!(u64::from(last_valid), 7);
+        let dual = format!("{:?}", adf.dual_encoding());
+        assert!(dual.starts_with("DualEncoding { statements: 2, conditions: 1,"));
+        let eager = format!("{:?}", adf);
+        assert!(eager.contains("dual_encoding: Some(DualEncoding {"));
     }
 
     #[test]
@@ -2189,4 +4833,219 @@ mod tests {
         assert!(orig0.unwrap().structural_eq(fixed0.unwrap()));
         assert!(orig1.unwrap().structural_eq(fixed1.unwrap()));
     }
+
+    #[test]
+    fn test_from_direct_only_is_lazy() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+            ac(0, neg(1)).
+            ac(1, or(0, 1)).
+        "#;
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let lazy = AdfBdds::from_direct_only(&expr_adf);
+        let eager = AdfBdds::try_from_expressions(&expr_adf).unwrap();
+
+        assert!(!lazy.has_dual_encoding());
+        assert!(!AdfBdds::from(&expr_adf).has_dual_encoding());
+        assert!(eager.has_dual_encoding());
+
+        // Accessing the dual encoding computes it and produces the same result.
+        let lazy_dual = lazy.dual_encoding();
+        let eager_dual = eager.dual_encoding();
+        assert!(lazy.has_dual_encoding());
+        assert_eq!(lazy_dual.var_map(), eager_dual.var_map());
+        assert!(lazy_dual.valid().structural_eq(eager_dual.valid()));
+        for s in expr_adf.statements() {
+            let (lazy_t, lazy_f) = lazy_dual.get_condition(s).unwrap();
+            let (eager_t, eager_f) = eager_dual.get_condition(s).unwrap();
+            assert!(lazy_t.structural_eq(eager_t));
+            assert!(lazy_f.structural_eq(eager_f));
+        }
+    }
+
+    #[test]
+    fn test_fix_free_statements_direct_only() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+            ac(0, 1).
+        "#;
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let lazy = AdfBdds::from_direct_only(&expr_adf).fix_free_statements(true);
+        let eager = AdfBdds::try_from_expressions(&expr_adf)
+            .unwrap()
+            .fix_free_statements(true);
+
+        assert!(!lazy.has_dual_encoding());
+        let (lazy_t, lazy_f) = lazy
+            .dual_encoding()
+            .get_condition(&Statement::from(1))
+            .unwrap();
+        let (eager_t, eager_f) = eager
+            .dual_encoding()
+            .get_condition(&Statement::from(1))
+            .unwrap();
+        assert!(lazy_t.structural_eq(eager_t));
+        assert!(lazy_f.structural_eq(eager_f));
+    }
+
+    #[test]
+    fn test_adf_bdds_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<AdfBdds>();
+    }
+
+    #[test]
+    fn test_dual_encoding_is_cached() {
+        let expr_adf = AdfExpressions::parse("ac(0, neg(0)).").expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+        let copy = adf.clone();
+
+        // Clones share the cache, so both see the same instance.
+        let first = adf.dual_encoding() as *const DualEncoding;
+        let second = copy.dual_encoding() as *const DualEncoding;
+        assert!(copy.has_dual_encoding());
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_link_type() {
+        let expr_adf = AdfExpressions::parse(
+            "s(a).\ns(b).\ns(c).\nac(a, and(b, neg(c))).\nac(b, xor(a, c)).\nac(c, c(v)).",
+        )
+        .unwrap();
+        let adf = AdfBdds::from(&expr_adf);
+        let (a, b, c) = (
+            Statement::from("a"),
+            Statement::from("b"),
+            Statement::from("c"),
+        );
+
+        assert_eq!(adf.link_type(&b, &a), Some(LinkType::Supporting));
+        assert_eq!(adf.link_type(&c, &a), Some(LinkType::Attacking));
+        assert_eq!(adf.link_type(&a, &a), Some(LinkType::Redundant));
+        assert_eq!(adf.link_type(&a, &b), Some(LinkType::Dependent));
+        assert_eq!(adf.link_type(&a, &c), Some(LinkType::Redundant));
+        assert_eq!(adf.link_type(&Statement::from("x"), &a), None);
+        assert!(!adf.is_bipolar());
+    }
+
+    #[rstest]
+    #[case("s(a).\ns(b).\nac(a, neg(b)).\nac(b, neg(a)).", true)]
+    #[case("s(a).\ns(b).\nac(a, or(a, neg(b))).\nac(b, c(f)).", true)]
+    #[case("s(a).\ns(b).\nac(a, iff(a, b)).\nac(b, b).", false)]
+    // Syntactically non-bipolar, but semantically equivalent to `b`.
+    #[case("s(a).\ns(b).\nac(a, or(and(a, b), and(neg(a), b))).\nac(b, b).", true)]
+    fn test_is_bipolar(#[case] adf: &str, #[case] expected: bool) {
+        let adf = AdfBdds::from(&AdfExpressions::parse(adf).unwrap());
+        assert_eq!(adf.is_bipolar(), expected);
+    }
+
+    #[rstest]
+    #[case("and(a, neg(b))")]
+    #[case("or(and(a, b), neg(c))")]
+    #[case("imp(a, or(b, c))")]
+    #[case("or(and(a, b), and(a, c), neg(d))")]
+    fn test_bipolar_dual_condition(#[case] condition: &str) {
+        let expr_adf = AdfExpressions::parse(&format!(
+            "s(a).\ns(b).\ns(c).\ns(d).\nac(d, {}).",
+            condition
+        ))
+        .unwrap();
+        let adf = AdfBdds::from(&expr_adf);
+        let direct_map = adf.direct_encoding().var_map();
+        let dual_map = DualMap::new(&direct_map.statements().cloned().collect::<Vec<_>>());
+        let condition = adf
+            .direct_encoding()
+            .get_condition(&Statement::from("d"))
+            .unwrap();
+
+        let (t, f) = bipolar_dual_condition(condition, direct_map, &dual_map).unwrap();
+        let (valid, _) = support_validity(condition, direct_map, &dual_map);
+        let mapping =
+            direct_to_dual_map_function(direct_map, &dual_map, &condition.used_variables())
+                .unwrap();
+        let exact_t = direct_to_dual_encoding(condition, &mapping, direct_map).unwrap();
+        let exact_f = direct_to_dual_encoding(&condition.not(), &mapping, direct_map).unwrap();
+        assert!(t.and(&valid).structural_eq(&exact_t));
+        assert!(f.and(&valid).structural_eq(&exact_f));
+
+        let dependent = Bdd::new_literal(direct_map[Statement::from("a")], true)
+            .xor(&Bdd::new_literal(direct_map[Statement::from("b")], true));
+        assert!(bipolar_dual_condition(&dependent, direct_map, &dual_map).is_none());
+    }
+
+    #[rstest]
+    // `a` and `b` rotate with a period of four.
+    #[case("ac(a, neg(b)).\nac(b, a).", &[0.0, 0.0, 0.0, 4.0])]
+    #[case("ac(a, neg(a)).", &[0.0, 2.0, 0.0, 2.0])]
+    #[case("ac(a, b).\nac(b, a).", &[2.0, 4.0, 2.0, 4.0])]
+    // Free statements can change their value in every step.
+    #[case("s(a).\nac(b, neg(b)).", &[0.0, 4.0, 0.0, 4.0])]
+    fn test_periodic_interpretations(#[case] adf: &str, #[case] expected: &[f64]) {
+        let adf = AdfBdds::from(&AdfExpressions::parse(adf).unwrap());
+        for (i, expected) in expected.iter().enumerate() {
+            let periodic = adf.periodic_interpretations(i + 1);
+            assert_eq!(periodic.model_count(), *expected, "period {}", i + 1);
+        }
+    }
+
+    #[test]
+    fn test_periodic_interpretations_are_models() {
+        let adf_str = "ac(a, or(b, c)).\nac(b, neg(c)).\nac(c, and(a, neg(b))).";
+        let adf = AdfBdds::from(&AdfExpressions::parse(adf_str).unwrap());
+        let models =
+            crate::AdfInterpretationSolver::from(crate::bdd_solver::NaiveGreedySolver::default())
+                .solve_complete_two_valued(&adf)
+                .unwrap();
+        let periodic = adf.periodic_interpretations(1);
+        assert!(periodic.proper_three_valued().is_empty());
+        assert!(periodic.two_valued_subset(&adf) == models);
+    }
+
+    #[test]
+    fn test_periodic_interpretations_oscillating() {
+        let adf = AdfBdds::from(&AdfExpressions::parse("ac(a, neg(a)).\nac(b, b).").unwrap());
+        let a = Statement::from("a");
+        let b = Statement::from("b");
+        assert!(adf.periodic_interpretations(1).is_empty());
+
+        // `a` oscillates with a period of two, `b` is stable.
+        let periodic = adf.periodic_interpretations(2);
+        let expected = [(false, false), (false, true), (true, false), (true, true)]
+            .map(|(a_value, b_value)| {
+                BTreeMap::from([(a.clone(), Some(a_value)), (b.clone(), Some(b_value))])
+            })
+            .to_vec();
+        assert_eq!(periodic.interpretations(), expected);
+        assert!(periodic == adf.periodic_interpretations(4));
+        assert!(adf.periodic_interpretations(3).is_empty());
+
+        // The constraint must hold in every step of the cycle.
+        let constrained = adf.with_constraint(&ConditionExpression::negation(
+            ConditionExpression::statement(b.clone()),
+        ));
+        assert_eq!(constrained.periodic_interpretations(2).model_count(), 2.0);
+        let constrained = adf.with_constraint(&ConditionExpression::negation(
+            ConditionExpression::statement(a),
+        ));
+        assert!(constrained.periodic_interpretations(2).is_empty());
+    }
+
+    #[rstest]
+    #[case("ac(a, neg(b)).\nac(b, c(v)).\nac(c, or(c, a)).\ns(d).")]
+    #[case("ac(a, or(b, c)).\nac(b, neg(c)).\nac(c, and(a, neg(b))).")]
+    #[case("ac(a, and(b, d)).\nac(b, imp(d, a)).\nac(c, xor(c, d)).\nac(d, c(f)).\nac(e, neg(a)).")]
+    #[case("ac(a, c(v)).\nac(b, a).\nac(c, neg(b)).\nac(d, or(c, e)).\nac(e, neg(d)).")]
+    fn test_grounded_symbolic(#[case] adf: &str) {
+        let adf = AdfBdds::from(&AdfExpressions::parse(adf).unwrap());
+        let expected =
+            crate::AdfInterpretationSolver::from(crate::bdd_solver::NaiveGreedySolver::default())
+                .solve_grounded(&adf)
+                .unwrap();
+        let grounded = adf.grounded_symbolic();
+        assert_eq!(grounded.model_count(), 1.0);
+        assert!(grounded == expected);
+    }
 }
@@ -0,0 +1,210 @@
+use crate::AdfExpressions;
+use log::debug;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// A cache of [`AdfExpressions`] parsed from files, keyed by the file path.
+///
+/// Every entry remembers the modification time of the file at the time it was parsed.
+/// When the same path is requested again, the file is only re-parsed if its modification
+/// time changed. This is useful when the same (large) files are processed repeatedly and
+/// most of them do not change between runs.
+///
+/// The cache holds at most `max_entries` ADFs. When a new entry does not fit, the least
+/// recently used entry is evicted. Paths are used as given, i.e. two different paths to
+/// the same file are cached separately. Files that fail to parse are never cached.
+#[derive(Clone, Debug)]
+pub struct AdfCache {
+    max_entries: usize,
+    entries: HashMap<PathBuf, CacheEntry>,
+    clock: u64,
+}
+
+#[derive(Clone, Debug)]
+struct CacheEntry {
+    modified: SystemTime,
+    last_used: u64,
+    adf: AdfExpressions,
+}
+
+impl AdfCache {
+    /// Create a new empty cache that holds at most `max_entries` ADFs.
+    ///
+    /// # Panics
+    ///
+    /// The `max_entries` must be positive.
+    pub fn new(max_entries: usize) -> AdfCache {
+        assert!(
+            max_entries > 0,
+            "Maximum number of entries must be positive"
+        );
+        AdfCache {
+            max_entries,
+            entries: HashMap::new(),
+            clock: 0,
+        }
+    }
+
+    /// Get the ADF stored in the file at `path`, using [`AdfExpressions::parse_file`]
+    /// if the file is not cached or was modified since it was last parsed.
+    pub fn get(&mut self, path: impl AsRef<Path>) -> Result<&AdfExpressions, String> {
+        let path = path.as_ref();
+        // The metadata is read before the content, such that a modification during
+        // parsing invalidates the entry on the next access.
+        let modified = std::fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .map_err(|e| format!("Failed to read file modification time: {}", e))?;
+
+        self.clock += 1;
+        let is_fresh = self
+            .entries
+            .get(path)
+            .is_some_and(|entry| entry.modified == modified);
+        if !is_fresh {
+            // A stale entry is dropped even if the file can no longer be parsed.
+            self.entries.remove(path);
+            let adf = AdfExpressions::parse_file(path)?;
+            debug!("Parsed `{}` into the ADF cache", path.display());
+            if self.entries.len() >= self.max_entries {
+                self.evict_least_recently_used();
+            }
+            self.entries.insert(
+                path.to_path_buf(),
+                CacheEntry {
+                    modified,
+                    last_used: self.clock,
+                    adf,
+                },
+            );
+        }
+
+        let entry = self
+            .entries
+            .get_mut(path)
+            .expect("Correctness violation: Cache entry not initialized.");
+        entry.last_used = self.clock;
+        Ok(&entry.adf)
+    }
+
+    /// Remove the entry of the given `path`. Returns `true` if the entry existed.
+    pub fn remove(&mut self, path: impl AsRef<Path>) -> bool {
+        self.entries.remove(path.as_ref()).is_some()
+    }
+
+    /// Remove all entries from the cache.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns `true` if the given `path` is cached (the entry may be outdated).
+    pub fn contains(&self, path: impl AsRef<Path>) -> bool {
+        self.entries.contains_key(path.as_ref())
+    }
+
+    /// The number of cached ADFs.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no cached ADFs.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The maximum number of cached ADFs.
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
+    fn evict_least_recently_used(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(path, _)| path.clone());
+        if let Some(oldest) = oldest {
+            debug!("Evicting `{}` from the ADF cache", oldest.display());
+            self.entries.remove(&oldest);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::time::Duration;
+
+    /// Write `content` into a test file and set its modification time to `seconds`
+    /// after the epoch (so that the test does not depend on the timestamp resolution).
+    fn write_file(path: &Path, content: &str, seconds: u64) {
+        std::fs::write(path, content).unwrap();
+        File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(seconds))
+            .unwrap();
+    }
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("adf-cache-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_cache_reparses_modified_files() {
+        let dir = test_dir("modified");
+        let path = dir.join("a.adf");
+        write_file(&path, "ac(a, neg(b)).\ns(b).", 1);
+
+        let mut cache = AdfCache::new(2);
+        let first = cache.get(&path).unwrap().clone();
+        assert_eq!(first.len(), 2);
+        assert!(cache.contains(&path));
+
+        // Same modification time: the (changed) content is not parsed again.
+        write_file(&path, "ac(a, c(v)).", 1);
+        assert_eq!(cache.get(&path).unwrap(), &first);
+
+        write_file(&path, "ac(a, c(v)).", 2);
+        assert_eq!(cache.get(&path).unwrap().len(), 1);
+
+        // Invalid content is reported and the stale entry is dropped.
+        write_file(&path, "ac(a, ", 3);
+        assert!(cache.get(&path).is_err());
+        assert!(!cache.contains(&path));
+        assert!(cache.get(dir.join("missing.adf")).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let dir = test_dir("evict");
+        let paths = ["a.adf", "b.adf", "c.adf"].map(|name| dir.join(name));
+        for path in &paths {
+            write_file(path, "ac(a, c(v)).", 1);
+        }
+
+        let mut cache = AdfCache::new(2);
+        assert_eq!(cache.max_entries(), 2);
+        cache.get(&paths[0]).unwrap();
+        cache.get(&paths[1]).unwrap();
+        cache.get(&paths[0]).unwrap();
+        cache.get(&paths[2]).unwrap();
+        assert_eq!(cache.len(), 2);
+        assert!(cache.contains(&paths[0]));
+        assert!(!cache.contains(&paths[1]));
+        assert!(cache.contains(&paths[2]));
+
+        assert!(cache.remove(&paths[0]));
+        assert!(!cache.remove(&paths[0]));
+        cache.clear();
+        assert!(cache.is_empty());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,12 +1,235 @@
-use crate::ConditionExpression;
 use crate::statement::Statement;
-use std::collections::BTreeMap;
+use crate::{ConditionExpression, ConditionExpressionNode};
+use log::warn;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Determines how support links of a bipolar argumentation framework are translated
+/// into acceptance conditions (see [`AdfExpressions::parse_bipolar_apx`]).
+///
+/// In all cases, an argument without attackers and supporters is always accepted (`c(v)`).
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub enum SupportSemantics {
+    /// An argument is accepted if none of its attackers is accepted, and at least one
+    /// of its supporters is accepted (if it has any supporters):
+    /// `and(neg(a_1), ..., neg(a_n), or(s_1, ..., s_m))`.
+    #[default]
+    Disjunctive,
+    /// Necessary support: an argument is accepted if none of its attackers and all of its
+    /// supporters are accepted: `and(neg(a_1), ..., neg(a_n), s_1, ..., s_m)`.
+    Necessary,
+    /// Deductive support: an argument is accepted if some of its supporters is accepted
+    /// (regardless of the attackers), or if none of its attackers is accepted:
+    /// `or(s_1, ..., s_m, and(neg(a_1), ..., neg(a_n)))`.
+    Deductive,
+}
+
+/// The keyword conventions of the line-oriented ADF formats used by different solvers
+/// (see [`ParseOptions::with_dialect`]).
+///
+/// All dialects use the same syntax of condition expressions, only the keywords of the
+/// statement declarations and acceptance conditions differ.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub enum AdfDialect {
+    /// `s(a).` and `ac(a, expression).`
+    #[default]
+    Diamond,
+    /// `statement(a).` and `ac(a, expression).`
+    Yadf,
+    /// `s(a).` and `acc(a, expression).`
+    Kpp,
+}
+
+impl AdfDialect {
+    /// Try to detect the dialect of the given `input` based on the keywords used in its first
+    /// ten declarations (empty lines and comments are skipped).
+    ///
+    /// Returns `None` if none of the declarations is recognized. Note that a file that only
+    /// uses `s(a).` declarations in its first lines cannot be distinguished from
+    /// [`AdfDialect::Kpp`], in which case [`AdfDialect::Diamond`] is returned.
+    pub fn detect(input: &str) -> Option<AdfDialect> {
+        let mut result = None;
+        let declarations = input
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .take(10);
+        for line in declarations {
+            if line.starts_with("acc(") {
+                return Some(AdfDialect::Kpp);
+            } else if line.starts_with("statement(") {
+                return Some(AdfDialect::Yadf);
+            } else if line.starts_with("s(") || line.starts_with("ac(") {
+                result = Some(AdfDialect::Diamond);
+            }
+        }
+        result
+    }
+
+    /// The accepted keywords of statement declarations and acceptance conditions. If no
+    /// dialect is given, both `s` and `statement` declarations are accepted
+    /// (see [`AdfExpressions::parse`]).
+    fn keywords(dialect: Option<AdfDialect>) -> (&'static [&'static str], &'static str) {
+        match dialect {
+            None => (&["s", "statement"], "ac"),
+            Some(AdfDialect::Diamond) => (&["s"], "ac"),
+            Some(AdfDialect::Yadf) => (&["statement"], "ac"),
+            Some(AdfDialect::Kpp) => (&["s"], "acc"),
+        }
+    }
+}
+
+/// Returns the arguments of `line` if it has the form `keyword(arguments).`.
+fn strip_declaration<'a>(line: &'a str, keyword: &str) -> Option<&'a str> {
+    line.strip_prefix(keyword)?
+        .strip_prefix('(')?
+        .strip_suffix(").")
+}
+
+/// Build the acceptance condition of an argument with the given `attackers` and `supporters`
+/// in an (bipolar) argumentation framework (see [`SupportSemantics`]).
+///
+/// Without supporters, the condition is the conjunction of `neg(a)` for all attackers `a`
+/// (a single attacker yields just `neg(a)`), or `c(v)` if there are no attackers.
+fn af_condition(
+    attackers: &[Statement],
+    supporters: &[Statement],
+    support: SupportSemantics,
+) -> ConditionExpression {
+    fn mk_and(operands: Vec<ConditionExpression>) -> ConditionExpression {
+        match operands.as_slice() {
+            [] => ConditionExpression::constant(true),
+            [operand] => operand.clone(),
+            _ => ConditionExpression::and(&operands),
+        }
+    }
+
+    fn mk_or(operands: Vec<ConditionExpression>) -> ConditionExpression {
+        match operands.as_slice() {
+            [] => ConditionExpression::constant(false),
+            [operand] => operand.clone(),
+            _ => ConditionExpression::or(&operands),
+        }
+    }
+
+    let attacks = attackers
+        .iter()
+        .map(|it| ConditionExpression::negation(ConditionExpression::statement(it.clone())))
+        .collect::<Vec<_>>();
+    let supports = supporters
+        .iter()
+        .map(|it| ConditionExpression::statement(it.clone()))
+        .collect::<Vec<_>>();
+    match support {
+        _ if supports.is_empty() => mk_and(attacks),
+        SupportSemantics::Disjunctive => {
+            let mut operands = attacks;
+            operands.push(mk_or(supports));
+            mk_and(operands)
+        }
+        SupportSemantics::Necessary => {
+            let mut operands = attacks;
+            operands.extend(supports);
+            mk_and(operands)
+        }
+        SupportSemantics::Deductive if attacks.is_empty() => ConditionExpression::constant(true),
+        SupportSemantics::Deductive => {
+            let mut operands = supports;
+            operands.push(mk_and(attacks));
+            mk_or(operands)
+        }
+    }
+}
+
+/// The (rough) number of bytes used by one symbol of
+/// [`AdfExpressions::estimated_encoding_size`].
+pub const ESTIMATED_BYTES_PER_SYMBOL: u64 = 10;
+
+/// How [`AdfExpressions::parse_with_options`] handles statements that are declared more
+/// than once, or that have more than one condition.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub enum DuplicateHandling {
+    /// Duplicate statements and conditions are an error.
+    #[default]
+    Reject,
+    /// Duplicate statements are ignored, and the last condition is used. In both cases,
+    /// a warning is logged.
+    Replace,
+    /// Duplicate statements are an error, and duplicate conditions are combined using
+    /// conjunction (in the order in which they appear in the input).
+    ///
+    /// This is useful for inputs where conditions are generated in parts (e.g. clause
+    /// by clause).
+    Conjoin,
+}
+
+/// Options of [`AdfExpressions::parse_with_options`].
+///
+/// The default options are the ones used by [`AdfExpressions::parse`].
+///
+/// # Example
+///
+/// ```
+/// # use biodivine_adf_solver::{AdfDialect, AdfExpressions, DuplicateHandling, ParseOptions};
+/// # use biodivine_adf_solver::Statement;
+/// let input = "s(a).\nacc(c, or(a, b)).\nacc(c, neg(a)).";
+/// let options = ParseOptions::default()
+///     .with_dialect(AdfDialect::detect(input))
+///     .with_implicit_statements(true)
+///     .with_duplicates(DuplicateHandling::Conjoin);
+/// let adf = AdfExpressions::parse_with_options(input, options).unwrap();
+/// assert!(adf.has_statement(&Statement::from("b")));
+/// let condition = adf.get_condition(&Statement::from("c")).unwrap();
+/// assert_eq!(condition.to_string(), "and(or(a,b),neg(a))");
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub struct ParseOptions {
+    dialect: Option<AdfDialect>,
+    implicit_statements: bool,
+    duplicates: DuplicateHandling,
+}
+
+impl ParseOptions {
+    /// Only accept the keywords of the given [`AdfDialect`] (e.g. as returned by
+    /// [`AdfDialect::detect`]). Semantically equal inputs produce the same ADF regardless
+    /// of dialect.
+    ///
+    /// If no dialect is given (the default), both `s(label).` and `statement(label).`
+    /// declarations are accepted, together with `ac(label, expression).` conditions.
+    pub fn with_dialect(mut self, dialect: Option<AdfDialect>) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// If `implicit_statements` is `true`, every statement that is referenced in some
+    /// condition but never declared is added to the ADF as a free statement. Statements
+    /// are always ordered by their label (see [`Statement`]), hence the implicit statements
+    /// obtain their indices (e.g. in [`DirectMap`](crate::DirectMap)) based on their label
+    /// as well, interleaved with the declared statements. Their position in the input
+    /// has no effect.
+    ///
+    /// If `implicit_statements` is `false` (the default), undeclared statements remain
+    /// missing, and must be fixed before the ADF can be converted into
+    /// [`AdfBdds`](crate::AdfBdds).
+    pub fn with_implicit_statements(mut self, implicit_statements: bool) -> Self {
+        self.implicit_statements = implicit_statements;
+        self
+    }
+
+    /// Set how duplicate declarations are handled (see [`DuplicateHandling`]).
+    pub fn with_duplicates(mut self, duplicates: DuplicateHandling) -> Self {
+        self.duplicates = duplicates;
+        self
+    }
+}
 
 /// Represents an abstract dialectical framework based on expressions
 /// (typically loaded from a file).
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct AdfExpressions {
     conditions: BTreeMap<Statement, Option<ConditionExpression>>,
+    /// The number of `Some` values in `conditions`. Use [`AdfExpressions::set_entry`] and
+    /// [`AdfExpressions::remove_entry`] to keep it up to date.
+    condition_count: usize,
 }
 
 impl AdfExpressions {
@@ -14,7 +237,34 @@ impl AdfExpressions {
     pub fn new() -> Self {
         AdfExpressions {
             conditions: BTreeMap::new(),
+            condition_count: 0,
+        }
+    }
+
+    /// Set the entry of `statement` to `condition`, creating the statement if necessary.
+    /// Returns the previous entry, like [`BTreeMap::insert`].
+    fn set_entry(
+        &mut self,
+        statement: Statement,
+        condition: Option<ConditionExpression>,
+    ) -> Option<Option<ConditionExpression>> {
+        if condition.is_some() {
+            self.condition_count += 1;
+        }
+        let previous = self.conditions.insert(statement, condition);
+        if let Some(Some(_)) = &previous {
+            self.condition_count -= 1;
         }
+        previous
+    }
+
+    /// Remove the entry of `statement`, like [`BTreeMap::remove`].
+    fn remove_entry(&mut self, statement: &Statement) -> Option<Option<ConditionExpression>> {
+        let previous = self.conditions.remove(statement);
+        if let Some(Some(_)) = &previous {
+            self.condition_count -= 1;
+        }
+        previous
     }
 
     /// Get the acceptance condition for a statement.
@@ -46,6 +296,50 @@ impl AdfExpressions {
         self.conditions.is_empty()
     }
 
+    /// Get the number of statements in the ADF (same as [`AdfExpressions::len`]).
+    pub fn statement_count(&self) -> usize {
+        self.conditions.len()
+    }
+
+    /// Get the number of statements that have a condition.
+    ///
+    /// The count is updated whenever a condition is added or removed, so unlike
+    /// `conditions().count()`, this does not need to scan the statements.
+    pub fn condition_count(&self) -> usize {
+        self.condition_count
+    }
+
+    /// Estimate the size of this ADF once it is written in a typical formula-based format
+    /// (e.g. `.bnet`), measured in "symbols" (statements, constants and operators).
+    ///
+    /// The estimate is the sum of the estimates of all conditions, where:
+    /// - statements and constants count as `1`;
+    /// - `neg`, `imp` and every operand of `and`/`or` add `1`;
+    /// - `xor` and `iff` count both of their operands twice (plus `3`), since formats without
+    ///   these operators have to expand them.
+    ///
+    /// Each symbol typically requires several bytes (see
+    /// [`AdfExpressions::is_likely_convertible`]).
+    pub fn estimated_encoding_size(&self) -> u64 {
+        self.conditions
+            .values()
+            .flatten()
+            .map(estimated_condition_size)
+            .sum()
+    }
+
+    /// Returns `true` if the [`AdfExpressions::estimated_encoding_size`] of this ADF likely
+    /// fits into the given `byte_budget`, assuming every symbol takes
+    /// [`ESTIMATED_BYTES_PER_SYMBOL`] bytes.
+    ///
+    /// This is only a cheap heuristic that can be used to skip conversions which would
+    /// produce impractically large files.
+    pub fn is_likely_convertible(&self, byte_budget: u64) -> bool {
+        self.estimated_encoding_size()
+            .saturating_mul(ESTIMATED_BYTES_PER_SYMBOL)
+            <= byte_budget
+    }
+
     /// Parse an ADF from a string in the `.adf` file format.
     ///
     /// The format consists of lines with:
@@ -55,9 +349,30 @@ impl AdfExpressions {
     /// Labels can be numeric (e.g., `1`, `42`) or string identifiers (e.g., `foo`, `bar`).
     /// Empty lines and lines starting with `#` are ignored as comments.
     /// Statements can be declared without conditions, and conditions can reference
-    /// statements that are not explicitly declared.
+    /// statements that are not explicitly declared (such statements are not added to
+    /// the ADF; see [`ParseOptions::with_implicit_statements`]).
+    ///
+    /// Declaring the same statement twice, or declaring two conditions for the same
+    /// statement, results in an error (see also [`ParseOptions::with_duplicates`]).
     pub fn parse(input: &str) -> Result<Self, String> {
+        Self::parse_with_options(input, ParseOptions::default())
+    }
+
+    /// Parse an ADF from a string in the `.adf` file format (see [`AdfExpressions::parse`]),
+    /// using the given [`ParseOptions`].
+    pub fn parse_with_options(input: &str, options: ParseOptions) -> Result<Self, String> {
+        let ParseOptions {
+            dialect,
+            implicit_statements,
+            duplicates,
+        } = options;
+        let lenient = duplicates == DuplicateHandling::Replace;
+        let (statement_keywords, condition_keyword) = AdfDialect::keywords(dialect);
         let mut adf = AdfExpressions::new();
+        // Statements declared using `s(label).` (to detect duplicate declarations).
+        let mut declared = BTreeSet::new();
+        // Parts of conditions that are declared multiple times (with `DuplicateHandling::Conjoin`).
+        let mut parts: BTreeMap<Statement, Vec<ConditionExpression>> = BTreeMap::new();
 
         for (line_num, line) in input.lines().enumerate() {
             let line = line.trim();
@@ -68,30 +383,41 @@ impl AdfExpressions {
             }
 
             // Parse statement declaration: s(label). or statement(label).
-            let is_short_form = line.starts_with("s(") && line.ends_with(").");
-            let is_long_form = line.starts_with("statement(") && line.ends_with(").");
-
-            if is_short_form || is_long_form {
-                let label_str = if is_short_form {
-                    &line[2..line.len() - 2]
-                } else {
-                    &line[10..line.len() - 2]
-                };
+            let declaration = statement_keywords
+                .iter()
+                .find_map(|keyword| strip_declaration(line, keyword));
 
+            if let Some(label_str) = declaration {
                 let label_str = label_str.trim();
 
                 // Try to parse as number first, otherwise use as string label
                 let statement = Statement::from(label_str);
 
+                if !declared.insert(statement.clone()) {
+                    if !lenient {
+                        return Err(format!(
+                            "Line {}: Statement {} is declared more than once",
+                            line_num + 1,
+                            statement
+                        ));
+                    }
+                    warn!(
+                        "Line {}: Statement {} is declared more than once",
+                        line_num + 1,
+                        statement
+                    );
+                }
+
                 // Insert statement with no condition if not already present
-                adf.conditions.entry(statement).or_insert(None);
+                if !adf.conditions.contains_key(&statement) {
+                    adf.set_entry(statement, None);
+                }
                 continue;
             }
 
             // Parse acceptance condition: ac(label, expression).
-            if line.starts_with("ac(") && line.ends_with(").") {
+            if let Some(content) = strip_declaration(line, condition_keyword) {
                 // Find the comma that separates the statement label from the expression
-                let content = &line[3..line.len() - 2];
                 let comma_pos = content.find(',').ok_or_else(|| {
                     format!(
                         "Line {}: Missing comma in acceptance condition",
@@ -115,16 +441,30 @@ impl AdfExpressions {
                 })?;
 
                 // Check if this statement already has a condition
-                if let Some(Some(_)) = adf.conditions.get(&statement) {
-                    return Err(format!(
-                        "Line {}: Statement {} already has a condition declared",
+                if let Some(Some(previous)) = adf.conditions.get(&statement) {
+                    if duplicates == DuplicateHandling::Conjoin {
+                        parts
+                            .entry(statement)
+                            .or_insert_with(|| vec![previous.clone()])
+                            .push(condition);
+                        continue;
+                    }
+                    if !lenient {
+                        return Err(format!(
+                            "Line {}: Statement {} already has a condition declared",
+                            line_num + 1,
+                            statement
+                        ));
+                    }
+                    warn!(
+                        "Line {}: Statement {} already has a condition declared; using the new condition",
                         line_num + 1,
                         statement
-                    ));
+                    );
                 }
 
                 // Insert the condition
-                adf.conditions.insert(statement, Some(condition));
+                adf.set_entry(statement, Some(condition));
                 continue;
             }
 
@@ -136,15 +476,24 @@ impl AdfExpressions {
             ));
         }
 
+        for (statement, parts) in parts {
+            adf.set_entry(statement, Some(ConditionExpression::and(&parts)));
+        }
+
+        if implicit_statements {
+            adf.fix_missing_statements();
+        }
+
         Ok(adf)
     }
 
     /// Parse an ADF from a string and automatically fix missing statements.
     /// This is equivalent to calling `parse()` followed by `fix_missing_statements()`.
     pub fn parse_and_fix(input: &str) -> Result<Self, String> {
-        let mut adf = Self::parse(input)?;
-        adf.fix_missing_statements();
-        Ok(adf)
+        Self::parse_with_options(
+            input,
+            ParseOptions::default().with_implicit_statements(true),
+        )
     }
 
     /// Parse an ADF from a file.
@@ -162,10 +511,224 @@ impl AdfExpressions {
         Ok(adf)
     }
 
+    /// Build an ADF from an abstract argumentation framework given as an attack matrix.
+    ///
+    /// The result has statements `0..n`, where `matrix[j][i] == true` means that `j` attacks
+    /// `i`. The condition of every statement `i` is the conjunction of `neg(j)` for all its
+    /// attackers `j` (a single attacker yields just `neg(j)`). Statements without
+    /// attackers have the condition `c(v)`.
+    ///
+    /// # Panics
+    ///
+    /// The matrix must be square.
+    pub fn from_attack_matrix(matrix: &[Vec<bool>]) -> Self {
+        let mut adf = AdfExpressions::new();
+        for (i, row) in matrix.iter().enumerate() {
+            assert_eq!(
+                row.len(),
+                matrix.len(),
+                "Row {} of the attack matrix has invalid length",
+                i
+            );
+            adf.add_statement(Statement::from(i));
+        }
+
+        for i in 0..matrix.len() {
+            let attackers = (0..matrix.len())
+                .filter(|j| matrix[*j][i])
+                .map(Statement::from)
+                .collect::<Vec<_>>();
+            let condition = af_condition(&attackers, &[], SupportSemantics::default());
+            adf.update_condition(Statement::from(i), condition);
+        }
+
+        adf
+    }
+
+    /// Parse a bipolar argumentation framework in the `.apx` format and translate it into
+    /// an ADF.
+    ///
+    /// The format consists of lines with:
+    /// - `arg(a).` to declare an argument
+    /// - `att(a,b).` to declare that `a` attacks `b`
+    /// - `sup(a,b).` to declare that `a` supports `b`
+    ///
+    /// Empty lines and lines starting with `%` or `#` are ignored as comments. Every argument
+    /// becomes a statement whose condition is built from its attackers and supporters based
+    /// on the given [`SupportSemantics`]. Returns an error if some line is malformed, or if
+    /// some relation references an undeclared argument.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use biodivine_adf_solver::{AdfExpressions, Statement, SupportSemantics};
+    /// let input = "arg(a).\narg(b).\narg(c).\natt(a,c).\nsup(b,c).";
+    /// let adf = AdfExpressions::parse_bipolar_apx(input, SupportSemantics::Disjunctive).unwrap();
+    /// let condition = adf.get_condition(&Statement::from("c")).unwrap();
+    /// assert_eq!(condition.to_string(), "and(neg(a),b)");
+    /// ```
+    pub fn parse_bipolar_apx(input: &str, support: SupportSemantics) -> Result<Self, String> {
+        let mut attackers: BTreeMap<Statement, Vec<Statement>> = BTreeMap::new();
+        let mut supporters: BTreeMap<Statement, Vec<Statement>> = BTreeMap::new();
+        let mut relations = Vec::new();
+
+        for (line_num, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('%') || line.starts_with('#') {
+                continue;
+            }
+
+            let content = line.strip_suffix(").").ok_or_else(|| {
+                format!("Line {}: Unrecognized line format: {}", line_num + 1, line)
+            })?;
+            if let Some(label) = content.strip_prefix("arg(") {
+                let argument = Statement::from(label.trim());
+                attackers.entry(argument.clone()).or_default();
+                supporters.entry(argument).or_default();
+                continue;
+            }
+
+            let (is_attack, pair) = if let Some(pair) = content.strip_prefix("att(") {
+                (true, pair)
+            } else if let Some(pair) = content.strip_prefix("sup(") {
+                (false, pair)
+            } else {
+                return Err(format!(
+                    "Line {}: Unrecognized line format: {}",
+                    line_num + 1,
+                    line
+                ));
+            };
+            let (source, target) = pair
+                .split_once(',')
+                .ok_or_else(|| format!("Line {}: Missing comma in relation", line_num + 1))?;
+            let source = Statement::from(source.trim());
+            let target = Statement::from(target.trim());
+            relations.push((line_num, is_attack, source, target));
+        }
+
+        for (line_num, is_attack, source, target) in relations {
+            for argument in [&source, &target] {
+                if !attackers.contains_key(argument) {
+                    return Err(format!(
+                        "Line {}: Argument {} is not declared",
+                        line_num + 1,
+                        argument
+                    ));
+                }
+            }
+            let relation = if is_attack {
+                &mut attackers
+            } else {
+                &mut supporters
+            };
+            relation
+                .get_mut(&target)
+                .expect("Argument is declared")
+                .push(source);
+        }
+
+        let mut adf = AdfExpressions::new();
+        for (argument, attackers) in attackers {
+            let condition = af_condition(&attackers, &supporters[&argument], support);
+            adf.update_condition(argument, condition);
+        }
+
+        Ok(adf)
+    }
+
+    /// Parse an argumentation framework in the trivial graph format (`.tgf`) and translate it
+    /// into an ADF.
+    ///
+    /// The input starts with a list of nodes, one per line, each consisting of a node ID
+    /// and an optional label. The node list is terminated by a line containing only `#`.
+    /// Afterwards, every line contains an edge (`source target`, with an optional edge label
+    /// that is ignored) which is interpreted as an attack. Empty lines are ignored.
+    ///
+    /// Every node becomes a statement named by its label (or by its ID if it has no label).
+    /// As in [`AdfExpressions::from_attack_matrix`], the condition of every statement
+    /// is the conjunction of `neg(a)` for all its attackers `a`, or `c(v)` if there are no
+    /// attackers. Returns an error if two nodes share the same ID or statement name, if some
+    /// label contains whitespace, or if an edge references an undeclared node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use biodivine_adf_solver::{AdfExpressions, Statement};
+    /// let input = "1 a\n2 b\n3\n#\n1 2\n3 2";
+    /// let adf = AdfExpressions::parse_tgf(input).unwrap();
+    /// let condition = adf.get_condition(&Statement::from("b")).unwrap();
+    /// assert_eq!(condition.to_string(), "and(neg(a),neg(3))");
+    /// ```
+    pub fn parse_tgf(input: &str) -> Result<Self, String> {
+        let mut nodes: BTreeMap<&str, Statement> = BTreeMap::new();
+        let mut attackers: BTreeMap<Statement, Vec<Statement>> = BTreeMap::new();
+        let mut in_edges = false;
+
+        for (line_num, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if line == "#" {
+                in_edges = true;
+                continue;
+            }
+
+            let (first, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let rest = rest.trim();
+            if !in_edges {
+                if rest.contains(char::is_whitespace) {
+                    return Err(format!(
+                        "Line {}: Node label {} contains whitespace",
+                        line_num + 1,
+                        rest
+                    ));
+                }
+                let statement = Statement::from(if rest.is_empty() { first } else { rest });
+                if nodes.contains_key(first) || attackers.contains_key(&statement) {
+                    return Err(format!(
+                        "Line {}: Node {} is declared more than once",
+                        line_num + 1,
+                        first
+                    ));
+                }
+                nodes.insert(first, statement.clone());
+                attackers.insert(statement, Vec::new());
+                continue;
+            }
+
+            let target = rest.split_whitespace().next().ok_or_else(|| {
+                format!("Line {}: Missing target of edge: {}", line_num + 1, line)
+            })?;
+            let [source, target] = [first, target].map(|node| {
+                nodes
+                    .get(node)
+                    .cloned()
+                    .ok_or_else(|| format!("Line {}: Node {} is not declared", line_num + 1, node))
+            });
+            let (source, target) = (source?, target?);
+            let target_attackers = attackers.get_mut(&target).expect("Node is declared");
+            if !target_attackers.contains(&source) {
+                target_attackers.push(source);
+            }
+        }
+
+        let mut adf = AdfExpressions::new();
+        for (argument, attackers) in attackers {
+            let condition = af_condition(&attackers, &[], SupportSemantics::default());
+            adf.update_condition(argument, condition);
+        }
+
+        Ok(adf)
+    }
+
     /// Add a statement without a condition.
     /// If the statement already exists, this does nothing.
     pub fn add_statement(&mut self, statement: Statement) {
-        self.conditions.entry(statement).or_insert(None);
+        if !self.conditions.contains_key(&statement) {
+            self.set_entry(statement, None);
+        }
     }
 
     /// Add a condition for a statement.
@@ -178,20 +741,59 @@ impl AdfExpressions {
         if let Some(Some(_)) = self.conditions.get(&statement) {
             return Err(format!("Statement {} already has a condition", statement));
         }
-        self.conditions.insert(statement, Some(condition));
+        self.set_entry(statement, Some(condition));
         Ok(())
     }
 
     /// Remove a statement and its condition entirely from the ADF.
-    pub fn remove_statement(&mut self, statement: Statement) {
-        self.conditions.remove(&statement);
+    ///
+    /// Returns an error (and leaves the ADF unchanged) if the statement is still referenced
+    /// by the condition of some other statement. Such statements can be removed using
+    /// [`AdfExpressions::remove_statement_cascading`]. If the statement doesn't exist,
+    /// this does nothing.
+    pub fn remove_statement(&mut self, statement: Statement) -> Result<(), String> {
+        let referencing = self
+            .conditions()
+            .filter(|(other, condition)| {
+                *other != statement && condition.collect_statements().contains(&statement)
+            })
+            .map(|(other, _)| other.to_string())
+            .collect::<Vec<_>>();
+        if !referencing.is_empty() {
+            return Err(format!(
+                "Statement {} is referenced by the conditions of: {}",
+                statement,
+                referencing.join(", ")
+            ));
+        }
+        self.remove_entry(&statement);
+        Ok(())
+    }
+
+    /// Remove a statement and its condition entirely from the ADF, replacing every reference
+    /// to the statement in the remaining conditions with the constant `default`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use biodivine_adf_solver::{AdfExpressions, Statement};
+    /// let mut adf = AdfExpressions::parse("ac(a, b).\nac(b, c(v)).\nac(c, or(a, b)).").unwrap();
+    /// assert!(adf.remove_statement(Statement::from("b")).is_err());
+    ///
+    /// adf.remove_statement_cascading(Statement::from("b"), false);
+    /// assert!(!adf.has_statement(&Statement::from("b")));
+    /// assert_eq!(adf.get_condition(&Statement::from("a")).unwrap().to_string(), "c(f)");
+    /// ```
+    pub fn remove_statement_cascading(&mut self, statement: Statement, default: bool) {
+        self.remove_entry(&statement);
+        self.substitute_statement(&statement, &ConditionExpression::constant(default));
     }
 
     /// Remove the condition for a statement, but keep the statement.
     /// If the statement doesn't exist, this does nothing.
     pub fn remove_condition(&mut self, statement: Statement) {
-        if let Some(cond) = self.conditions.get_mut(&statement) {
-            *cond = None;
+        if self.conditions.contains_key(&statement) {
+            self.set_entry(statement, None);
         }
     }
 
@@ -199,7 +801,7 @@ impl AdfExpressions {
     /// If the statement doesn't exist, it will be created.
     /// If it already has a condition, it will be replaced.
     pub fn update_condition(&mut self, statement: Statement, condition: ConditionExpression) {
-        self.conditions.insert(statement, Some(condition));
+        self.set_entry(statement, Some(condition));
     }
 
     /// Get an iterator over all statements that have conditions.
@@ -230,8 +832,6 @@ impl AdfExpressions {
     /// Find all statements that appear in some condition expression but are not declared.
     /// Returns a sorted vector of missing statements.
     pub fn find_missing_statements(&self) -> Vec<Statement> {
-        use std::collections::BTreeSet;
-
         let mut referenced = BTreeSet::new();
 
         // Collect all statements referenced in conditions
@@ -375,6 +975,46 @@ impl AdfExpressions {
         }
     }
 
+    /// Create a copy of this ADF where every condition `c` is replaced by `neg(c)`.
+    ///
+    /// The set of statements is preserved and free statements stay free. Since negation
+    /// flips the polarity of every link, supporting links of the original ADF become
+    /// attacking links (and vice versa).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use biodivine_adf_solver::{AdfExpressions, ConditionExpression, Statement};
+    /// let mut adf = AdfExpressions::new();
+    /// let s1 = Statement::from(1);
+    /// let s2 = Statement::from(2);
+    ///
+    /// // Add condition: ac(1, and(1, neg(2)))
+    /// adf.add_condition(s1.clone(), ConditionExpression::and(&[
+    ///     ConditionExpression::statement(s1.clone()),
+    ///     ConditionExpression::negation(ConditionExpression::statement(s2.clone())),
+    /// ])).unwrap();
+    /// adf.add_statement(s2.clone());
+    ///
+    /// let negated = adf.negate_conditions();
+    /// assert_eq!(negated.get_condition(&s1).unwrap().to_string(), "neg(and(1,neg(2)))");
+    /// assert!(negated.get_condition(&s2).is_none());
+    /// ```
+    pub fn negate_conditions(&self) -> AdfExpressions {
+        let conditions = self
+            .conditions
+            .iter()
+            .map(|(statement, condition)| {
+                let negated = condition.clone().map(ConditionExpression::negation);
+                (statement.clone(), negated)
+            })
+            .collect();
+        AdfExpressions {
+            conditions,
+            condition_count: self.condition_count,
+        }
+    }
+
     /// Build a dependency map showing which statements are referenced in each condition.
     ///
     /// Returns a map where the key is a statement that has a condition, and the value
@@ -403,9 +1043,7 @@ impl AdfExpressions {
     /// assert!(s1_deps.contains(&s2));
     /// assert!(s1_deps.contains(&s3));
     /// ```
-    pub fn build_dependency_map(
-        &self,
-    ) -> BTreeMap<Statement, std::collections::BTreeSet<Statement>> {
+    pub fn build_dependency_map(&self) -> BTreeMap<Statement, BTreeSet<Statement>> {
         let mut dep_map = BTreeMap::new();
 
         for (statement, condition) in &self.conditions {
@@ -418,6 +1056,96 @@ impl AdfExpressions {
         dep_map
     }
 
+    /// Find all dependency cycles of this ADF, i.e. the non-trivial strongly connected
+    /// components of the dependency graph (see [`AdfExpressions::build_dependency_map`]).
+    ///
+    /// A single statement forms a cycle only if its condition references the statement
+    /// itself (e.g. `ac(a, a).`). Each cycle is sorted, and the cycles are sorted
+    /// by their smallest statement.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use biodivine_adf_solver::{AdfExpressions, Statement};
+    /// let adf = AdfExpressions::parse("ac(0, 0).\nac(1, 2).\nac(2, neg(1)).\nac(3, 1).").unwrap();
+    /// let cycles = adf.dependency_cycles();
+    /// assert_eq!(cycles, vec![
+    ///     vec![Statement::from(0)],
+    ///     vec![Statement::from(1), Statement::from(2)],
+    /// ]);
+    /// ```
+    pub fn dependency_cycles(&self) -> Vec<Vec<Statement>> {
+        let dependencies = self.build_dependency_map();
+        let mut cycles: Vec<Vec<Statement>> =
+            strongly_connected_components(self.conditions.keys(), &dependencies)
+                .into_iter()
+                .filter(|component| {
+                    let first = &component[0];
+                    component.len() > 1
+                        || dependencies
+                            .get(first)
+                            .is_some_and(|deps| deps.contains(first))
+                })
+                .collect();
+        cycles.sort();
+        cycles
+    }
+
+    /// Group statements into layers based on the longest path in the acyclic condensation
+    /// of the dependency graph (see [`AdfExpressions::build_dependency_map`]).
+    ///
+    /// Statements that belong to the same cycle (i.e. the same strongly connected
+    /// component) are always in the same layer. Statements that only depend on statements in
+    /// their own component (e.g. statements without dependencies) form layer 0. Every other
+    /// statement is in the layer directly above the highest layer of its dependencies.
+    /// Missing statements (see [`AdfExpressions::find_missing_statements`]) are ignored.
+    /// Each layer is sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use biodivine_adf_solver::{AdfExpressions, Statement};
+    /// let adf = AdfExpressions::parse("ac(0, c(v)).\nac(1, 2).\nac(2, neg(1)).\nac(3, and(0, 1)).").unwrap();
+    /// assert_eq!(adf.topological_layers(), vec![
+    ///     vec![Statement::from(0), Statement::from(1), Statement::from(2)],
+    ///     vec![Statement::from(3)],
+    /// ]);
+    /// ```
+    pub fn topological_layers(&self) -> Vec<Vec<Statement>> {
+        let dependencies = self.build_dependency_map();
+        let mut layer_of: BTreeMap<&Statement, usize> = BTreeMap::new();
+        let mut layers: Vec<Vec<Statement>> = Vec::new();
+        for component in strongly_connected_components(self.conditions.keys(), &dependencies) {
+            if !self.has_statement(&component[0]) {
+                // Missing statements are always singleton components.
+                continue;
+            }
+            // Dependencies in the same component are not assigned a layer yet.
+            let layer = component
+                .iter()
+                .flat_map(|statement| dependencies.get(statement).into_iter().flatten())
+                .filter_map(|dependency| layer_of.get(dependency))
+                .map(|layer| layer + 1)
+                .max()
+                .unwrap_or(0);
+            if layers.len() <= layer {
+                layers.resize(layer + 1, Vec::new());
+            }
+            for statement in &component {
+                let (key, _) = self
+                    .conditions
+                    .get_key_value(statement)
+                    .expect("Statement exists");
+                layer_of.insert(key, layer);
+            }
+            layers[layer].extend(component);
+        }
+        for layer in &mut layers {
+            layer.sort();
+        }
+        layers
+    }
+
     /// Rename multiple statements throughout the entire ADF using a map.
     ///
     /// This method renames multiple statements both in the statement list and in all conditions
@@ -515,14 +1243,13 @@ impl AdfExpressions {
         // Collect old conditions first to avoid borrowing issues
         let old_conditions: Vec<_> = renamings
             .keys()
-            .map(|old_stmt| (old_stmt.clone(), self.conditions.remove(old_stmt)))
+            .map(|old_stmt| (old_stmt.clone(), self.remove_entry(old_stmt)))
             .collect();
 
         // Insert with new names
         for (old_stmt, old_condition) in old_conditions {
             if let Some(new_stmt) = renamings.get(&old_stmt) {
-                self.conditions
-                    .insert(new_stmt.clone(), old_condition.flatten());
+                self.set_entry(new_stmt.clone(), old_condition.flatten());
             }
         }
 
@@ -593,7 +1320,7 @@ impl AdfExpressions {
         }
 
         // Remove the old statement and get its condition
-        let old_condition = self.conditions.remove(old_statement);
+        let old_condition = self.remove_entry(old_statement);
 
         // Substitute old statement with new statement in all conditions
         let replacement = ConditionExpression::statement(new_statement.clone());
@@ -610,11 +1337,69 @@ impl AdfExpressions {
             .map(|opt_cond| opt_cond.map(|cond| cond.substitute(old_statement, &replacement)));
 
         // Insert the new statement with the (possibly modified) condition
-        self.conditions
-            .insert(new_statement.clone(), new_condition.flatten());
+        self.set_entry(new_statement.clone(), new_condition.flatten());
 
         Ok(())
     }
+
+    /// Compute a deterministic hash of this ADF which only depends on its semantics.
+    ///
+    /// Every condition is first canonicalized by converting it into a BDD (which is
+    /// a canonical representation of a Boolean function once the variable ordering is fixed).
+    /// The hash then combines the sorted statements, the information which statements are
+    /// free, and the full node structure of each condition BDD. As such, ADFs that only
+    /// differ in the syntax of their conditions (e.g., operand order in `and`/`or`, double
+    /// negation, or `imp` vs. `or(neg(..), ..)`) produce the same hash.
+    ///
+    /// Missing statements are treated as free statements (see
+    /// [`AdfExpressions::fix_missing_statements`]).
+    ///
+    /// Conversely, ADFs with different semantics have different canonical structures, so
+    /// their hashes only collide if the underlying 64-bit hash function collides. Equal
+    /// hashes should be followed by an actual equivalence check when this matters.
+    /// The value is stable across runs, but not guaranteed to be stable across versions
+    /// of this crate or the Rust standard library.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use biodivine_adf_solver::AdfExpressions;
+    /// let a = AdfExpressions::parse("ac(1, and(2, 3)).\ns(2).\ns(3).").unwrap();
+    /// let b = AdfExpressions::parse("ac(1, neg(or(neg(3), neg(2)))).\ns(2).\ns(3).").unwrap();
+    /// assert_eq!(a.canonical_hash(), b.canonical_hash());
+    /// ```
+    pub fn canonical_hash(&self) -> u64 {
+        use crate::AdfBdds;
+        use crate::adf_bdds::bdd_nodes;
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let mut adf = self.clone();
+        adf.fix_missing_statements();
+
+        let bdds = AdfBdds::from_direct_only(&adf);
+        let direct = bdds.direct_encoding();
+        let variables = direct
+            .var_map()
+            .statements()
+            .map(|statement| direct.var_map()[statement])
+            .collect::<Vec<_>>();
+
+        let mut hasher = DefaultHasher::new();
+        adf.len().hash(&mut hasher);
+        for statement in direct.var_map().statements() {
+            statement.hash(&mut hasher);
+            match direct.get_condition(statement) {
+                None => false.hash(&mut hasher),
+                Some(bdd) => {
+                    true.hash(&mut hasher);
+                    // Reduced BDDs are identical for equivalent functions, hence their
+                    // node lists are also identical for equivalent conditions.
+                    bdd_nodes(bdd, &variables).hash(&mut hasher);
+                }
+            }
+        }
+        hasher.finish()
+    }
 }
 
 impl Default for AdfExpressions {
@@ -623,11 +1408,241 @@ impl Default for AdfExpressions {
     }
 }
 
+impl TryFrom<&str> for AdfExpressions {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
+    }
+}
+
+/// Compute the strongly connected components of a dependency graph, where
+/// `dependencies[s]` are the statements that `s` depends on. Statements that are not
+/// reachable from `roots` are ignored.
+///
+/// The components are returned in dependency order, i.e. every component only depends
+/// on itself and on components that appear before it. Each component is sorted.
+pub(crate) fn strongly_connected_components<'a>(
+    roots: impl IntoIterator<Item = &'a Statement>,
+    dependencies: &BTreeMap<Statement, BTreeSet<Statement>>,
+) -> Vec<Vec<Statement>> {
+    let empty = BTreeSet::new();
+    let successors = |s: &Statement| dependencies.get(s).unwrap_or(&empty);
+
+    // Kosaraju's algorithm: compute DFS post-order on the dependency graph...
+    let mut visited = BTreeSet::new();
+    let mut post_order = Vec::new();
+    for root in roots {
+        if !visited.insert(root.clone()) {
+            continue;
+        }
+        let mut stack = vec![(root.clone(), successors(root).iter())];
+        while let Some((node, children)) = stack.last_mut() {
+            if let Some(child) = children.next() {
+                if visited.insert(child.clone()) {
+                    let iter = successors(child).iter();
+                    stack.push((child.clone(), iter));
+                }
+            } else {
+                post_order.push(node.clone());
+                stack.pop();
+            }
+        }
+    }
+
+    // ...and then collect components on the reversed graph in reverse post-order.
+    let mut dependants: BTreeMap<Statement, Vec<Statement>> = BTreeMap::new();
+    for (statement, deps) in dependencies {
+        for dep in deps {
+            dependants
+                .entry(dep.clone())
+                .or_default()
+                .push(statement.clone());
+        }
+    }
+
+    let mut assigned = BTreeSet::new();
+    let mut components = Vec::new();
+    for root in post_order.into_iter().rev() {
+        if !assigned.insert(root.clone()) {
+            continue;
+        }
+        let mut component = vec![root.clone()];
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            for dependant in dependants.get(&node).into_iter().flatten() {
+                if visited.contains(dependant) && assigned.insert(dependant.clone()) {
+                    component.push(dependant.clone());
+                    stack.push(dependant.clone());
+                }
+            }
+        }
+        component.sort();
+        components.push(component);
+    }
+
+    // Kosaraju produces the components starting with those that nothing depends on.
+    components.reverse();
+    components
+}
+
+/// Estimate the size of a single condition (see [`AdfExpressions::estimated_encoding_size`]).
+fn estimated_condition_size(condition: &ConditionExpression) -> u64 {
+    match condition.node() {
+        ConditionExpressionNode::Statement(_) | ConditionExpressionNode::Constant(_) => 1,
+        ConditionExpressionNode::Negation(x) => 1 + estimated_condition_size(x),
+        ConditionExpressionNode::And(args) | ConditionExpressionNode::Or(args) => {
+            args.iter().map(estimated_condition_size).sum::<u64>() + (args.len() as u64)
+        }
+        ConditionExpressionNode::Implication(x, y) => {
+            estimated_condition_size(x) + estimated_condition_size(y) + 1
+        }
+        ConditionExpressionNode::Equivalence(x, y) | ConditionExpressionNode::ExclusiveOr(x, y) => {
+            2 * (estimated_condition_size(x) + estimated_condition_size(y)) + 3
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rstest::rstest;
 
+    #[test]
+    fn test_statement_and_condition_count() {
+        let mut adf = AdfExpressions::new();
+        assert_eq!(adf.statement_count(), 0);
+        assert_eq!(adf.condition_count(), 0);
+
+        let input = "s(0).\ns(1).\ns(2).\nac(0, c(v)).\nac(1, neg(3)).";
+        adf = AdfExpressions::parse(input).unwrap();
+        // Statement `3` is only referenced, not declared.
+        assert_eq!(adf.statement_count(), 3);
+        assert_eq!(adf.statement_count(), adf.statements().count());
+        assert_eq!(adf.condition_count(), 2);
+        assert_eq!(adf.condition_count(), adf.conditions().count());
+
+        adf.remove_condition(Statement::from(0));
+        assert_eq!(adf.statement_count(), 3);
+        assert_eq!(adf.condition_count(), 1);
+    }
+
+    #[test]
+    fn test_condition_count_is_maintained() {
+        fn check(adf: &AdfExpressions, expected: usize) {
+            assert_eq!(adf.condition_count(), expected);
+            assert_eq!(adf.condition_count(), adf.conditions().count());
+        }
+
+        let options = ParseOptions::default().with_duplicates(DuplicateHandling::Conjoin);
+        let input = "s(a).\ns(b).\nac(a, b).\nac(a, c(v)).\nac(b, a).";
+        let mut adf = AdfExpressions::parse_with_options(input, options).unwrap();
+        check(&adf, 2);
+
+        // Adding an existing statement or replacing a condition keeps the count.
+        adf.add_statement(Statement::from("a"));
+        check(&adf, 2);
+        adf.update_condition(Statement::from("a"), ConditionExpression::constant(true));
+        check(&adf, 2);
+        assert!(
+            adf.add_condition(Statement::from("b"), ConditionExpression::constant(true))
+                .is_err()
+        );
+        check(&adf, 2);
+
+        adf.add_statement(Statement::from("c"));
+        check(&adf, 2);
+        adf.add_condition(Statement::from("c"), ConditionExpression::constant(false))
+            .unwrap();
+        check(&adf, 3);
+
+        adf.remove_condition(Statement::from("c"));
+        check(&adf, 2);
+        adf.remove_condition(Statement::from("c"));
+        check(&adf, 2);
+        adf.update_condition(Statement::from("d"), ConditionExpression::constant(false));
+        check(&adf, 3);
+
+        adf.rename_statement(&Statement::from("d"), &Statement::from("e"))
+            .unwrap();
+        check(&adf, 3);
+        let renamings = BTreeMap::from([
+            (Statement::from("a"), Statement::from("x")),
+            (Statement::from("c"), Statement::from("y")),
+        ]);
+        adf.rename_statements(&renamings).unwrap();
+        check(&adf, 3);
+        check(&adf.negate_conditions(), 3);
+
+        adf.remove_statement(Statement::from("y")).unwrap();
+        check(&adf, 3);
+        adf.remove_statement(Statement::from("e")).unwrap();
+        check(&adf, 2);
+        adf.remove_statement_cascading(Statement::from("x"), true);
+        check(&adf, 1);
+        assert_eq!(adf, AdfExpressions::parse("ac(b, c(v)).").unwrap());
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        let adf = AdfExpressions::try_from("s(0).\nac(0, c(v)).").unwrap();
+        assert_eq!(adf, AdfExpressions::parse("s(0).\nac(0, c(v)).").unwrap());
+        assert!(AdfExpressions::try_from("ac(0 c(v)).").is_err());
+
+        // The error can be propagated into a boxed error.
+        fn parse_boxed(input: &str) -> Result<usize, Box<dyn std::error::Error>> {
+            Ok(AdfExpressions::try_from(input)?.len())
+        }
+        assert_eq!(parse_boxed("s(0).\ns(1).").unwrap(), 2);
+        assert!(parse_boxed("invalid").is_err());
+    }
+
+    #[test]
+    fn test_from_attack_matrix() {
+        // 0 attacks 1, 1 and 2 attack each other, 2 attacks itself.
+        let matrix = vec![
+            vec![false, true, false],
+            vec![false, false, true],
+            vec![false, true, true],
+        ];
+        let adf = AdfExpressions::from_attack_matrix(&matrix);
+
+        assert_eq!(adf.len(), 3);
+        let expected = AdfExpressions::parse(
+            "s(0).\ns(1).\ns(2).\nac(0, c(v)).\nac(1, and(neg(0), neg(2))).\nac(2, and(neg(1), neg(2))).",
+        )
+        .unwrap();
+        assert_eq!(adf, expected);
+        assert!(adf.find_missing_statements().is_empty());
+    }
+
+    #[test]
+    fn test_from_attack_matrix_single_attacker() {
+        let matrix = vec![vec![false, true], vec![false, false]];
+        let adf = AdfExpressions::from_attack_matrix(&matrix);
+
+        assert_eq!(
+            adf.get_condition(&Statement::from(1)).unwrap().to_string(),
+            "neg(0)"
+        );
+        assert_eq!(
+            adf.get_condition(&Statement::from(0)).unwrap().to_string(),
+            "c(v)"
+        );
+    }
+
+    #[test]
+    fn test_from_attack_matrix_empty() {
+        let adf = AdfExpressions::from_attack_matrix(&[]);
+        assert!(adf.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_attack_matrix_not_square() {
+        AdfExpressions::from_attack_matrix(&[vec![false, true]]);
+    }
+
     #[test]
     fn test_parse_simple_adf() {
         let input = r#"
@@ -929,6 +1944,32 @@ ac(1, c(f)).
         assert!(err.contains("Statement 1"));
     }
 
+    #[test]
+    fn test_parse_duplicate_statement() {
+        let input = "s(1).\ns(2).\nac(2, 1).\ns(1).";
+        let err = AdfExpressions::parse(input).unwrap_err();
+        assert!(err.contains("Line 4"));
+        assert!(err.contains("Statement 1 is declared more than once"));
+
+        // Declaring a statement in addition to its condition is not a duplicate.
+        assert!(AdfExpressions::parse("ac(1, c(v)).\ns(1).").is_ok());
+    }
+
+    #[test]
+    fn test_parse_replace_duplicates() {
+        let input = "s(1).\ns(1).\nac(1, c(v)).\nac(2, 1).\nac(1, neg(2)).";
+        assert!(AdfExpressions::parse(input).is_err());
+
+        let lenient = ParseOptions::default().with_duplicates(DuplicateHandling::Replace);
+
+        let adf = AdfExpressions::parse_with_options(input, lenient).unwrap();
+        assert_eq!(adf.len(), 2);
+        assert_eq!(
+            adf.get_condition(&Statement::from(1)).unwrap().to_string(),
+            "neg(2)"
+        );
+    }
+
     #[test]
     fn test_parse_duplicate_condition_complex() {
         let input = r#"
@@ -948,16 +1989,19 @@ ac(2, or(1, 3)).
     }
 
     #[test]
-    fn test_parse_statement_redeclaration_is_ok() {
-        // Multiple s(N) declarations for the same statement should be OK
-        // (it's redundant but not an error)
+    fn test_parse_statement_redeclaration() {
+        // Multiple s(N) declarations for the same statement are an error,
+        // unless they are replaced.
         let input = r#"
 s(1).
 s(1).
 s(1).
 ac(1, c(v)).
 "#;
-        let result = AdfExpressions::parse(input);
+        let err = AdfExpressions::parse(input).unwrap_err();
+        assert!(err.contains("Line 3"));
+        let lenient = ParseOptions::default().with_duplicates(DuplicateHandling::Replace);
+        let result = AdfExpressions::parse_with_options(input, lenient);
         assert!(result.is_ok());
         let adf = result.unwrap();
         assert_eq!(adf.len(), 1);
@@ -1061,7 +2105,7 @@ ac(3, and(1, 2)).
         adf.add_statement(s2.clone());
         assert_eq!(adf.len(), 2);
 
-        adf.remove_statement(s1.clone());
+        adf.remove_statement(s1.clone()).unwrap();
         assert_eq!(adf.len(), 1);
         assert!(!adf.has_statement(&s1));
         assert!(adf.has_statement(&s2));
@@ -1072,7 +2116,7 @@ ac(3, and(1, 2)).
         let mut adf = AdfExpressions::new();
         let s1 = Statement::from(1);
 
-        adf.remove_statement(s1.clone()); // Should not panic
+        adf.remove_statement(s1.clone()).unwrap(); // Should not panic
         assert_eq!(adf.len(), 0);
     }
 
@@ -1083,10 +2127,45 @@ ac(3, and(1, 2)).
         let cond = ConditionExpression::constant(true);
 
         adf.add_condition(s1.clone(), cond).unwrap();
-        adf.remove_statement(s1.clone());
+        adf.remove_statement(s1.clone()).unwrap();
         assert_eq!(adf.len(), 0);
     }
 
+    #[test]
+    fn test_remove_statement_referenced() {
+        let mut adf =
+            AdfExpressions::parse("ac(a, and(a, b)).\nac(b, b).\nac(c, neg(b)).").unwrap();
+        let error = adf.remove_statement(Statement::from("b")).unwrap_err();
+        assert!(error.contains("a, c"));
+        assert_eq!(adf.len(), 3);
+
+        // Self-references do not prevent removal.
+        adf.remove_statement(Statement::from("c")).unwrap();
+        adf.remove_statement(Statement::from("a")).unwrap();
+        adf.remove_statement(Statement::from("b")).unwrap();
+        assert!(adf.is_empty());
+    }
+
+    #[test]
+    fn test_remove_statement_cascading() {
+        let mut adf = AdfExpressions::parse("s(a).\nac(b, and(a, b)).\nac(c, neg(a)).").unwrap();
+        adf.remove_statement_cascading(Statement::from("a"), true);
+        assert!(!adf.has_statement(&Statement::from("a")));
+        assert_eq!(
+            adf.get_condition(&Statement::from("b"))
+                .unwrap()
+                .to_string(),
+            "and(c(v),b)"
+        );
+        assert_eq!(
+            adf.get_condition(&Statement::from("c"))
+                .unwrap()
+                .to_string(),
+            "neg(c(v))"
+        );
+        adf.remove_statement(Statement::from("b")).unwrap();
+    }
+
     // Tests for remove_condition
     #[test]
     fn test_remove_condition_existing() {
@@ -3383,4 +4462,326 @@ ac(3, neg(1)).
             count
         );
     }
+
+    // Tests for canonical_hash
+
+    #[test]
+    fn test_canonical_hash_reordered_operands() {
+        let a = AdfExpressions::parse("ac(1, and(2, or(3, 1))).\nac(2, c(v)).\ns(3).").unwrap();
+        let b = AdfExpressions::parse("s(3).\nac(2, c(v)).\nac(1, and(or(1, 3), 2)).").unwrap();
+        assert_eq!(a.canonical_hash(), b.canonical_hash());
+    }
+
+    #[test]
+    fn test_canonical_hash_equivalent_syntax() {
+        let a = AdfExpressions::parse("ac(1, imp(2, 3)).\ns(2).\ns(3).").unwrap();
+        let b = AdfExpressions::parse("ac(1, or(neg(2), neg(neg(3)))).\ns(2).\ns(3).").unwrap();
+        assert_eq!(a.canonical_hash(), b.canonical_hash());
+    }
+
+    #[test]
+    fn test_canonical_hash_different_semantics() {
+        let a = AdfExpressions::parse("ac(1, and(2, 3)).\ns(2).\ns(3).").unwrap();
+        let b = AdfExpressions::parse("ac(1, or(2, 3)).\ns(2).\ns(3).").unwrap();
+        let c = AdfExpressions::parse("ac(1, and(2, 3)).\nac(2, 2).\ns(3).").unwrap();
+        assert_ne!(a.canonical_hash(), b.canonical_hash());
+        // A free statement and an identity condition are different structures.
+        assert_ne!(a.canonical_hash(), c.canonical_hash());
+    }
+
+    #[test]
+    fn test_canonical_hash_same_fingerprint() {
+        // Same support, node count, model count and most negative model, but different
+        // functions.
+        let a = AdfExpressions::parse("ac(1, and(neg(2), neg(and(3, 4)))).\ns(2).\ns(3).\ns(4).")
+            .unwrap();
+        let b =
+            AdfExpressions::parse("ac(1, and(neg(2), imp(3, 4))).\ns(2).\ns(3).\ns(4).").unwrap();
+        assert_ne!(a.canonical_hash(), b.canonical_hash());
+    }
+
+    #[test]
+    fn test_canonical_hash_missing_statements() {
+        let a = AdfExpressions::parse("ac(1, and(2, 3)).").unwrap();
+        let b = AdfExpressions::parse("ac(1, and(2, 3)).\ns(2).\ns(3).").unwrap();
+        assert_eq!(a.canonical_hash(), b.canonical_hash());
+    }
+
+    #[test]
+    fn test_self_referential_condition() {
+        let adf = AdfExpressions::parse("ac(0, 0).").unwrap();
+        let s0 = Statement::from(0);
+        assert_eq!(
+            adf.get_condition(&s0),
+            Some(&ConditionExpression::statement(s0.clone()))
+        );
+        assert!(adf.find_missing_statements().is_empty());
+        assert_eq!(adf.dependency_cycles(), vec![vec![s0]]);
+    }
+
+    #[test]
+    fn test_dependency_cycles() {
+        let adf = AdfExpressions::parse(
+            "s(a).\nac(b, and(a, c)).\nac(c, or(b, d)).\nac(d, neg(a)).\nac(e, e).",
+        )
+        .unwrap();
+        let cycles = adf.dependency_cycles();
+        assert_eq!(
+            cycles,
+            vec![
+                vec![Statement::from("b"), Statement::from("c")],
+                vec![Statement::from("e")],
+            ]
+        );
+
+        let acyclic = AdfExpressions::parse("s(a).\nac(b, a).\nac(c, and(a, b)).").unwrap();
+        assert!(acyclic.dependency_cycles().is_empty());
+    }
+
+    #[test]
+    fn test_parse_with_implicit_statements() {
+        let input = "s(1).\nac(3, or(2, 1)).\nac(1, 0).";
+
+        let strict = AdfExpressions::parse_with_options(input, ParseOptions::default()).unwrap();
+        assert_eq!(
+            strict.find_missing_statements(),
+            vec![Statement::from(0), Statement::from(2)]
+        );
+        assert_eq!(strict, AdfExpressions::parse(input).unwrap());
+
+        let implicit = AdfExpressions::parse_with_options(
+            input,
+            ParseOptions::default().with_implicit_statements(true),
+        )
+        .unwrap();
+        assert!(implicit.find_missing_statements().is_empty());
+        let statements: Vec<Statement> = implicit.statements().cloned().collect();
+        assert_eq!(
+            statements,
+            vec![
+                Statement::from(0),
+                Statement::from(1),
+                Statement::from(2),
+                Statement::from(3)
+            ]
+        );
+        assert!(implicit.get_condition(&Statement::from(0)).is_none());
+        assert!(implicit.get_condition(&Statement::from(2)).is_none());
+    }
+
+    #[test]
+    fn test_strongly_connected_components_order() {
+        let adf = AdfExpressions::parse(
+            "s(a).\nac(b, and(a, c)).\nac(c, or(b, d)).\nac(d, neg(a)).\nac(e, b).",
+        )
+        .unwrap();
+        let dependencies = adf.build_dependency_map();
+        let components = strongly_connected_components(adf.statements(), &dependencies);
+        assert_eq!(components.len(), 4);
+        assert_eq!(components.last().unwrap(), &vec![Statement::from("e")]);
+
+        // Every component only depends on itself and earlier components.
+        let mut seen = BTreeSet::new();
+        for component in &components {
+            seen.extend(component.iter().cloned());
+            for statement in component {
+                for dep in dependencies.get(statement).into_iter().flatten() {
+                    assert!(seen.contains(dep));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_negate_conditions() {
+        let adf =
+            AdfExpressions::parse("s(a).\ns(b).\nac(a, or(b, c(f))).\nac(c, neg(a)).").unwrap();
+        let negated = adf.negate_conditions();
+
+        assert!(negated.statements().eq(adf.statements()));
+        assert!(negated.get_condition(&Statement::from("b")).is_none());
+        assert_eq!(
+            negated
+                .get_condition(&Statement::from("a"))
+                .unwrap()
+                .to_string(),
+            "neg(or(b,c(f)))"
+        );
+        assert_eq!(
+            negated
+                .get_condition(&Statement::from("c"))
+                .unwrap()
+                .to_string(),
+            "neg(neg(a))"
+        );
+
+        // The original ADF is not modified.
+        assert_eq!(
+            adf.get_condition(&Statement::from("c"))
+                .unwrap()
+                .to_string(),
+            "neg(a)"
+        );
+    }
+
+    #[rstest]
+    #[case(SupportSemantics::Disjunctive, "and(neg(a),or(b,c))", "b")]
+    #[case(SupportSemantics::Necessary, "and(neg(a),b,c)", "b")]
+    #[case(SupportSemantics::Deductive, "or(b,c,neg(a))", "c(v)")]
+    fn test_parse_bipolar_apx(
+        #[case] support: SupportSemantics,
+        #[case] expected_d: &str,
+        #[case] expected_e: &str,
+    ) {
+        let input = r#"
+            % A bipolar AF.
+            arg(a).
+            arg(b).
+            arg(c).
+            arg(d).
+            arg(e).
+            att(a,d).
+            sup(b, d).
+            sup(c,d).
+            sup(b,e).
+            att(b,a).
+        "#;
+        let adf = AdfExpressions::parse_bipolar_apx(input, support).unwrap();
+        assert_eq!(adf.len(), 5);
+        let condition = |s: &str| adf.get_condition(&Statement::from(s)).unwrap().to_string();
+        assert_eq!(condition("a"), "neg(b)");
+        assert_eq!(condition("b"), "c(v)");
+        assert_eq!(condition("d"), expected_d);
+        assert_eq!(condition("e"), expected_e);
+    }
+
+    #[test]
+    fn test_parse_bipolar_apx_invalid() {
+        let support = SupportSemantics::default();
+        let error = AdfExpressions::parse_bipolar_apx("arg(a).\natt(a,b).", support).unwrap_err();
+        assert!(error.contains("Line 2"));
+        assert!(error.contains("Argument b is not declared"));
+        assert!(AdfExpressions::parse_bipolar_apx("arg(a).\natt(a).", support).is_err());
+        assert!(AdfExpressions::parse_bipolar_apx("arg(a).\nfoo(a).", support).is_err());
+        assert!(AdfExpressions::parse_bipolar_apx("arg(a)", support).is_err());
+    }
+
+    #[test]
+    fn test_topological_layers() {
+        let adf = AdfExpressions::parse(
+            "s(a).\nac(b, and(a, c)).\nac(c, or(b, d)).\nac(d, neg(a)).\nac(e, b).\nac(f, and(e, x)).",
+        )
+        .unwrap();
+        let layers = adf.topological_layers();
+        let expected: Vec<Vec<Statement>> = vec![
+            vec!["a".into()],
+            vec!["d".into()],
+            vec!["b".into(), "c".into()],
+            vec!["e".into()],
+            vec!["f".into()],
+        ];
+        assert_eq!(layers, expected);
+
+        assert!(AdfExpressions::new().topological_layers().is_empty());
+    }
+
+    #[test]
+    fn test_parse_tgf() {
+        let input = "1 a\n2 b\n\n3\n#\n1 2 attacks\n3 2\n2 1\n2 1\n";
+        let adf = AdfExpressions::parse_tgf(input).unwrap();
+        assert_eq!(adf.len(), 3);
+        let condition = |s: &str| adf.get_condition(&Statement::from(s)).unwrap().to_string();
+        assert_eq!(condition("a"), "neg(b)");
+        assert_eq!(condition("b"), "and(neg(a),neg(3))");
+        assert_eq!(condition("3"), "c(v)");
+
+        // Nodes without edges.
+        let adf = AdfExpressions::parse_tgf("1\n2").unwrap();
+        assert_eq!(adf.conditions().count(), 2);
+    }
+
+    #[test]
+    fn test_parse_tgf_errors() {
+        let error = AdfExpressions::parse_tgf("1 a\n#\n1 2").unwrap_err();
+        assert!(error.contains("Line 3") && error.contains("Node 2 is not declared"));
+        let error = AdfExpressions::parse_tgf("1 a\n2 a").unwrap_err();
+        assert!(error.contains("declared more than once"));
+        let error = AdfExpressions::parse_tgf("1 a b").unwrap_err();
+        assert!(error.contains("whitespace"));
+        let error = AdfExpressions::parse_tgf("1\n#\n1").unwrap_err();
+        assert!(error.contains("Missing target"));
+    }
+
+    #[test]
+    fn test_estimated_encoding_size() {
+        let adf = AdfExpressions::parse(
+            "s(a).\nac(b, and(a, neg(c))).\nac(c, xor(a, b)).\nac(d, imp(c(v), b)).",
+        )
+        .unwrap();
+        // b: 1 + (1 + 1) + 2 = 5, c: 2 * (1 + 1) + 3 = 7, d: 1 + 1 + 1 = 3
+        assert_eq!(adf.estimated_encoding_size(), 15);
+        assert!(adf.is_likely_convertible(150));
+        assert!(!adf.is_likely_convertible(149));
+        assert_eq!(AdfExpressions::new().estimated_encoding_size(), 0);
+    }
+
+    #[test]
+    fn test_parse_conjoin_duplicates() {
+        let input = "s(a).\nac(a, b).\nac(b, c(v)).\nac(a, neg(c)).\nac(c, a).";
+        let conjoined = ParseOptions::default().with_duplicates(DuplicateHandling::Conjoin);
+        let adf = AdfExpressions::parse_with_options(input, conjoined).unwrap();
+        let condition = |s: &str| adf.get_condition(&Statement::from(s)).unwrap().to_string();
+        assert_eq!(condition("a"), "and(b,neg(c))");
+        assert_eq!(condition("b"), "c(v)");
+        assert_eq!(condition("c"), "a");
+
+        // Strict parsing still rejects the same input.
+        assert!(AdfExpressions::parse(input).is_err());
+        // Duplicate declarations are still rejected.
+        assert!(AdfExpressions::parse_with_options("s(a).\ns(a).", conjoined).is_err());
+    }
+
+    #[test]
+    fn test_parse_with_dialect() {
+        let diamond = "# comment\ns(a).\ns(b).\nac(a, or(b, neg(a))).\nac(b, c(v)).";
+        let yadf = "statement(a).\nstatement(b).\nac(a, or(b, neg(a))).\nac(b, c(v)).";
+        let kpp = "s(a).\ns(b).\nacc(a, or(b, neg(a))).\nacc(b, c(v)).";
+        let expected = AdfExpressions::parse(diamond).unwrap();
+        let options = |dialect| ParseOptions::default().with_dialect(dialect);
+
+        for (input, dialect) in [
+            (diamond, AdfDialect::Diamond),
+            (yadf, AdfDialect::Yadf),
+            (kpp, AdfDialect::Kpp),
+        ] {
+            assert_eq!(AdfDialect::detect(input), Some(dialect));
+            assert_eq!(
+                AdfExpressions::parse_with_options(input, options(Some(dialect))).unwrap(),
+                expected
+            );
+            assert_eq!(
+                AdfExpressions::parse_with_options(input, options(AdfDialect::detect(input)))
+                    .unwrap(),
+                expected
+            );
+        }
+
+        // Keywords of other dialects are rejected.
+        assert!(
+            AdfExpressions::parse_with_options(kpp, options(Some(AdfDialect::Diamond))).is_err()
+        );
+        assert!(
+            AdfExpressions::parse_with_options(diamond, options(Some(AdfDialect::Kpp))).is_err()
+        );
+        assert!(
+            AdfExpressions::parse_with_options(diamond, options(Some(AdfDialect::Yadf))).is_err()
+        );
+        assert!(AdfExpressions::parse(kpp).is_err());
+
+        assert_eq!(AdfDialect::detect("# only a comment"), None);
+        assert_eq!(
+            AdfExpressions::parse_with_options("", options(AdfDialect::detect(""))).unwrap(),
+            AdfExpressions::new()
+        );
+    }
 }
@@ -1,12 +1,53 @@
-use crate::bdd_solver::{BddSolver, DynamicBddSolver};
-use crate::{AdfBdds, ModelSetThreeValued, ModelSetTwoValued, Statement};
+use crate::adf_bdds::{bipolar_dual_condition, build_dual_encoding_without_conditions};
+use crate::adf_expressions::strongly_connected_components;
+use crate::bdd_solver::{BddSolver, DynamicBddSolver, SolveError};
+use crate::{AdfBdds, DualEncoding, DualMap, ModelSetThreeValued, ModelSetTwoValued, Statement};
 use cancel_this::{Cancellable, is_cancelled};
-use log::{debug, info};
+use log::{debug, info, trace};
+use rayon::prelude::*;
 use ruddy::split::Bdd;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::sync::Arc;
+use std::sync::mpsc::Sender;
+
+/// Determines how [`AdfInterpretationSolver`] treats "free" statements, i.e. statements
+/// without an acceptance condition, when computing three-valued interpretations.
+///
+/// Different ADF dialects interpret missing conditions differently, hence the policy allows
+/// matching the behavior of other tools.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub enum FreePolicy {
+    /// The statement is left unconstrained (apart from the validity of its dual encoding).
+    #[default]
+    Undefined,
+    /// The statement supports itself, i.e. its condition is the statement itself (`ac(s, s)`).
+    ///
+    /// Note that under admissible and complete semantics, this condition does not restrict
+    /// the statement, so the results are the same as for [`FreePolicy::Undefined`].
+    SelfSupport,
+    /// The condition of the statement is the constant `c(v)`.
+    True,
+    /// The condition of the statement is the constant `c(f)`.
+    False,
+}
+
+/// A progress update of a computation performed by [`AdfInterpretationSolver`]
+/// (see [`AdfInterpretationSolver::solve_admissible_with_channel`]).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Progress {
+    /// The index of the constraint (i.e. statement) that was processed. Once all
+    /// constraints are merged, this is equal to `total`.
+    pub constraint_index: usize,
+    /// The total number of constraints (i.e. statements).
+    pub total: usize,
+    /// The number of BDD nodes of the current constraint (or of the final result).
+    pub node_count: usize,
+}
 
 pub struct AdfInterpretationSolver {
     solver: DynamicBddSolver,
+    free_statement_policy: FreePolicy,
+    node_limit: Option<usize>,
 }
 
 impl<S: BddSolver + 'static> From<S> for AdfInterpretationSolver {
@@ -18,30 +59,194 @@ impl<S: BddSolver + 'static> From<S> for AdfInterpretationSolver {
 impl AdfInterpretationSolver {
     /// Create a new `AdfInterpretationSolver` with the given BDD solver.
     pub fn new(solver: DynamicBddSolver) -> Self {
-        AdfInterpretationSolver { solver }
+        AdfInterpretationSolver {
+            solver,
+            free_statement_policy: FreePolicy::default(),
+            node_limit: None,
+        }
+    }
+
+    /// Update the [`FreePolicy`] used by this solver for statements without a condition.
+    pub fn with_free_statement_policy(mut self, policy: FreePolicy) -> Self {
+        self.free_statement_policy = policy;
+        self
+    }
+
+    /// Get the [`FreePolicy`] used by this solver for statements without a condition.
+    pub fn free_statement_policy(&self) -> FreePolicy {
+        self.free_statement_policy
+    }
+
+    /// Set the maximal number of nodes of the BDDs produced while merging constraints.
+    ///
+    /// The limit is passed to [`BddSolver::solve_conjunction_limited`] of the underlying
+    /// BDD solver and is enforced by the `try_solve_*` methods (e.g.
+    /// [`AdfInterpretationSolver::try_solve_complete`]), which fail with
+    /// [`SolveError::NodeLimitExceeded`] once it is exceeded. The limit is intended for
+    /// memory-constrained environments, where a graceful failure is preferable to running
+    /// out of memory. Note that the limit is not enforced by the `solve_*` methods, since
+    /// [`Cancellable`] can only report cancellation.
+    pub fn with_node_limit(mut self, node_limit: usize) -> Self {
+        self.node_limit = Some(node_limit);
+        self
+    }
+
+    /// Get the node limit of this solver (see [`AdfInterpretationSolver::with_node_limit`]).
+    pub fn node_limit(&self) -> Option<usize> {
+        self.node_limit
+    }
+
+    /// Compute the conjunction of `constraints` using the underlying [`BddSolver`]. The outer
+    /// result reports cancellation, the inner result reports exceeding the `node_limit`.
+    fn solve_conjunction(
+        &self,
+        constraints: &[Bdd],
+        node_limit: Option<usize>,
+    ) -> Cancellable<Result<Bdd, SolveError>> {
+        let result = match node_limit {
+            Some(limit) => self.solver.solve_conjunction_limited(constraints, limit)?,
+            None => Ok(self.solver.solve_conjunction(constraints)?),
+        };
+        if let Err(e) = &result {
+            info!("Aborting computation: {}", e);
+        }
+        Ok(result)
+    }
+
+    /// Get the dual condition of the given statement, or a condition derived from the
+    /// [`FreePolicy`] if the statement is free. Returns `None` if the statement
+    /// should not be constrained at all.
+    fn dual_condition(&self, dual: &DualEncoding, statement: &Statement) -> Option<(Bdd, Bdd)> {
+        if let Some((p_condition, n_condition)) = dual.get_condition(statement) {
+            return Some((p_condition.clone(), n_condition.clone()));
+        }
+        self.free_dual_condition(dual, statement)
+    }
+
+    /// Get the dual condition of a free statement derived from the [`FreePolicy`].
+    /// Returns `None` if the statement should not be constrained at all.
+    fn free_dual_condition(
+        &self,
+        dual: &DualEncoding,
+        statement: &Statement,
+    ) -> Option<(Bdd, Bdd)> {
+        match self.free_statement_policy {
+            FreePolicy::Undefined => None,
+            FreePolicy::SelfSupport => Some(dual.var_map().make_literals(statement)),
+            FreePolicy::True => Some((Bdd::new_true(), Bdd::new_false())),
+            FreePolicy::False => Some((Bdd::new_false(), Bdd::new_true())),
+        }
     }
 
     /// Computes the [`ModelSetTwoValued`] of all complete two valued interpretations of this ADF.
     pub fn solve_complete_two_valued(&self, adf: &AdfBdds) -> Cancellable<ModelSetTwoValued> {
+        expect_unlimited(self.solve_complete_two_valued_internal(adf, None))
+    }
+
+    /// Same as [`AdfInterpretationSolver::solve_complete_two_valued`], but fails with
+    /// [`SolveError::NodeLimitExceeded`] if the [`AdfInterpretationSolver::node_limit`]
+    /// is exceeded.
+    pub fn try_solve_complete_two_valued(
+        &self,
+        adf: &AdfBdds,
+    ) -> Result<ModelSetTwoValued, SolveError> {
+        flatten_limited(self.solve_complete_two_valued_internal(adf, self.node_limit))
+    }
+
+    /// Implementation of [`AdfInterpretationSolver::solve_complete_two_valued`] and
+    /// [`AdfInterpretationSolver::try_solve_complete_two_valued`].
+    fn solve_complete_two_valued_internal(
+        &self,
+        adf: &AdfBdds,
+        node_limit: Option<usize>,
+    ) -> Cancellable<Result<ModelSetTwoValued, SolveError>> {
         info!("Starting computation of complete two-valued interpretations");
 
+        let fixed_point_constraints = self.fixed_point_constraints(adf)?;
+        let result_bdd = match self.solve_conjunction(&fixed_point_constraints, node_limit)? {
+            Ok(result_bdd) => result_bdd,
+            Err(e) => return Ok(Err(e)),
+        };
+
+        let model_set = adf.mk_two_valued_set(result_bdd);
+
+        info!(
+            "Computation complete: resulting BDD has {} nodes",
+            model_set.symbolic_set().node_count()
+        );
+
+        Ok(Ok(model_set))
+    }
+
+    /// Same as [`AdfInterpretationSolver::solve_complete_two_valued`], but if the computation
+    /// is cancelled, the work done so far is not lost.
+    ///
+    /// The fixed-point constraints are merged one by one (smallest first) using the underlying
+    /// [`BddSolver`], instead of passing all of them to the solver at once. If the computation
+    /// is cancelled while merging, the second value contains the conjunction of the
+    /// constraints merged so far. Since this is a conjunction of a subset of the constraints,
+    /// it is always a superset of the complete two-valued interpretations. The second value
+    /// is `None` if the computation succeeded, or if it was cancelled before any merging
+    /// started.
+    pub fn solve_complete_two_valued_anytime(
+        &self,
+        adf: &AdfBdds,
+    ) -> (Cancellable<ModelSetTwoValued>, Option<ModelSetTwoValued>) {
+        info!("Starting anytime computation of complete two-valued interpretations");
+
+        let mut fixed_point_constraints = match self.fixed_point_constraints(adf) {
+            Ok(constraints) => constraints,
+            Err(e) => return (Err(e), None),
+        };
+        fixed_point_constraints.sort_by_key(|it| it.node_count());
+
+        let mut accumulated = Bdd::new_true();
+        for (index, constraint) in fixed_point_constraints.iter().enumerate() {
+            let merged = is_cancelled!().and_then(|_| {
+                self.solver
+                    .solve_conjunction(&[accumulated.clone(), constraint.clone()])
+            });
+            match merged {
+                Ok(merged) => accumulated = merged,
+                Err(e) => {
+                    info!(
+                        "Cancelled after merging {}/{} constraints: partial result has {} nodes",
+                        index,
+                        fixed_point_constraints.len(),
+                        accumulated.node_count()
+                    );
+                    return (Err(e), Some(adf.mk_two_valued_set(accumulated)));
+                }
+            }
+            if accumulated.is_false() {
+                break;
+            }
+        }
+
+        let model_set = adf.mk_two_valued_set(accumulated);
+        info!(
+            "Computation complete: resulting BDD has {} nodes",
+            model_set.symbolic_set().node_count()
+        );
+        (Ok(model_set), None)
+    }
+
+    /// Generate the fixed-point constraints `s <=> ac(s)` of all statements with a condition,
+    /// together with the global constraint of the ADF (if any).
+    fn fixed_point_constraints(&self, adf: &AdfBdds) -> Cancellable<Vec<Bdd>> {
         let direct = adf.direct_encoding();
         let var_map = direct.var_map();
 
         let mut fixed_point_constraints = Vec::new();
         let total_statements = var_map.statements().count();
 
-        for statement in var_map.statements() {
+        // Free statements (without a condition) are not constrained.
+        for (statement, condition) in direct.iter_conditions() {
             is_cancelled!()?;
 
             // Get the BDD literal for this statement
             let statement_lit = var_map.make_literal(statement, true);
 
-            // If condition does not exist, this is a free statement.
-            let Some(condition) = direct.get_condition(statement) else {
-                continue;
-            };
-
             // Fixed point constraint: statement <=> condition
             let constraint = statement_lit.iff(condition);
 
@@ -54,15 +259,104 @@ impl AdfInterpretationSolver {
             fixed_point_constraints.push(constraint);
         }
 
+        if !direct.constraint().is_true() {
+            fixed_point_constraints.push(direct.constraint().clone());
+        }
+
         info!(
             "Generated {} fixed-point constraints from {} statements",
             fixed_point_constraints.len(),
             total_statements
         );
 
-        let result_bdd = self.solver.solve_conjunction(&fixed_point_constraints)?;
+        Ok(fixed_point_constraints)
+    }
 
-        let model_set = adf.mk_two_valued_set(result_bdd);
+    /// Computes the [`ModelSetTwoValued`] of all complete two valued interpretations of this
+    /// ADF by solving the strongly connected components of its dependency graph one by one.
+    ///
+    /// The components are solved in dependency order. Once a statement has the same value
+    /// in all interpretations found so far, this value is substituted into the conditions
+    /// of the remaining components before they are solved. The result is the same as
+    /// for [`AdfInterpretationSolver::solve_complete_two_valued`], but this approach is
+    /// typically faster for modular ADFs with many small components.
+    pub fn solve_complete_two_valued_by_scc(
+        &self,
+        adf: &AdfBdds,
+    ) -> Cancellable<ModelSetTwoValued> {
+        info!("Starting component-wise computation of complete two-valued interpretations");
+
+        let direct = adf.direct_encoding();
+        let var_map = direct.var_map();
+
+        let dependencies: BTreeMap<Statement, BTreeSet<Statement>> = direct
+            .conditional_statements()
+            .map(|s| {
+                let support = direct.condition_support(s).unwrap_or_default();
+                (s.clone(), support.into_iter().collect())
+            })
+            .collect();
+        let components = strongly_connected_components(var_map.statements(), &dependencies);
+
+        info!("Dependency graph has {} components", components.len());
+
+        let mut result = Bdd::new_true();
+        // A conjunction of literals of all statements that are decided in `result`.
+        let mut decided = Bdd::new_true();
+        let mut decided_vars = Vec::new();
+        for component in components {
+            is_cancelled!()?;
+
+            let mut constraints = Vec::new();
+            for statement in &component {
+                let Some(condition) = direct.get_condition(statement) else {
+                    continue;
+                };
+                let condition = if decided_vars.is_empty() {
+                    condition.clone()
+                } else {
+                    condition.and(&decided).exists(&decided_vars)
+                };
+                constraints.push(var_map.make_literal(statement, true).iff(&condition));
+            }
+
+            if constraints.is_empty() {
+                // Free statements do not constrain the result.
+                continue;
+            }
+
+            let component_result = self.solver.solve_conjunction(&constraints)?;
+            result = result.and(&component_result);
+
+            debug!(
+                "Solved component of {} statements: result has {} nodes",
+                component.len(),
+                result.node_count()
+            );
+
+            if result.is_false() {
+                break;
+            }
+
+            for statement in &component {
+                let literal = var_map.make_literal(statement, true);
+                let value = if result.and(&literal.not()).is_false() {
+                    true
+                } else if result.and(&literal).is_false() {
+                    false
+                } else {
+                    continue;
+                };
+                decided = decided.and(&var_map.make_literal(statement, value));
+                decided_vars.push(var_map[statement]);
+            }
+        }
+
+        if !direct.constraint().is_true() {
+            result = result.and(direct.constraint());
+        }
+
+        let model_set = adf.mk_two_valued_set(result);
 
         info!(
             "Computation complete: resulting BDD has {} nodes",
@@ -124,7 +418,7 @@ impl AdfInterpretationSolver {
 
             result = result.union(&k_candidate);
 
-            let looser_models = k_candidate.extend_with_more_ones();
+            let looser_models = k_candidate.try_extend_with_more_ones()?;
             remaining = remaining.minus(&looser_models);
 
             info!(
@@ -242,19 +536,61 @@ impl AdfInterpretationSolver {
 
     /// Computes the [`ModelSetThreeValued`] of all admissible three valued interpretations of this ADF.
     pub fn solve_admissible(&self, adf: &AdfBdds) -> Cancellable<ModelSetThreeValued> {
+        expect_unlimited(self.solve_admissible_with_progress(adf, None, |_| ()))
+    }
+
+    /// Same as [`AdfInterpretationSolver::solve_admissible`], but fails with
+    /// [`SolveError::NodeLimitExceeded`] if the [`AdfInterpretationSolver::node_limit`]
+    /// is exceeded.
+    pub fn try_solve_admissible(&self, adf: &AdfBdds) -> Result<ModelSetThreeValued, SolveError> {
+        flatten_limited(self.solve_admissible_with_progress(adf, self.node_limit, |_| ()))
+    }
+
+    /// Same as [`AdfInterpretationSolver::solve_admissible`], but [`Progress`] updates are
+    /// also sent through the given channel (in addition to the usual log messages).
+    ///
+    /// One update is sent for every statement once its trap constraint is generated
+    /// (the `node_count` is zero for free statements which are not constrained), and a final
+    /// update is sent once all constraints are merged (here, the `node_count` is the size of
+    /// the result). In total, the channel thus receives `total + 1` updates. If the receiver
+    /// is disconnected, the updates are silently dropped.
+    pub fn solve_admissible_with_channel(
+        &self,
+        adf: &AdfBdds,
+        tx: Sender<Progress>,
+    ) -> Cancellable<ModelSetThreeValued> {
+        expect_unlimited(self.solve_admissible_with_progress(adf, None, |progress| {
+            let _ = tx.send(progress);
+        }))
+    }
+
+    /// Implementation of [`AdfInterpretationSolver::solve_admissible`] which reports
+    /// [`Progress`] using the given callback. The inner result reports exceeding
+    /// the `node_limit`.
+    fn solve_admissible_with_progress(
+        &self,
+        adf: &AdfBdds,
+        node_limit: Option<usize>,
+        report: impl Fn(Progress),
+    ) -> Cancellable<Result<ModelSetThreeValued, SolveError>> {
         info!("Starting computation of admissible three-valued interpretations");
 
-        let dual = adf.dual_encoding();
+        let dual = adf.try_dual_encoding()?;
         let var_map = dual.var_map();
 
-        let mut trap_constraints = vec![dual.valid().clone()];
+        let mut trap_constraints = vec![dual.valid().clone(), dual.constraint().clone()];
         let total_statements = var_map.statements().count();
 
-        for statement in var_map.statements() {
+        for (index, statement) in var_map.statements().enumerate() {
             is_cancelled!()?;
 
-            // If condition does not exist, this is a free statement.
-            let Some((p_condition, n_condition)) = dual.get_condition(statement) else {
+            // If condition does not exist, this is a free statement handled by the policy.
+            let Some((p_condition, n_condition)) = self.dual_condition(dual, statement) else {
+                report(Progress {
+                    constraint_index: index,
+                    total: total_statements,
+                    node_count: 0,
+                });
                 continue;
             };
 
@@ -273,7 +609,13 @@ impl AdfInterpretationSolver {
                 statement
             );
 
-            trap_constraints.push(p_constraint.and(&n_constraint));
+            let constraint = p_constraint.and(&n_constraint);
+            report(Progress {
+                constraint_index: index,
+                total: total_statements,
+                node_count: constraint.node_count(),
+            });
+            trap_constraints.push(constraint);
         }
 
         trap_constraints.retain(|it| !it.is_true());
@@ -284,8 +626,76 @@ impl AdfInterpretationSolver {
             total_statements
         );
 
-        let result_bdd = self.solver.solve_conjunction(&trap_constraints)?;
+        let result_bdd = match self.solve_conjunction(&trap_constraints, node_limit)? {
+            Ok(result_bdd) => result_bdd,
+            Err(e) => return Ok(Err(e)),
+        };
+
+        let model_set = adf.mk_three_valued_set(result_bdd);
+
+        info!(
+            "Computation complete: resulting BDD has {} nodes",
+            model_set.symbolic_set().node_count()
+        );
+        report(Progress {
+            constraint_index: total_statements,
+            total: total_statements,
+            node_count: model_set.symbolic_set().node_count(),
+        });
+
+        Ok(Ok(model_set))
+    }
+
+    /// Computes an over-approximation of [`AdfInterpretationSolver::solve_admissible`] which
+    /// only generates the trap constraints of statements in the given `window`.
+    ///
+    /// Statements outside the window are left unconstrained (i.e. they can take any value,
+    /// regardless of their condition). The result is thus always a superset of the admissible
+    /// interpretations, and it is equal to it if the window contains all statements. It is
+    /// *not* sound on its own, but it is cheaper to compute for small windows, so it can
+    /// serve as a pre-filter (e.g. intersecting the results for several windows still yields
+    /// a superset of the admissible interpretations).
+    ///
+    /// # Panics
+    ///
+    /// All statements in the `window` must be present in the ADF.
+    pub fn solve_admissible_windowed(
+        &self,
+        adf: &AdfBdds,
+        window: &[Statement],
+    ) -> Cancellable<ModelSetThreeValued> {
+        info!(
+            "Starting computation of admissible interpretations restricted to {} statements",
+            window.len()
+        );
+
+        let dual = adf.try_dual_encoding()?;
+        let var_map = dual.var_map();
+
+        let mut trap_constraints = vec![dual.valid().clone(), dual.constraint().clone()];
+        for statement in window.iter().collect::<BTreeSet<_>>() {
+            is_cancelled!()?;
+            assert!(
+                var_map.get(statement).is_some(),
+                "Statement `{}` is not present in the ADF",
+                statement
+            );
 
+            let Some((p_condition, n_condition)) = self.dual_condition(dual, statement) else {
+                continue;
+            };
+
+            let p_literal = var_map.make_positive_literal(statement, true);
+            let n_literal = var_map.make_negative_literal(statement, true);
+            trap_constraints.push(
+                p_condition
+                    .implies(&p_literal)
+                    .and(&n_condition.implies(&n_literal)),
+            );
+        }
+
+        trap_constraints.retain(|it| !it.is_true());
+        let result_bdd = self.solver.solve_conjunction(&trap_constraints)?;
         let model_set = adf.mk_three_valued_set(result_bdd);
 
         info!(
@@ -296,22 +706,381 @@ impl AdfInterpretationSolver {
         Ok(model_set)
     }
 
+    /// Computes the [`ModelSetThreeValued`] of all admissible interpretations of a bipolar
+    /// ADF (see [`AdfBdds::is_bipolar`]).
+    ///
+    /// Unlike [`AdfInterpretationSolver::solve_admissible`], this does not need the dual
+    /// encoding of the `adf`: the dual conditions are derived from the direct conditions
+    /// using the polarity of their links, which only requires a substitution instead of
+    /// quantification over all completions of every condition. The validity of the dual
+    /// encoding is then enforced once globally instead of within every constraint.
+    ///
+    /// Since the dual encoding of the `adf` is not computed, the result is bound to
+    /// a separate [`DualEncoding`] over the same statements. It contains the same
+    /// interpretations as the result of [`AdfInterpretationSolver::solve_admissible`], but
+    /// [`ModelSetThreeValued::rebind`] is necessary to combine it with sets that use the
+    /// dual encoding of the `adf`.
+    ///
+    /// # Panics
+    ///
+    /// The ADF must be bipolar.
+    pub fn solve_admissible_bipolar(&self, adf: &AdfBdds) -> Cancellable<ModelSetThreeValued> {
+        info!("Starting computation of admissible three-valued interpretations (bipolar)");
+
+        let direct = adf.direct_encoding();
+        let statements = direct.var_map().statements().cloned().collect::<Vec<_>>();
+        let dual = build_dual_encoding_without_conditions(direct, DualMap::new(&statements))?;
+        let var_map = dual.var_map();
+
+        let mut trap_constraints = vec![dual.valid().clone(), dual.constraint().clone()];
+        let total_statements = var_map.statements().count();
+
+        for statement in var_map.statements() {
+            is_cancelled!()?;
+
+            let (p_condition, n_condition) = match direct.get_condition(statement) {
+                Some(condition) => bipolar_dual_condition(condition, direct.var_map(), var_map)
+                    .unwrap_or_else(|| {
+                        panic!("Condition of statement `{}` is not bipolar", statement)
+                    }),
+                None => match self.free_dual_condition(&dual, statement) {
+                    Some(condition) => condition,
+                    None => continue,
+                },
+            };
+
+            let p_literal = var_map.make_positive_literal(statement, true);
+            let n_literal = var_map.make_negative_literal(statement, true);
+            let constraint = p_condition
+                .implies(&p_literal)
+                .and(&n_condition.implies(&n_literal));
+
+            debug!(
+                "Generated constraint of size {} for statement `{}`",
+                constraint.node_count(),
+                statement
+            );
+
+            trap_constraints.push(constraint);
+        }
+
+        trap_constraints.retain(|it| !it.is_true());
+
+        info!(
+            "Generated {} trap constraints from {} statements",
+            trap_constraints.len(),
+            total_statements
+        );
+
+        let result_bdd = self.solver.solve_conjunction(&trap_constraints)?;
+        let model_set = ModelSetThreeValued::new(result_bdd, Arc::new(dual));
+
+        info!(
+            "Computation complete: resulting BDD has {} nodes",
+            model_set.symbolic_set().node_count()
+        );
+
+        Ok(model_set)
+    }
+
+    /// Computes the grounded three-valued interpretation of this ADF.
+    ///
+    /// The result is a [`ModelSetThreeValued`] with exactly one element. It is computed as
+    /// the least fixed point of the three-valued ADF operator, starting from the interpretation
+    /// where every statement is undefined. See also [`AdfInterpretationSolver::solve_grounded_trace`].
+    pub fn solve_grounded(&self, adf: &AdfBdds) -> Cancellable<ModelSetThreeValued> {
+        let mut trace = self.solve_grounded_trace(adf)?;
+        Ok(trace
+            .pop()
+            .expect("Correctness violation: Grounded trace is empty."))
+    }
+
+    /// Computes the sequence of interpretations (the Kleene sequence) that leads to the
+    /// grounded interpretation of this ADF.
+    ///
+    /// Each element is a [`ModelSetThreeValued`] with exactly one interpretation, representing
+    /// the state after one application of the three-valued ADF operator (the initial
+    /// interpretation where every statement is undefined is not included). The sequence stops
+    /// once a fixed point is reached, so the last element is the grounded interpretation
+    /// and the sequence always contains at least one element.
+    pub fn solve_grounded_trace(&self, adf: &AdfBdds) -> Cancellable<Vec<ModelSetThreeValued>> {
+        info!("Starting computation of the grounded interpretation");
+
+        let dual = adf.try_dual_encoding()?;
+        let var_map = dual.var_map();
+
+        // Initially, every statement is undefined (both dual variables are set).
+        let mut current = Bdd::new_true();
+        for statement in var_map.statements() {
+            let (p_literal, n_literal) = var_map.make_literals(statement);
+            current = current.and(&p_literal).and(&n_literal);
+        }
+
+        let mut trace = Vec::new();
+        loop {
+            let mut next = Bdd::new_true();
+            for statement in var_map.statements() {
+                is_cancelled!()?;
+
+                // A statement can be true (false) if its positive (negative) condition
+                // is satisfiable in the current interpretation.
+                let (can_be_true, can_be_false) = match self.dual_condition(dual, statement) {
+                    Some((p_condition, n_condition)) => (
+                        !current.and(&p_condition).is_false(),
+                        !current.and(&n_condition).is_false(),
+                    ),
+                    // Unconstrained free statements stay undefined.
+                    None => (true, true),
+                };
+
+                let p_literal = var_map.make_positive_literal(statement, can_be_true);
+                let n_literal = var_map.make_negative_literal(statement, can_be_false);
+                next = next.and(&p_literal).and(&n_literal);
+            }
+
+            let is_fixed_point = next.iff(&current).is_true();
+            if trace.is_empty() || !is_fixed_point {
+                trace.push(adf.mk_three_valued_set(next.clone()));
+                debug!("Computed grounded iteration {}", trace.len());
+            }
+
+            if is_fixed_point {
+                break;
+            }
+            current = next;
+        }
+
+        info!(
+            "Computation complete: grounded interpretation found after {} iterations",
+            trace.len()
+        );
+
+        Ok(trace)
+    }
+
+    /// Computes the grounded three-valued interpretation of this ADF using a worklist.
+    ///
+    /// The result is the same as for [`AdfInterpretationSolver::solve_grounded`], but instead
+    /// of applying the three-valued ADF operator to all statements in every iteration,
+    /// a statement is only re-evaluated once the value of some statement in the support of its
+    /// condition changes. Furthermore, conditions are only evaluated with respect to the values
+    /// of their support. This is typically much faster for large sparse ADFs.
+    pub fn solve_grounded_worklist(&self, adf: &AdfBdds) -> Cancellable<ModelSetThreeValued> {
+        info!("Starting computation of the grounded interpretation (worklist)");
+
+        let dual = adf.try_dual_encoding()?;
+        let var_map = dual.var_map();
+
+        // The (can_be_true, can_be_false) conditions together with their supports.
+        let mut conditions = BTreeMap::new();
+        let mut dependents: BTreeMap<Statement, Vec<Statement>> = BTreeMap::new();
+        for statement in var_map.statements() {
+            // Unconstrained free statements stay undefined.
+            let Some((p_condition, n_condition)) = self.dual_condition(dual, statement) else {
+                continue;
+            };
+            let mut variables = p_condition.used_variables();
+            variables.extend(n_condition.used_variables());
+            let support: BTreeSet<Statement> = variables
+                .into_iter()
+                .map(|var| var_map.resolve(var).expect("Dual variable").0)
+                .collect();
+            for input in &support {
+                dependents
+                    .entry(input.clone())
+                    .or_default()
+                    .push(statement.clone());
+            }
+            conditions.insert(statement.clone(), (p_condition, n_condition, support));
+        }
+
+        // Initially, every statement is undefined (both dual variables are set).
+        let mut values: BTreeMap<Statement, (bool, bool)> = var_map
+            .statements()
+            .map(|statement| (statement.clone(), (true, true)))
+            .collect();
+        let mut worklist: VecDeque<Statement> = conditions.keys().cloned().collect();
+        let mut queued: BTreeSet<Statement> = conditions.keys().cloned().collect();
+
+        let mut evaluations = 0usize;
+        while let Some(statement) = worklist.pop_front() {
+            is_cancelled!()?;
+            queued.remove(&statement);
+            evaluations += 1;
+
+            let (p_condition, n_condition, support) = &conditions[&statement];
+            let mut current = Bdd::new_true();
+            for input in support.iter().rev() {
+                let (can_be_true, can_be_false) = values[input];
+                current = var_map
+                    .make_positive_literal(input, can_be_true)
+                    .and(&var_map.make_negative_literal(input, can_be_false))
+                    .and(&current);
+            }
+            let value = (
+                !current.and(p_condition).is_false(),
+                !current.and(n_condition).is_false(),
+            );
+
+            if values[&statement] != value {
+                values.insert(statement.clone(), value);
+                for dependent in dependents.get(&statement).into_iter().flatten() {
+                    if queued.insert(dependent.clone()) {
+                        worklist.push_back(dependent.clone());
+                    }
+                }
+            }
+        }
+
+        let mut result = Bdd::new_true();
+        for (statement, (can_be_true, can_be_false)) in values.iter().rev() {
+            result = var_map
+                .make_positive_literal(statement, *can_be_true)
+                .and(&var_map.make_negative_literal(statement, *can_be_false))
+                .and(&result);
+        }
+
+        info!(
+            "Computation complete: grounded interpretation found after {} condition evaluations",
+            evaluations
+        );
+
+        Ok(adf.mk_three_valued_set(result))
+    }
+
     /// Computes the [`ModelSetThreeValued`] of all complete three valued interpretations of this ADF.
     pub fn solve_complete(&self, adf: &AdfBdds) -> Cancellable<ModelSetThreeValued> {
-        self.solve_complete_internal(adf, &BTreeSet::new())
+        expect_unlimited(self.solve_complete_internal(adf, &BTreeSet::new(), None))
+    }
+
+    /// Same as [`AdfInterpretationSolver::solve_complete`], but fails with
+    /// [`SolveError::NodeLimitExceeded`] if the [`AdfInterpretationSolver::node_limit`]
+    /// is exceeded.
+    ///
+    /// ```
+    /// # use biodivine_adf_solver::{AdfBdds, AdfExpressions, AdfInterpretationSolver};
+    /// # use biodivine_adf_solver::bdd_solver::{NaiveGreedySolver, SolveError};
+    /// let adf = AdfExpressions::parse("ac(a, xor(b, c)).\nac(b, neg(a)).\nac(c, or(a, b)).");
+    /// let adf = AdfBdds::from(&adf.unwrap());
+    /// let solver = AdfInterpretationSolver::from(NaiveGreedySolver::default()).with_node_limit(1);
+    /// let result = solver.try_solve_complete(&adf);
+    /// assert!(matches!(result, Err(SolveError::NodeLimitExceeded { limit: 1, .. })));
+    /// ```
+    pub fn try_solve_complete(&self, adf: &AdfBdds) -> Result<ModelSetThreeValued, SolveError> {
+        flatten_limited(self.solve_complete_internal(adf, &BTreeSet::new(), self.node_limit))
+    }
+
+    /// Computes the two-valued models of this ADF, i.e. the complete three-valued
+    /// interpretations without undefined statements, returned in the direct encoding.
+    ///
+    /// The complete interpretations are computed using [`AdfInterpretationSolver::solve_complete`]
+    /// and the fully decided ones are extracted using
+    /// [`ModelSetThreeValued::two_valued_subset`]. A two-valued interpretation is complete
+    /// if and only if it is a fixed point of the conditions, hence with the
+    /// [`FreePolicy::Undefined`] and [`FreePolicy::SelfSupport`] policies, the result is the same
+    /// as for [`AdfInterpretationSolver::solve_complete_two_valued`] (which is typically faster).
+    /// With [`FreePolicy::True`] or [`FreePolicy::False`], the free statements are additionally
+    /// fixed to the given value.
+    pub fn solve_two_valued_models(&self, adf: &AdfBdds) -> Cancellable<ModelSetTwoValued> {
+        let complete = self.solve_complete(adf)?;
+        is_cancelled!()?;
+        let model_set = complete.two_valued_subset(adf);
+        info!(
+            "Extracted {} two-valued models from {} complete interpretations",
+            model_set.model_count_display(),
+            complete.model_count_display()
+        );
+        Ok(model_set)
+    }
+
+    /// Same as [`AdfInterpretationSolver::solve_complete`], but also returns the grounded
+    /// interpretation (see [`AdfInterpretationSolver::solve_grounded`]), where `None`
+    /// represents an undefined statement.
+    ///
+    /// The grounded interpretation is the least complete interpretation w.r.t. the information
+    /// ordering, hence every complete interpretation extends it, i.e. the complete set is
+    /// not changed by [`ModelSetThreeValued::above`] with the grounded interpretation. With the
+    /// default [`FreePolicy`], the grounded interpretation cached by [`AdfBdds::grounded`]
+    /// is reused (or computed by this solver and cached, see [`AdfBdds::grounded_or_init`]).
+    pub fn solve_complete_with_grounded(
+        &self,
+        adf: &AdfBdds,
+    ) -> Cancellable<(ModelSetThreeValued, BTreeMap<Statement, Option<bool>>)> {
+        let solve_grounded = |adf: &AdfBdds| -> Cancellable<_> {
+            Ok(self
+                .solve_grounded_worklist(adf)?
+                .interpretations()
+                .pop()
+                .expect("Correctness violation: Grounded interpretation is missing."))
+        };
+        let grounded = if self.free_statement_policy == FreePolicy::Undefined {
+            adf.grounded_or_init(solve_grounded)?.clone()
+        } else {
+            solve_grounded(adf)?
+        };
+        let complete = self.solve_complete(adf)?;
+        Ok((complete, grounded))
     }
 
     /// Internal version of complete model computation which allows to explicitly fix
     /// all input variables. This means the result are not all complete models, just the
     /// ones with fixed inputs, but that's often enough (e.g. if searching for preferred models).
+    /// The inner result reports exceeding the `node_limit`.
     fn solve_complete_internal(
         &self,
         adf: &AdfBdds,
         fixed_inputs: &BTreeSet<Statement>,
-    ) -> Cancellable<ModelSetThreeValued> {
+        node_limit: Option<usize>,
+    ) -> Cancellable<Result<ModelSetThreeValued, SolveError>> {
         info!("Starting computation of complete three-valued interpretations");
 
-        let dual = adf.dual_encoding();
+        let trap_constraints = self.complete_constraints(adf, fixed_inputs)?;
+        let result_bdd = match self.solve_conjunction(&trap_constraints, node_limit)? {
+            Ok(result_bdd) => result_bdd,
+            Err(e) => return Ok(Err(e)),
+        };
+
+        let model_set = adf.mk_three_valued_set(result_bdd);
+
+        info!(
+            "Computation complete: resulting BDD has {} nodes",
+            model_set.symbolic_set().node_count()
+        );
+
+        Ok(Ok(model_set))
+    }
+
+    /// Count the complete three-valued interpretations of this ADF.
+    ///
+    /// The result is the same as the [`ModelSetThreeValued::model_count`] of
+    /// [`AdfInterpretationSolver::solve_complete`], but the conjunction of the constraints
+    /// is counted directly and dropped right after, i.e. it is never wrapped into
+    /// a [`ModelSetThreeValued`] that could outlive this call. Note that the conjunction
+    /// itself still has to be computed.
+    pub fn count_complete_three_valued(&self, adf: &AdfBdds) -> Cancellable<f64> {
+        info!("Starting counting of complete three-valued interpretations");
+
+        let trap_constraints = self.complete_constraints(adf, &BTreeSet::new())?;
+        let result_bdd = self.solver.solve_conjunction(&trap_constraints)?;
+        let count = adf.try_dual_encoding()?.count_dual_valuations(&result_bdd);
+
+        info!(
+            "Counting complete: resulting BDD has {} nodes and {} valuations",
+            result_bdd.node_count(),
+            count
+        );
+
+        Ok(count)
+    }
+
+    /// Generate the trap constraints of the complete three-valued interpretations
+    /// (see [`AdfInterpretationSolver::solve_complete_internal`]).
+    fn complete_constraints(
+        &self,
+        adf: &AdfBdds,
+        fixed_inputs: &BTreeSet<Statement>,
+    ) -> Cancellable<Vec<Bdd>> {
+        let dual = adf.try_dual_encoding()?;
         let var_map = dual.var_map();
 
         let mut initial = dual.valid().clone();
@@ -321,7 +1090,7 @@ impl AdfInterpretationSolver {
             initial = initial.and(&p_literal.and(&n_literal).not());
         }
 
-        let mut trap_constraints = vec![initial];
+        let mut trap_constraints = vec![initial, dual.constraint().clone()];
         let total_statements = var_map.statements().count();
 
         for statement in var_map.statements() {
@@ -338,8 +1107,8 @@ impl AdfInterpretationSolver {
                be set to both 0 and 1 in it.
             */
 
-            // If condition does not exist, this is a free statement.
-            let Some((p_condition, n_condition)) = dual.get_condition(statement) else {
+            // If condition does not exist, this is a free statement handled by the policy.
+            let Some((p_condition, n_condition)) = self.dual_condition(dual, statement) else {
                 continue;
             };
 
@@ -352,48 +1121,226 @@ impl AdfInterpretationSolver {
             let n_constraint = n_condition.implies(&n_literal);
 
             let not_fixed = p_literal.and(&n_literal);
-            let can_be_both = p_condition.and(n_condition);
+            let can_be_both = p_condition.and(&n_condition);
             let completeness = not_fixed.implies(&can_be_both);
 
-            debug!(
-                "Generated constraints of size {}/{}/{} for statement `{}`",
-                p_constraint.node_count(),
-                n_constraint.node_count(),
-                completeness.node_count(),
+            debug!(
+                "Generated constraints of size {}/{}/{} for statement `{}`",
+                p_constraint.node_count(),
+                n_constraint.node_count(),
+                completeness.node_count(),
+                statement
+            );
+
+            trap_constraints.push(p_constraint.and(&n_constraint).and(&completeness));
+        }
+
+        trap_constraints.retain(|it| !it.is_true());
+
+        info!(
+            "Generated {} trap constraints from {} statements",
+            trap_constraints.len(),
+            total_statements
+        );
+
+        Ok(trap_constraints)
+    }
+
+    pub fn solve_preferred(&self, adf: &AdfBdds) -> Cancellable<ModelSetThreeValued> {
+        expect_unlimited(self.solve_preferred_internal(adf, None))
+    }
+
+    /// Same as [`AdfInterpretationSolver::solve_preferred`], but fails with
+    /// [`SolveError::NodeLimitExceeded`] if the [`AdfInterpretationSolver::node_limit`]
+    /// is exceeded while computing the complete interpretations.
+    pub fn try_solve_preferred(&self, adf: &AdfBdds) -> Result<ModelSetThreeValued, SolveError> {
+        flatten_limited(self.solve_preferred_internal(adf, self.node_limit))
+    }
+
+    /// Implementation of [`AdfInterpretationSolver::solve_preferred`] and
+    /// [`AdfInterpretationSolver::try_solve_preferred`].
+    fn solve_preferred_internal(
+        &self,
+        adf: &AdfBdds,
+        node_limit: Option<usize>,
+    ) -> Cancellable<Result<ModelSetThreeValued, SolveError>> {
+        info!(
+            "Starting computation of preferred interpretations by finding complete interpretations"
+        );
+
+        let fixed_inputs = self.preferred_fixed_inputs(adf);
+        let remaining = match self.solve_complete_internal(adf, &fixed_inputs, node_limit)? {
+            Ok(remaining) => remaining,
+            Err(e) => return Ok(Err(e)),
+        };
+
+        let result = Self::maximal_models(adf, remaining, &fixed_inputs)?;
+
+        info!(
+            "Computation complete: resulting BDD has {} nodes",
+            result.symbolic_set().node_count()
+        );
+
+        Ok(Ok(result))
+    }
+
+    /// Computes the preferred interpretations that are optimal with respect to the given
+    /// statement `priorities`.
+    ///
+    /// First, the preferred interpretations are computed using
+    /// [`AdfInterpretationSolver::solve_preferred`]. Then, the statements are grouped by
+    /// priority and the groups are processed from the highest priority to the lowest. For
+    /// each group, only the interpretations with the highest number of decided (`0` or `1`)
+    /// statements in that group are kept. The result is thus a subset of the preferred
+    /// interpretations that is optimal in the lexicographic ordering of the per-priority
+    /// decided counts. Statements without a priority are not optimized, i.e. without
+    /// priorities, the result are all preferred interpretations.
+    ///
+    /// # Panics
+    ///
+    /// Every statement in `priorities` must be present in the ADF.
+    pub fn solve_preferred_prioritized(
+        &self,
+        adf: &AdfBdds,
+        priorities: &BTreeMap<Statement, u32>,
+    ) -> Cancellable<ModelSetThreeValued> {
+        info!("Starting computation of prioritized preferred interpretations");
+
+        let preferred = self.solve_preferred(adf)?;
+        let dual_map = adf.try_dual_encoding()?.var_map();
+        let direct_map = adf.direct_encoding().var_map();
+
+        let mut groups: BTreeMap<u32, Vec<&Statement>> = BTreeMap::new();
+        for (statement, priority) in priorities {
+            assert!(
+                direct_map.get(statement).is_some(),
+                "Statement {} not found in the ADF",
                 statement
             );
-
-            trap_constraints.push(p_constraint.and(&n_constraint).and(&completeness));
+            groups.entry(*priority).or_default().push(statement);
         }
 
-        trap_constraints.retain(|it| !it.is_true());
+        let mut result = preferred.symbolic_set().clone();
+        for (priority, group) in groups.into_iter().rev() {
+            // Same as in `mk_exactly_k_free_statements`: build the cardinality constraint
+            // over direct variables and then substitute them with `p_var & n_var`.
+            let direct_vars = group.iter().map(|s| direct_map[*s]).collect::<Vec<_>>();
+            for undefined_count in 0..=group.len() {
+                is_cancelled!()?;
 
-        info!(
-            "Generated {} trap constraints from {} statements",
-            trap_constraints.len(),
-            total_statements
-        );
+                let mut exactly_k = Bdd::new_sat_exactly_k(undefined_count, &direct_vars);
+                for statement in &group {
+                    let (p_lit, n_lit) = dual_map.make_literals(statement);
+                    exactly_k =
+                        exactly_k.safe_substitution(direct_map[*statement], &p_lit.and(&n_lit));
+                }
 
-        let result_bdd = self.solver.solve_conjunction(&trap_constraints)?;
+                let candidate = result.and(&exactly_k);
+                if !candidate.is_false() {
+                    debug!(
+                        "Priority {}: at most {} of {} statements undefined",
+                        priority,
+                        undefined_count,
+                        group.len()
+                    );
+                    result = candidate;
+                    break;
+                }
+            }
+        }
 
-        let model_set = adf.mk_three_valued_set(result_bdd);
+        let result = adf.mk_three_valued_set(result);
 
         info!(
             "Computation complete: resulting BDD has {} nodes",
-            model_set.symbolic_set().node_count()
+            result.symbolic_set().node_count()
         );
 
-        Ok(model_set)
+        Ok(result)
     }
 
-    pub fn solve_preferred(&self, adf: &AdfBdds) -> Cancellable<ModelSetThreeValued> {
+    /// A parallel variant of [`AdfInterpretationSolver::solve_preferred`].
+    ///
+    /// The complete interpretations are split into three branches based on the value
+    /// (`0`, `1` or `*`) of a single pivot statement. The maximal interpretations of each
+    /// branch are computed concurrently (using `rayon`), and then the results are merged.
+    /// During merging, interpretations where the pivot is `*` are removed if they are looser
+    /// than some maximal interpretation from the other two branches (interpretations with
+    /// a fixed pivot cannot be looser than interpretations from any other branch).
+    ///
+    /// The result is the same as for [`AdfInterpretationSolver::solve_preferred`].
+    /// Cancellation triggers of the calling thread are propagated to the worker threads,
+    /// and once some branch is cancelled, the remaining branches are not started.
+    pub fn solve_preferred_parallel(&self, adf: &AdfBdds) -> Cancellable<ModelSetThreeValued> {
         info!(
-            "Starting computation of preferred interpretations by finding complete interpretations"
+            "Starting parallel computation of preferred interpretations by finding complete interpretations"
+        );
+
+        let fixed_inputs = self.preferred_fixed_inputs(adf);
+        let complete = expect_unlimited(self.solve_complete_internal(adf, &fixed_inputs, None))?;
+
+        // The pivot must be able to take all three values, hence it cannot be a fixed input.
+        let Some(pivot) = adf.statements().find(|s| !fixed_inputs.contains(*s)) else {
+            info!("No pivot statement available; falling back to sequential minimization");
+            return Self::maximal_models(adf, complete, &fixed_inputs);
+        };
+
+        info!("Splitting complete interpretations using pivot `{}`", pivot);
+
+        let dual_map = adf.try_dual_encoding()?.var_map();
+        let (p_literal, n_literal) = dual_map.make_literals(pivot);
+        let branches = [
+            p_literal.and(&n_literal.not()),
+            p_literal.not().and(&n_literal),
+            p_literal.and(&n_literal),
+        ]
+        .map(|branch| complete.intersect(&adf.mk_three_valued_set(branch)));
+
+        let mut results = par_map_cancellable(Vec::from(branches), |branch| {
+            Self::maximal_models(adf, branch, &fixed_inputs)
+        })?;
+        let undefined_pivot = results
+            .pop()
+            .expect("Correctness violation: Missing branch.");
+        let fixed_pivot = results[0].union(&results[1]);
+
+        // Remove interpretations with `pivot=*` that are looser than some fixed-pivot result.
+        let looser_models = fixed_pivot.try_extend_with_looser_models(&fixed_inputs)?;
+        let result = fixed_pivot.union(&undefined_pivot.minus(&looser_models));
+
+        info!(
+            "Computation complete: resulting BDD has {} nodes",
+            result.symbolic_set().node_count()
         );
 
-        let fixed_inputs = adf.free_statements();
-        let mut remaining = self.solve_complete_internal(adf, &fixed_inputs)?;
+        Ok(result)
+    }
+
+    /// Statements that are fixed (i.e. cannot be `*`) when computing preferred interpretations.
+    fn preferred_fixed_inputs(&self, adf: &AdfBdds) -> BTreeSet<Statement> {
+        let mut fixed_inputs = adf.free_statements();
+        if matches!(
+            self.free_statement_policy,
+            FreePolicy::True | FreePolicy::False
+        ) {
+            // Statements without a condition are constants under these policies.
+            let direct = adf.direct_encoding();
+            fixed_inputs.retain(|s| direct.get_condition(s).is_some());
+        }
+        fixed_inputs
+    }
 
+    /// Compute the subset of `remaining` interpretations that are maximal w.r.t. the
+    /// information ordering, i.e. those that are not looser than any other interpretation
+    /// in the set.
+    ///
+    /// This is the shared maximality filter of the preferred semantics. Cancellation is
+    /// checked in every iteration as well as while computing the looser interpretations.
+    fn maximal_models(
+        adf: &AdfBdds,
+        mut remaining: ModelSetThreeValued,
+        fixed_inputs: &BTreeSet<Statement>,
+    ) -> Cancellable<ModelSetThreeValued> {
         info!(
             "Starting minimization process with {} BDD nodes.",
             remaining.symbolic_set().node_count()
@@ -401,7 +1348,11 @@ impl AdfInterpretationSolver {
 
         let mut result = adf.mk_three_valued_set(Bdd::new_false());
 
+        let mut iteration = 0;
         while !remaining.is_empty() {
+            is_cancelled!()?;
+            iteration += 1;
+
             let preferred_model = remaining.most_fixed_model();
 
             // Compute the number of free statements in the preferred model:
@@ -436,7 +1387,7 @@ impl AdfInterpretationSolver {
 
             result = result.union(&k_preferred);
 
-            let looser_models = k_preferred.extend_with_looser_models(&fixed_inputs);
+            let looser_models = k_preferred.try_extend_with_looser_models(fixed_inputs)?;
             remaining = remaining.minus(&looser_models);
 
             info!(
@@ -444,26 +1395,111 @@ impl AdfInterpretationSolver {
                 remaining.symbolic_set().node_count(),
                 result.symbolic_set().node_count(),
             );
+            trace!(
+                "Maximality filter [iteration {}]: {} maximal models found, {} remaining.",
+                iteration,
+                result.model_count_display(),
+                remaining.model_count_display(),
+            );
         }
 
-        info!(
-            "Computation complete: resulting BDD has {} nodes",
-            result.symbolic_set().node_count()
-        );
-
         Ok(result)
     }
 }
 
+/// Unwrap the result of a computation without a node limit, which can only be cancelled.
+fn expect_unlimited<T>(result: Cancellable<Result<T, SolveError>>) -> Cancellable<T> {
+    Ok(result?.expect("Correctness violation: Node limit exceeded without a limit."))
+}
+
+/// Merge the cancellation of a computation with a node limit into its [`SolveError`].
+fn flatten_limited<T>(result: Cancellable<Result<T, SolveError>>) -> Result<T, SolveError> {
+    result.unwrap_or(Err(SolveError::Cancelled))
+}
+
+/// Apply `function` to all `items` in parallel (using `rayon`), failing if any of the
+/// calls is cancelled.
+///
+/// Cancellation triggers are thread-local, hence the triggers of the calling thread are not
+/// visible to the worker threads. To propagate them, the calling thread only waits for the
+/// workers and checks its triggers periodically (the triggers of `cancel_this` can only be
+/// polled, they do not notify anyone once cancelled). Once it is cancelled, it cancels
+/// a shared token that is observed by all workers. The workers stop at the first cancelled
+/// call. The calling thread is woken up as soon as the workers finish.
+fn par_map_cancellable<I: Send, T: Send>(
+    items: Vec<I>,
+    function: impl Fn(I) -> Cancellable<T> + Sync,
+) -> Cancellable<Vec<T>> {
+    let token = crate::CancelToken::new();
+    let caller = std::thread::current();
+    let results = std::thread::scope(|scope| {
+        let workers = scope.spawn(|| {
+            let results = items
+                .into_par_iter()
+                .map(|item| {
+                    cancel_this::on_trigger(token.clone(), || {
+                        is_cancelled!()?;
+                        function(item)
+                    })
+                })
+                .collect::<Cancellable<Vec<_>>>();
+            caller.unpark();
+            results
+        });
+        while !workers.is_finished() {
+            if is_cancelled!().is_err() {
+                token.cancel();
+            }
+            std::thread::park_timeout(std::time::Duration::from_millis(10));
+        }
+        workers.join().unwrap_or_else(std::panic::resume_unwind)
+    });
+    is_cancelled!()?;
+    results
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::bdd_solver::NaiveGreedySolver;
+    use rstest::rstest;
 
     fn create_test_solver() -> AdfInterpretationSolver {
         AdfInterpretationSolver::from(NaiveGreedySolver::default())
     }
 
+    #[test]
+    fn test_solve_two_valued_models() {
+        let solver = create_test_solver();
+        for adf_str in [
+            "ac(a, neg(b)).\nac(b, neg(a)).\ns(c).",
+            "ac(a, neg(a)).\nac(b, c(v)).",
+            "ac(a, or(a, b)).\nac(b, and(a, neg(c))).\nac(c, xor(a, b)).",
+        ] {
+            let expr_adf = crate::AdfExpressions::parse(adf_str).unwrap();
+            let adf = AdfBdds::from(&expr_adf);
+            let expected = solver.solve_complete_two_valued(&adf).unwrap();
+            let result = solver.solve_two_valued_models(&adf).unwrap();
+            assert!(result == expected);
+        }
+
+        // Free statements are fixed by the policy.
+        let expr_adf = crate::AdfExpressions::parse("ac(a, b).\ns(b).").unwrap();
+        let adf = AdfBdds::from(&expr_adf);
+        let solver = create_test_solver().with_free_statement_policy(FreePolicy::True);
+        assert_eq!(
+            solver.solve_two_valued_models(&adf).unwrap().model_count(),
+            1.0
+        );
+        assert_eq!(
+            solver
+                .solve_complete_two_valued(&adf)
+                .unwrap()
+                .model_count(),
+            2.0
+        );
+    }
+
     #[test]
     fn test_solve_simple_adf_constant_true() {
         let solver = create_test_solver();
@@ -548,6 +1584,7 @@ mod tests {
         // For one statement: {T}, {U}, or {T,U} are valid three-valued interpretations
         // With the constraint, we must allow T, so we have: {T} and {T,U}
         assert_eq!(model_set.model_count(), 2.0);
+        assert!(adf.contains_empty_interpretation(&model_set));
     }
 
     #[test]
@@ -569,6 +1606,7 @@ mod tests {
         // Statement 0 depends on 1, statement 1 has constant true
         // This means both statements can be * or 1, but not 0. Valid statements are: **, *1, 11.
         assert_eq!(model_set.model_count(), 3.0);
+        assert!(adf.contains_empty_interpretation(&model_set));
     }
 
     #[test]
@@ -590,6 +1628,275 @@ mod tests {
         // Free statements don't add constraints, so we just have the constraint from statement 0.
         // Plus the valid constraint requiring at least one dual variable per statement.
         assert_eq!(model_set.model_count(), 6.0);
+        assert!(adf.contains_empty_interpretation(&model_set));
+    }
+
+    #[test]
+    fn test_solve_admissible_free_statement_policy() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+            ac(0, c(v)).
+        "#;
+        let expr_adf = crate::AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+
+        let count = |policy: FreePolicy| {
+            let solver = create_test_solver().with_free_statement_policy(policy);
+            assert_eq!(solver.free_statement_policy(), policy);
+            solver
+                .solve_admissible(&adf)
+                .expect("Solving should not be cancelled")
+                .model_count()
+        };
+
+        // Undefined and self-supporting free statements can be 0, 1, or *.
+        assert_eq!(count(FreePolicy::Undefined), 6.0);
+        assert_eq!(count(FreePolicy::SelfSupport), 6.0);
+        // Constant free statements can be either their constant value, or *.
+        assert_eq!(count(FreePolicy::True), 4.0);
+        assert_eq!(count(FreePolicy::False), 4.0);
+    }
+
+    #[test]
+    fn test_solve_complete_free_statement_policy() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+            ac(0, 1).
+        "#;
+        let expr_adf = crate::AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+
+        let count = |policy: FreePolicy| {
+            create_test_solver()
+                .with_free_statement_policy(policy)
+                .solve_complete(&adf)
+                .expect("Solving should not be cancelled")
+                .model_count()
+        };
+
+        // Statement 0 copies the value of the free statement 1.
+        assert_eq!(count(FreePolicy::Undefined), 3.0);
+        assert_eq!(count(FreePolicy::SelfSupport), 3.0);
+        // With a constant condition, the only complete interpretation is the fixed one.
+        assert_eq!(count(FreePolicy::True), 1.0);
+        assert_eq!(count(FreePolicy::False), 1.0);
+    }
+
+    #[test]
+    fn test_solve_preferred_free_statement_policy() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+            ac(0, neg(1)).
+        "#;
+        let expr_adf = crate::AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+
+        let undefined = create_test_solver()
+            .solve_preferred(&adf)
+            .expect("Solving should not be cancelled");
+        // The free statement is fixed to either 0 or 1, and statement 0 follows.
+        assert_eq!(undefined.model_count(), 2.0);
+
+        let fixed = create_test_solver()
+            .with_free_statement_policy(FreePolicy::True)
+            .solve_preferred(&adf)
+            .expect("Solving should not be cancelled");
+        // The free statement is always true, meaning statement 0 is false.
+        assert_eq!(fixed.model_count(), 1.0);
+    }
+
+    #[test]
+    fn test_solve_grounded_trace() {
+        let solver = create_test_solver();
+        let adf_str = r#"
+            s(a).
+            s(b).
+            s(c).
+            s(d).
+            ac(a, c(v)).
+            ac(b, a).
+            ac(c, neg(b)).
+            ac(d, d).
+        "#;
+        let expr_adf = crate::AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+        let dual_map = adf.dual_encoding().var_map();
+
+        let trace = solver
+            .solve_grounded_trace(&adf)
+            .expect("Solving should not be cancelled");
+
+        // The information propagates from `a` to `b` and then to `c`. Statement `d` stays
+        // undefined.
+        assert_eq!(trace.len(), 3);
+        for interpretation in &trace {
+            assert_eq!(interpretation.model_count(), 1.0);
+        }
+
+        let expected = [("a", true, false), ("b", true, false), ("c", false, true)];
+        let mut expected_bdd = dual_map
+            .make_positive_literal(&Statement::from("d"), true)
+            .and(&dual_map.make_negative_literal(&Statement::from("d"), true));
+        for (name, p_value, n_value) in expected {
+            let statement = Statement::from(name);
+            expected_bdd = expected_bdd
+                .and(&dual_map.make_positive_literal(&statement, p_value))
+                .and(&dual_map.make_negative_literal(&statement, n_value));
+        }
+        assert!(trace[2].symbolic_set().iff(&expected_bdd).is_true());
+
+        // The first step only fixes `a`.
+        let a_true = dual_map.make_negative_literal(&Statement::from("a"), false);
+        let b_undefined = dual_map.make_negative_literal(&Statement::from("b"), true);
+        assert!(trace[0].symbolic_set().implies(&a_true).is_true());
+        assert!(trace[0].symbolic_set().implies(&b_undefined).is_true());
+
+        let grounded = solver
+            .solve_grounded(&adf)
+            .expect("Solving should not be cancelled");
+        assert!(grounded == trace[2]);
+
+        // The grounded interpretation is also complete.
+        let complete = solver
+            .solve_complete(&adf)
+            .expect("Solving should not be cancelled");
+        assert!(!complete.intersect(&grounded).is_empty());
+    }
+
+    #[test]
+    fn test_solve_grounded_trace_fixed_initial() {
+        let solver = create_test_solver();
+        let adf_str = r#"
+            s(0).
+            s(1).
+            ac(0, 1).
+            ac(1, 0).
+        "#;
+        let expr_adf = crate::AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+
+        let trace = solver
+            .solve_grounded_trace(&adf)
+            .expect("Solving should not be cancelled");
+
+        // The all-undefined interpretation is already grounded.
+        assert_eq!(trace.len(), 1);
+        assert_eq!(trace[0].model_count(), 1.0);
+        let dual_map = adf.dual_encoding().var_map();
+        for statement in adf.statements() {
+            let (p_literal, n_literal) = dual_map.make_literals(statement);
+            let undefined = p_literal.and(&n_literal);
+            assert!(trace[0].symbolic_set().implies(&undefined).is_true());
+        }
+    }
+
+    #[rstest]
+    #[case("s(0).\ns(1).\nac(0, c(v)).")]
+    #[case("s(0).\ns(1).\nac(0, 1).\nac(1, 0).")]
+    #[case("s(0).\ns(1).\nac(0, neg(1)).\nac(1, neg(0)).")]
+    #[case("s(a).\ns(b).\ns(c).\nac(a, neg(b)).\nac(b, neg(a)).\nac(c, and(a, neg(c))).")]
+    #[case("s(a).\ns(b).\ns(c).\nac(a, or(b, c)).\nac(b, a).\nac(c, neg(c)).")]
+    fn test_solve_preferred_parallel(#[case] adf_str: &str) {
+        let solver = create_test_solver();
+        let expr_adf = crate::AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+
+        let sequential = solver
+            .solve_preferred(&adf)
+            .expect("Solving should not be cancelled");
+        let parallel = solver
+            .solve_preferred_parallel(&adf)
+            .expect("Solving should not be cancelled");
+
+        assert!(
+            sequential
+                .symbolic_set()
+                .iff(parallel.symbolic_set())
+                .is_true()
+        );
+    }
+
+    #[test]
+    fn test_par_map_cancellable() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::{Duration, Instant};
+
+        let items = vec![1, 2, 3];
+        let result = par_map_cancellable(items.clone(), |item| Ok(item * 2));
+        assert_eq!(result.unwrap(), vec![2, 4, 6]);
+
+        // The token is only cancelled once some worker is running, hence the workers
+        // must observe the trigger of the calling thread.
+        let started = AtomicUsize::new(0);
+        let gave_up = AtomicUsize::new(0);
+        let token = crate::CancelToken::new();
+        let trigger = token.clone();
+        let start = Instant::now();
+        let result = std::thread::scope(|scope| {
+            scope.spawn(|| {
+                while started.load(Ordering::SeqCst) == 0 {
+                    std::thread::yield_now();
+                }
+                trigger.cancel();
+            });
+            cancel_this::on_trigger(token, || {
+                par_map_cancellable(items, |_| {
+                    started.fetch_add(1, Ordering::SeqCst);
+                    // Give up after ten seconds if the cancellation is not propagated.
+                    for _ in 0..1000 {
+                        is_cancelled!()?;
+                        std::thread::sleep(Duration::from_millis(10));
+                    }
+                    gave_up.fetch_add(1, Ordering::SeqCst);
+                    Ok(())
+                })
+            })
+        });
+        assert!(result.is_err());
+        assert_eq!(gave_up.load(Ordering::SeqCst), 0);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_solve_with_constraint() {
+        let solver = create_test_solver();
+        let adf_str = r#"
+            s(0).
+            s(1).
+            ac(0, 1).
+            ac(1, 0).
+        "#;
+        let expr_adf = crate::AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let constraint = crate::ConditionExpression::parse("neg(and(0, 1))").unwrap();
+        let adf = AdfBdds::from(&expr_adf).with_constraint(&constraint);
+
+        // Without the constraint, (0,0) and (1,1) are both complete.
+        let two_valued = solver
+            .solve_complete_two_valued(&adf)
+            .expect("Solving should not be cancelled");
+        assert_eq!(two_valued.model_count(), 1.0);
+
+        let stable = solver
+            .solve_stable_two_valued(&adf)
+            .expect("Solving should not be cancelled");
+        assert_eq!(stable.model_count(), 1.0);
+
+        // Without the constraint, (0,0), (1,1), and (*,*) are complete.
+        let complete = solver
+            .solve_complete(&adf)
+            .expect("Solving should not be cancelled");
+        assert_eq!(complete.model_count(), 1.0);
+
+        let admissible = solver
+            .solve_admissible(&adf)
+            .expect("Solving should not be cancelled");
+        let unconstrained = solver
+            .solve_admissible(&AdfBdds::from(&expr_adf))
+            .expect("Solving should not be cancelled");
+        assert!(admissible.model_count() < unconstrained.model_count());
     }
 
     #[test]
@@ -724,6 +2031,102 @@ mod tests {
         assert_eq!(model_set.model_count(), 2.0);
     }
 
+    #[rstest]
+    #[case(FreePolicy::Undefined)]
+    #[case(FreePolicy::True)]
+    fn test_solve_complete_with_grounded(#[case] policy: FreePolicy) {
+        let solver = create_test_solver().with_free_statement_policy(policy);
+        let adf_str = "ac(a, neg(b)).\nac(b, c(v)).\nac(c, or(c, d)).\nac(d, neg(e)).\ns(e).";
+        let adf = AdfBdds::from(&crate::AdfExpressions::parse(adf_str).unwrap());
+
+        let (complete, grounded) = solver.solve_complete_with_grounded(&adf).unwrap();
+        assert!(complete == solver.solve_complete(&adf).unwrap());
+        let expected = solver.solve_grounded(&adf).unwrap().interpretations();
+        assert_eq!(vec![grounded.clone()], expected);
+
+        // Every complete interpretation extends the grounded one.
+        assert!(complete.above(&grounded) == complete);
+        assert_eq!(grounded[&Statement::from("a")], Some(false));
+        assert_eq!(grounded[&Statement::from("b")], Some(true));
+    }
+
+    #[rstest]
+    #[case("ac(a, neg(b)).\nac(b, neg(a)).\ns(c).")]
+    #[case("ac(a, neg(a)).\nac(b, c(v)).")]
+    #[case("ac(a, or(a, b)).\nac(b, and(a, neg(c))).\nac(c, xor(a, b)).")]
+    fn test_count_complete_three_valued(#[case] adf_str: &str) {
+        let solver = create_test_solver();
+        let adf = AdfBdds::from(&crate::AdfExpressions::parse(adf_str).unwrap());
+        let expected = solver.solve_complete(&adf).unwrap().model_count();
+        assert_eq!(solver.count_complete_three_valued(&adf).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_maximal_models_cancellation() {
+        let solver = create_test_solver();
+        let adf_str = "ac(a, neg(b)).\nac(b, neg(a)).\nac(c, or(a, c)).";
+        let adf = AdfBdds::from(&crate::AdfExpressions::parse(adf_str).unwrap());
+        let fixed_inputs = solver.preferred_fixed_inputs(&adf);
+        let complete = solver
+            .solve_complete_internal(&adf, &fixed_inputs, None)
+            .unwrap()
+            .unwrap();
+
+        let maximal =
+            AdfInterpretationSolver::maximal_models(&adf, complete.clone(), &fixed_inputs).unwrap();
+        assert!(maximal == solver.solve_preferred(&adf).unwrap());
+
+        let token = crate::CancelToken::new();
+        token.cancel();
+        let result = cancel_this::on_trigger(token, || {
+            AdfInterpretationSolver::maximal_models(&adf, complete, &fixed_inputs)
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_node_limit() {
+        let adf_str = "ac(a, xor(b, c)).\nac(b, neg(a)).\nac(c, or(a, b)).\nac(d, iff(c, a)).";
+        let adf = AdfBdds::from(&crate::AdfExpressions::parse(adf_str).unwrap());
+        let unlimited = create_test_solver();
+        assert_eq!(unlimited.node_limit(), None);
+        let expected = unlimited.solve_complete(&adf).unwrap();
+
+        let limited = create_test_solver().with_node_limit(1_000_000);
+        assert_eq!(limited.node_limit(), Some(1_000_000));
+        assert!(limited.try_solve_complete(&adf).unwrap() == expected);
+        let preferred = unlimited.solve_preferred(&adf).unwrap();
+        assert!(limited.try_solve_preferred(&adf).unwrap() == preferred);
+
+        let limited = create_test_solver().with_node_limit(2);
+        let error = limited.try_solve_complete(&adf).unwrap_err();
+        assert!(matches!(
+            error,
+            SolveError::NodeLimitExceeded { limit: 2, node_count } if node_count > 2
+        ));
+        for error in [
+            limited.try_solve_admissible(&adf).unwrap_err(),
+            limited.try_solve_preferred(&adf).unwrap_err(),
+            limited.try_solve_complete_two_valued(&adf).unwrap_err(),
+        ] {
+            assert!(matches!(
+                error,
+                SolveError::NodeLimitExceeded { limit: 2, .. }
+            ));
+        }
+        // The plain methods do not enforce the limit.
+        assert!(limited.solve_complete(&adf).unwrap() == expected);
+
+        let token = crate::CancelToken::new();
+        token.cancel();
+        let error = cancel_this::on_trigger(token, || -> Cancellable<_> {
+            Ok(limited.try_solve_complete(&adf))
+        })
+        .unwrap()
+        .unwrap_err();
+        assert_eq!(error, SolveError::Cancelled);
+    }
+
     // Tests for solve_stable_two_valued
 
     #[test]
@@ -981,4 +2384,259 @@ mod tests {
         assert_eq!(model_set.model_count(), 1.0);
         assert!(!model_set.is_empty());
     }
+
+    #[test]
+    fn test_solve_self_referential_condition() {
+        let solver = create_test_solver();
+        let expr_adf = crate::AdfExpressions::parse("ac(0, 0).").expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+
+        // The condition is `0 <=> 0`, hence statement 0 can be chosen freely.
+        let two_valued = solver
+            .solve_complete_two_valued(&adf)
+            .expect("Solving should not be cancelled");
+        assert_eq!(two_valued.model_count(), 2.0);
+
+        // In three-valued semantics, the self-supporting value can also remain undecided.
+        let complete = solver
+            .solve_complete(&adf)
+            .expect("Solving should not be cancelled");
+        assert_eq!(complete.model_count(), 3.0);
+    }
+
+    #[rstest]
+    #[case("s(a).\nac(b, neg(a)).\nac(c, or(b, d)).\nac(d, and(c, neg(e))).\nac(e, neg(d)).")]
+    #[case("ac(a, c(v)).\nac(b, and(a, neg(c))).\nac(c, neg(b)).\nac(d, xor(b, d)).")]
+    #[case("ac(a, neg(a)).\nac(b, a).")]
+    #[case("s(a).\ns(b).\nac(c, iff(a, b)).")]
+    fn test_solve_complete_two_valued_by_scc(#[case] adf_str: &str) {
+        let solver = create_test_solver();
+        let expr_adf = crate::AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+
+        let monolithic = solver
+            .solve_complete_two_valued(&adf)
+            .expect("Solving should not be cancelled");
+        let by_scc = solver
+            .solve_complete_two_valued_by_scc(&adf)
+            .expect("Solving should not be cancelled");
+        assert!(monolithic == by_scc);
+    }
+
+    #[test]
+    fn test_solve_preferred_prioritized() {
+        let solver = create_test_solver();
+        // Statements `a` and `b` attack each other, `c` attacks itself.
+        let adf_str = r#"
+            ac(a, neg(b)).
+            ac(b, neg(a)).
+            ac(c, neg(c)).
+        "#;
+        let expr_adf = crate::AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+        let a = Statement::from("a");
+        let b = Statement::from("b");
+        let preferred = solver.solve_preferred(&adf).unwrap();
+
+        // Without priorities, all preferred interpretations are kept.
+        let unprioritized = solver
+            .solve_preferred_prioritized(&adf, &BTreeMap::new())
+            .unwrap();
+        assert!(unprioritized == preferred);
+
+        // Both preferred interpretations decide `a`, so a partial priority keeps both.
+        let partial = solver
+            .solve_preferred_prioritized(&adf, &BTreeMap::from([(a.clone(), 1)]))
+            .unwrap();
+        assert!(partial == preferred);
+
+        let priorities = BTreeMap::from([(a.clone(), 2), (b.clone(), 1)]);
+        let result = solver
+            .solve_preferred_prioritized(&adf, &priorities)
+            .unwrap();
+        assert!(result == preferred);
+        let classification = result.statement_classification();
+        assert_eq!(
+            classification[&Statement::from("c")],
+            crate::StatementStatus::AlwaysUndefined
+        );
+        assert_eq!(classification[&a], crate::StatementStatus::Varies);
+    }
+
+    #[test]
+    fn test_solve_preferred_prioritized_selects_preferred() {
+        let solver = create_test_solver();
+        // The preferred interpretations are {a=1, b=0, c=*, d=0} and {a=0, b=1, c=0, d=1}.
+        // Prioritizing `c` selects the second one. The admissible (but not preferred)
+        // interpretation {a=0, b=1, c=0, d=*} also decides `c`, but it is not returned.
+        let adf_str = r#"
+            ac(a, neg(b)).
+            ac(b, neg(a)).
+            ac(c, and(a, neg(c))).
+            ac(d, neg(a)).
+        "#;
+        let adf = AdfBdds::from(&crate::AdfExpressions::parse(adf_str).unwrap());
+        let preferred = solver.solve_preferred(&adf).unwrap();
+
+        let c = Statement::from("c");
+        let result = solver
+            .solve_preferred_prioritized(&adf, &BTreeMap::from([(c.clone(), 1)]))
+            .unwrap();
+        assert_eq!(preferred.model_count(), 2.0);
+        assert_eq!(result.model_count(), 1.0);
+        assert!(result.minus(&preferred).is_empty());
+        for interpretation in result.interpretations() {
+            assert!(interpretation[&c].is_some());
+        }
+    }
+
+    #[rstest]
+    #[case("s(a).\ns(b).\nac(a, neg(b)).\nac(b, neg(a)).", FreePolicy::Undefined)]
+    #[case(
+        "s(a).\ns(b).\ns(c).\nac(a, and(b, neg(c))).\nac(b, or(a, c)).\nac(c, neg(c)).",
+        FreePolicy::Undefined
+    )]
+    #[case(
+        "s(a).\ns(b).\ns(c).\nac(a, imp(b, c)).\nac(b, c(v)).",
+        FreePolicy::False
+    )]
+    #[case(
+        "s(a).\ns(b).\ns(c).\nac(a, or(b, neg(c))).\nac(c, a).",
+        FreePolicy::SelfSupport
+    )]
+    fn test_solve_admissible_bipolar(#[case] adf: &str, #[case] policy: FreePolicy) {
+        let solver = create_test_solver().with_free_statement_policy(policy);
+        let adf = AdfBdds::from(&crate::AdfExpressions::parse(adf).unwrap());
+        assert!(adf.is_bipolar());
+
+        let result = solver.solve_admissible_bipolar(&adf).unwrap();
+        // The bipolar solver does not need the dual encoding of the ADF.
+        assert!(!adf.has_dual_encoding());
+
+        let expected = solver.solve_admissible(&adf).unwrap();
+        assert_eq!(result.model_count(), expected.model_count());
+        let result = result
+            .rebind(adf.dual_encoding_arc().clone())
+            .expect("Encodings are compatible");
+        assert!(result == expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not bipolar")]
+    fn test_solve_admissible_bipolar_not_bipolar() {
+        let adf = "s(a).\ns(b).\nac(a, xor(a, b)).\nac(b, b).";
+        let adf = AdfBdds::from(&crate::AdfExpressions::parse(adf).unwrap());
+        let _ = create_test_solver().solve_admissible_bipolar(&adf);
+    }
+
+    #[test]
+    fn test_solve_admissible_with_channel() {
+        let solver = create_test_solver();
+        let adf_str = "s(a).\ns(b).\ns(c).\nac(a, neg(b)).\nac(b, neg(a)).";
+        let adf = AdfBdds::from(&crate::AdfExpressions::parse(adf_str).unwrap());
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let result = solver.solve_admissible_with_channel(&adf, tx).unwrap();
+        assert!(result == solver.solve_admissible(&adf).unwrap());
+        assert!(adf.contains_empty_interpretation(&result));
+
+        // The sender is dropped once solving is done, so this terminates.
+        let updates: Vec<Progress> = rx.iter().collect();
+        assert_eq!(updates.len(), 4);
+        let indices: Vec<usize> = updates.iter().map(|it| it.constraint_index).collect();
+        assert_eq!(indices, vec![0, 1, 2, 3]);
+        assert!(updates.iter().all(|it| it.total == 3));
+        // Statement `c` is free, hence it has no constraint.
+        assert_eq!(updates[2].node_count, 0);
+        assert_eq!(updates[3].node_count, result.symbolic_set().node_count());
+    }
+
+    /// A solver that cancels the given token after it is called `limit` times.
+    struct CancellingSolver {
+        token: crate::CancelToken,
+        calls: std::sync::atomic::AtomicUsize,
+        limit: usize,
+    }
+
+    impl BddSolver for CancellingSolver {
+        fn solve_conjunction(&self, constraints: &[Bdd]) -> Cancellable<Bdd> {
+            let calls = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            if calls >= self.limit {
+                self.token.cancel();
+            }
+            NaiveGreedySolver::default().solve_conjunction(constraints)
+        }
+    }
+
+    #[test]
+    fn test_solve_complete_two_valued_anytime() {
+        let adf_str = "ac(a, or(b, c)).\nac(b, neg(a)).\nac(c, and(a, d)).\nac(d, neg(b)).";
+        let adf = AdfBdds::from(&crate::AdfExpressions::parse(adf_str).unwrap());
+        let expected = create_test_solver()
+            .solve_complete_two_valued(&adf)
+            .unwrap();
+
+        let (result, partial) = create_test_solver().solve_complete_two_valued_anytime(&adf);
+        assert!(result.unwrap() == expected);
+        assert!(partial.is_none());
+
+        let token = crate::CancelToken::new();
+        let solver = AdfInterpretationSolver::from(CancellingSolver {
+            token: token.clone(),
+            calls: Default::default(),
+            limit: 2,
+        });
+        let (result, partial) = cancel_this::on_trigger(token, || {
+            let (result, partial) = solver.solve_complete_two_valued_anytime(&adf);
+            Ok((result, partial))
+        })
+        .unwrap();
+        assert!(result.is_err());
+        let partial = partial.expect("Partial result is available");
+        // The partial result over-approximates the complete interpretations.
+        assert!(expected.minus(&partial).is_empty());
+        assert!(partial.model_count() > expected.model_count());
+    }
+
+    #[test]
+    fn test_solve_admissible_windowed() {
+        let solver = create_test_solver();
+        let adf_str = "ac(a, neg(b)).\nac(b, neg(a)).\nac(c, and(a, neg(c))).\nac(d, c(f)).";
+        let adf = AdfBdds::from(&crate::AdfExpressions::parse(adf_str).unwrap());
+        let full = solver.solve_admissible(&adf).unwrap();
+
+        let all: Vec<Statement> = adf.statements().cloned().collect();
+        assert!(solver.solve_admissible_windowed(&adf, &all).unwrap() == full);
+
+        let window = ["a".into(), "d".into()];
+        let partial = solver.solve_admissible_windowed(&adf, &window).unwrap();
+        assert!(full.minus(&partial).is_empty());
+        assert!(partial.model_count() > full.model_count());
+
+        let empty = solver.solve_admissible_windowed(&adf, &[]).unwrap();
+        assert_eq!(empty.model_count(), 81.0);
+    }
+
+    #[rstest]
+    #[case(
+        "ac(a, c(v)).\nac(b, neg(a)).\nac(c, or(b, d)).\nac(d, neg(c)).",
+        FreePolicy::Undefined
+    )]
+    #[case("ac(a, b).\nac(b, a).\nac(c, neg(a)).", FreePolicy::Undefined)]
+    #[case("s(a).\nac(b, and(a, neg(c))).\nac(c, imp(a, c)).", FreePolicy::True)]
+    #[case("s(a).\nac(b, xor(a, b)).\nac(c, or(neg(a), b)).", FreePolicy::False)]
+    #[case("s(a).\nac(b, iff(a, c(f))).", FreePolicy::SelfSupport)]
+    #[case(
+        "ac(a, c(f)).\nac(b, neg(a)).\nac(c, and(b, neg(d))).\nac(d, or(a, neg(b))).\nac(e, xor(c, d)).",
+        FreePolicy::Undefined
+    )]
+    fn test_solve_grounded_worklist(#[case] adf: &str, #[case] policy: FreePolicy) {
+        let solver = create_test_solver().with_free_statement_policy(policy);
+        let adf = AdfBdds::from(&crate::AdfExpressions::parse(adf).unwrap());
+
+        let expected = solver.solve_grounded(&adf).unwrap();
+        let result = solver.solve_grounded_worklist(&adf).unwrap();
+        assert_eq!(result.model_count(), 1.0);
+        assert!(result == expected);
+    }
 }
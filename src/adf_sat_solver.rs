@@ -0,0 +1,275 @@
+use crate::{
+    AdfBdds, AdfExpressions, ConditionExpression, ConditionExpressionNode, ModelSetTwoValued,
+    Statement,
+};
+use cancel_this::{Cancellable, is_cancelled};
+use log::{debug, info};
+use std::collections::{BTreeMap, BTreeSet};
+use varisat::{ExtendFormula, Lit, Solver, Var};
+
+/// A SAT-based solver for the two-valued complete semantics, available with the `sat`
+/// feature.
+///
+/// Instead of building BDDs of the conditions, the fixed-point constraints `s <=> ac(s)`
+/// are translated into CNF (using Tseitin encoding of the condition expressions) and the
+/// models are enumerated one by one using the `varisat` SAT solver. This is useful for
+/// ADFs where the conditions themselves are too large for BDDs, but the number of models
+/// is small.
+///
+/// Note that every model is enumerated explicitly. Hence, ADFs with many models (e.g.
+/// with many free statements) are typically better handled by
+/// [`AdfInterpretationSolver`](crate::AdfInterpretationSolver).
+#[derive(Clone, Default, Debug)]
+pub struct AdfSatSolver {}
+
+impl AdfSatSolver {
+    /// Enumerate the two-valued complete interpretations (two-valued models) of the
+    /// given `adf`. If `limit` is given, at most `limit` models are returned.
+    ///
+    /// The models are returned in the order in which they are found by the SAT solver.
+    /// Cancellation is checked before every SAT query.
+    ///
+    /// # Panics
+    ///
+    /// The `adf` must not contain missing statements.
+    pub fn enumerate_complete_two_valued(
+        &self,
+        adf: &AdfExpressions,
+        limit: Option<usize>,
+    ) -> Cancellable<Vec<BTreeMap<Statement, bool>>> {
+        info!("Starting SAT enumeration of complete two-valued interpretations");
+        assert!(
+            adf.find_missing_statements().is_empty(),
+            "ADF contains missing statements."
+        );
+
+        let mut encoder = CnfEncoder::new(adf);
+        for (statement, condition) in adf.conditions() {
+            let statement = Lit::positive(encoder.statements[&statement]);
+            let condition = encoder.encode(condition);
+            encoder.add_clause(&[!statement, condition]);
+            encoder.add_clause(&[statement, !condition]);
+        }
+        debug!(
+            "Encoded {} statements into {} variables and {} clauses",
+            encoder.statements.len(),
+            encoder.var_count,
+            encoder.clauses.len()
+        );
+
+        let mut solver = Solver::new();
+        for clause in &encoder.clauses {
+            solver.add_clause(clause);
+        }
+
+        let mut models = Vec::new();
+        while limit.is_none_or(|limit| models.len() < limit) {
+            is_cancelled!()?;
+            let satisfiable = solver
+                .solve()
+                .unwrap_or_else(|e| panic!("SAT solver failed: {}", e));
+            if !satisfiable {
+                break;
+            }
+
+            let assignment = solver.model().expect("Satisfiable formula has a model");
+            let positive = assignment
+                .into_iter()
+                .filter(|lit| lit.is_positive())
+                .map(|lit| lit.var())
+                .collect::<BTreeSet<_>>();
+            let mut model = BTreeMap::new();
+            let mut blocking = Vec::with_capacity(encoder.statements.len());
+            for (statement, var) in &encoder.statements {
+                let value = positive.contains(var);
+                model.insert(statement.clone(), value);
+                blocking.push(Lit::from_var(*var, !value));
+            }
+            // Auxiliary variables are determined by the statements, so blocking the
+            // statement values is sufficient.
+            solver.add_clause(&blocking);
+            models.push(model);
+        }
+
+        info!("Found {} complete two-valued interpretations", models.len());
+        Ok(models)
+    }
+
+    /// Compute the two-valued complete interpretations of the given `adf` as
+    /// a [`ModelSetTwoValued`].
+    ///
+    /// The models are enumerated using [`AdfSatSolver::enumerate_complete_two_valued`].
+    /// The resulting set uses a direct encoding of the statements of `adf` without any
+    /// conditions, hence the conditions never need to be translated into BDDs. Use
+    /// [`ModelSetTwoValued::rebind`] to combine the result with sets computed using
+    /// an [`AdfBdds`] of the same ADF.
+    ///
+    /// # Panics
+    ///
+    /// The `adf` must not contain missing statements.
+    pub fn solve_complete_two_valued(
+        &self,
+        adf: &AdfExpressions,
+    ) -> Cancellable<ModelSetTwoValued> {
+        let models = self.enumerate_complete_two_valued(adf, None)?;
+        let mut statements_only = AdfExpressions::new();
+        for statement in adf.statements() {
+            statements_only.add_statement(statement.clone());
+        }
+        let encoding = AdfBdds::try_from_direct_only(&statements_only)?;
+        Ok(encoding.two_valued_set_from_models(&models))
+    }
+}
+
+/// Translates condition expressions into CNF clauses using Tseitin encoding.
+///
+/// Each statement has its own variable. Every non-trivial sub-expression gets an auxiliary
+/// variable which is equivalent to the value of the sub-expression.
+struct CnfEncoder {
+    statements: BTreeMap<Statement, Var>,
+    clauses: Vec<Vec<Lit>>,
+    var_count: usize,
+    true_lit: Lit,
+}
+
+impl CnfEncoder {
+    fn new(adf: &AdfExpressions) -> Self {
+        let statements = adf
+            .statements()
+            .enumerate()
+            .map(|(i, s)| (s.clone(), Var::from_index(i)))
+            .collect::<BTreeMap<_, _>>();
+        let var_count = statements.len();
+        let true_lit = Lit::positive(Var::from_index(var_count));
+        CnfEncoder {
+            statements,
+            clauses: vec![vec![true_lit]],
+            var_count: var_count + 1,
+            true_lit,
+        }
+    }
+
+    fn add_clause(&mut self, clause: &[Lit]) {
+        self.clauses.push(clause.to_vec());
+    }
+
+    fn new_lit(&mut self) -> Lit {
+        let lit = Lit::positive(Var::from_index(self.var_count));
+        self.var_count += 1;
+        lit
+    }
+
+    /// Return a literal which is equivalent to the given `expression`.
+    fn encode(&mut self, expression: &ConditionExpression) -> Lit {
+        match expression.node() {
+            ConditionExpressionNode::Constant(value) => {
+                if *value {
+                    self.true_lit
+                } else {
+                    !self.true_lit
+                }
+            }
+            ConditionExpressionNode::Statement(statement) => {
+                Lit::positive(self.statements[statement])
+            }
+            ConditionExpressionNode::Negation(operand) => !self.encode(operand),
+            ConditionExpressionNode::And(operands) => {
+                let operands = operands
+                    .iter()
+                    .map(|it| self.encode(it))
+                    .collect::<Vec<_>>();
+                self.encode_and(&operands)
+            }
+            ConditionExpressionNode::Or(operands) => {
+                let operands = operands
+                    .iter()
+                    .map(|it| !self.encode(it))
+                    .collect::<Vec<_>>();
+                !self.encode_and(&operands)
+            }
+            ConditionExpressionNode::Implication(left, right) => {
+                let operands = [self.encode(left), !self.encode(right)];
+                !self.encode_and(&operands)
+            }
+            ConditionExpressionNode::Equivalence(left, right) => {
+                let (left, right) = (self.encode(left), self.encode(right));
+                self.encode_iff(left, right)
+            }
+            ConditionExpressionNode::ExclusiveOr(left, right) => {
+                let (left, right) = (self.encode(left), self.encode(right));
+                !self.encode_iff(left, right)
+            }
+        }
+    }
+
+    /// Return a literal equivalent to the conjunction of `operands`.
+    fn encode_and(&mut self, operands: &[Lit]) -> Lit {
+        match operands {
+            [] => self.true_lit,
+            [operand] => *operand,
+            _ => {
+                let result = self.new_lit();
+                let mut some_false = vec![result];
+                for operand in operands {
+                    self.add_clause(&[!result, *operand]);
+                    some_false.push(!*operand);
+                }
+                self.add_clause(&some_false);
+                result
+            }
+        }
+    }
+
+    /// Return a literal equivalent to `left <=> right`.
+    fn encode_iff(&mut self, left: Lit, right: Lit) -> Lit {
+        let result = self.new_lit();
+        self.add_clause(&[!result, !left, right]);
+        self.add_clause(&[!result, left, !right]);
+        self.add_clause(&[result, left, right]);
+        self.add_clause(&[result, !left, !right]);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AdfInterpretationSolver;
+    use crate::bdd_solver::NaiveGreedySolver;
+
+    #[test]
+    fn test_sat_matches_bdd_solver() {
+        let adf_str = "ac(a, or(b, neg(c))).\nac(b, and(a, c)).\nac(c, xor(a, d)).\ns(d).\nac(e, imp(e, iff(a, c))).\nac(f, c(v)).";
+        let adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let bdds = AdfBdds::from(&adf);
+        let expected = AdfInterpretationSolver::from(NaiveGreedySolver::default())
+            .solve_complete_two_valued(&bdds)
+            .unwrap();
+
+        let result = AdfSatSolver::default()
+            .solve_complete_two_valued(&adf)
+            .unwrap()
+            .rebind(bdds.direct_encoding_arc().clone())
+            .expect("Encodings are compatible");
+        assert!(result == expected);
+
+        let limited = AdfSatSolver::default()
+            .enumerate_complete_two_valued(&adf, Some(1))
+            .unwrap();
+        assert_eq!(limited.len(), 1.min(expected.model_count() as usize));
+    }
+
+    #[test]
+    fn test_sat_without_models() {
+        let adf = AdfExpressions::parse("ac(a, neg(a)).\nac(b, c(f)).").unwrap();
+        let models = AdfSatSolver::default()
+            .enumerate_complete_two_valued(&adf, None)
+            .unwrap();
+        assert!(models.is_empty());
+
+        let empty = AdfSatSolver::default()
+            .solve_complete_two_valued(&AdfExpressions::new())
+            .unwrap();
+        assert_eq!(empty.model_count(), 1.0);
+    }
+}
@@ -0,0 +1,369 @@
+use crate::bdd_solver::{BddSolver, DynamicBddSolver};
+use crate::{
+    AdfBdds, AdfExpressions, AdfInterpretationSolver, DynamicModelSet, ModelSetThreeValued,
+    ModelSetTwoValued, Statement,
+};
+use cancel_this::{Cancellable, is_cancelled};
+use log::info;
+use std::collections::BTreeMap;
+
+/// A cancellation token which can be shared by multiple (possibly concurrent) solves
+/// (see [`AdfSolver::solve_with_token`]). Calling `cancel` on any clone of the token
+/// cancels all solves that use it.
+pub type CancelToken = cancel_this::CancelAtomic;
+
+/// The ADF semantics supported by [`AdfSolver`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Semantics {
+    /// Two-valued complete interpretations (two-valued models).
+    TwoValuedComplete,
+    /// Two-valued stable interpretations.
+    Stable,
+    /// Three-valued admissible interpretations.
+    Admissible,
+    /// Three-valued complete interpretations.
+    Complete,
+    /// Three-valued preferred interpretations.
+    Preferred,
+    /// The three-valued grounded interpretation.
+    Grounded,
+}
+
+impl Semantics {
+    /// Returns `true` if the semantics produces two-valued interpretations, i.e. it only
+    /// needs the direct encoding of the ADF.
+    pub fn is_two_valued(&self) -> bool {
+        matches!(self, Semantics::TwoValuedComplete | Semantics::Stable)
+    }
+}
+
+/// A high-level facade that computes the interpretations of an [`AdfExpressions`] under
+/// the given [`Semantics`].
+///
+/// The solver takes care of building the symbolic [`AdfBdds`] representation. Only the
+/// encoding that is actually needed by the semantics is computed: two-valued semantics only
+/// use the direct encoding, while the dual encoding is built on demand by three-valued
+/// semantics. If you need more control (e.g. to reuse the encoding for multiple
+/// semantics), use [`AdfBdds`] and [`AdfInterpretationSolver`] directly.
+pub struct AdfSolver {
+    solver: AdfInterpretationSolver,
+}
+
+impl<S: BddSolver + 'static> From<S> for AdfSolver {
+    fn from(value: S) -> Self {
+        AdfSolver::new(Box::new(value))
+    }
+}
+
+impl From<AdfInterpretationSolver> for AdfSolver {
+    fn from(value: AdfInterpretationSolver) -> Self {
+        AdfSolver { solver: value }
+    }
+}
+
+impl AdfSolver {
+    /// Create a new `AdfSolver` with the given BDD solver.
+    pub fn new(solver: DynamicBddSolver) -> Self {
+        AdfSolver {
+            solver: AdfInterpretationSolver::new(solver),
+        }
+    }
+
+    /// Get the underlying [`AdfInterpretationSolver`].
+    pub fn interpretation_solver(&self) -> &AdfInterpretationSolver {
+        &self.solver
+    }
+
+    /// Compute all interpretations of the given `adf` under the given `semantics`.
+    ///
+    /// This operation is cancellable using the `cancel-this` crate.
+    ///
+    /// # Panics
+    ///
+    /// The `adf` must not contain missing statements (see
+    /// [`AdfExpressions::fix_missing_statements`]).
+    pub fn solve(
+        &self,
+        adf: &AdfExpressions,
+        semantics: Semantics,
+    ) -> Cancellable<DynamicModelSet> {
+        info!(
+            "Solving {:?} semantics for an ADF with {} statements",
+            semantics,
+            adf.len()
+        );
+
+        let adf = AdfBdds::try_from_direct_only(adf)?;
+        let result: DynamicModelSet = if semantics.is_two_valued() {
+            Box::new(self.solve_two_valued(&adf, semantics)?)
+        } else {
+            Box::new(self.solve_three_valued(&adf, semantics)?)
+        };
+        Ok(result)
+    }
+
+    /// Returns `true` if the given `statement` is `true` in at least one interpretation
+    /// of the `adf` under the given `semantics`.
+    ///
+    /// See [`AdfSolver::credulous_witness`] for details.
+    pub fn is_credulously_accepted(
+        &self,
+        adf: &AdfExpressions,
+        statement: &Statement,
+        semantics: Semantics,
+    ) -> Cancellable<bool> {
+        Ok(self.credulous_witness(adf, statement, semantics)?.is_some())
+    }
+
+    /// Find an interpretation of the `adf` under the given `semantics` in which the given
+    /// `statement` is `true`, or return `None` if no such interpretation exists.
+    ///
+    /// The witness is minimal in the following sense: For two-valued semantics, it is an
+    /// interpretation with the fewest `true` statements. For three-valued semantics, it is
+    /// an interpretation with the most undefined (`None`) statements. For two-valued
+    /// semantics, all values in the result are defined.
+    ///
+    /// # Panics
+    ///
+    /// The `statement` must be present in the `adf`, and the `adf` must not contain
+    /// missing statements (see [`AdfExpressions::fix_missing_statements`]).
+    pub fn credulous_witness(
+        &self,
+        adf: &AdfExpressions,
+        statement: &Statement,
+        semantics: Semantics,
+    ) -> Cancellable<Option<BTreeMap<Statement, Option<bool>>>> {
+        let adf = AdfBdds::try_from_direct_only(adf)?;
+        if semantics.is_two_valued() {
+            let direct = adf.direct_encoding();
+            let models = self.solve_two_valued(&adf, semantics)?;
+            let accepted = models
+                .symbolic_set()
+                .and(&direct.var_map().make_literal(statement, true));
+            if accepted.is_false() {
+                return Ok(None);
+            }
+            let valuation = direct.most_zero_model(&accepted);
+            let witness = direct
+                .var_map()
+                .statements()
+                .map(|s| (s.clone(), Some(valuation[&direct.var_map()[s]])))
+                .collect();
+            Ok(Some(witness))
+        } else {
+            let dual = adf.try_dual_encoding()?;
+            let var_map = dual.var_map();
+            let models = self.solve_three_valued(&adf, semantics)?;
+            let accepted = models
+                .symbolic_set()
+                .and(&var_map.make_positive_literal(statement, true))
+                .and(&var_map.make_negative_literal(statement, false));
+            if accepted.is_false() {
+                return Ok(None);
+            }
+            // Find the interpretations with the highest number of undefined statements.
+            let mut witnesses = accepted.clone();
+            for free_count in (0..var_map.size()).rev() {
+                is_cancelled!()?;
+                let free = ModelSetThreeValued::mk_exactly_k_free_statements(free_count, &adf);
+                witnesses = accepted.and(free.symbolic_set());
+                if !witnesses.is_false() {
+                    break;
+                }
+            }
+            let valuation = dual.most_fixed_model(&witnesses);
+            let witness = var_map
+                .statements()
+                .map(|s| {
+                    let (t_var, f_var) = var_map[s];
+                    let value = match (valuation[&t_var], valuation[&f_var]) {
+                        (true, false) => Some(true),
+                        (false, true) => Some(false),
+                        _ => None,
+                    };
+                    (s.clone(), value)
+                })
+                .collect();
+            Ok(Some(witness))
+        }
+    }
+
+    /// Compute the interpretations of a two-valued `semantics`.
+    fn solve_two_valued(
+        &self,
+        adf: &AdfBdds,
+        semantics: Semantics,
+    ) -> Cancellable<ModelSetTwoValued> {
+        match semantics {
+            Semantics::TwoValuedComplete => self.solver.solve_complete_two_valued(adf),
+            Semantics::Stable => self.solver.solve_stable_two_valued(adf),
+            _ => unreachable!("{:?} is not a two-valued semantics", semantics),
+        }
+    }
+
+    /// Compute the interpretations of a three-valued `semantics`.
+    fn solve_three_valued(
+        &self,
+        adf: &AdfBdds,
+        semantics: Semantics,
+    ) -> Cancellable<ModelSetThreeValued> {
+        match semantics {
+            Semantics::Admissible => self.solver.solve_admissible(adf),
+            Semantics::Complete => self.solver.solve_complete(adf),
+            Semantics::Preferred => self.solver.solve_preferred(adf),
+            Semantics::Grounded => self.solver.solve_grounded(adf),
+            _ => unreachable!("{:?} is not a three-valued semantics", semantics),
+        }
+    }
+
+    /// Same as [`AdfSolver::solve`], but the computation is also cancelled once the given
+    /// `token` is cancelled.
+    ///
+    /// The same token can be passed to multiple solves (including solves running
+    /// on different threads) to cancel all of them at once.
+    pub fn solve_with_token(
+        &self,
+        adf: &AdfExpressions,
+        semantics: Semantics,
+        token: &CancelToken,
+    ) -> Cancellable<DynamicModelSet> {
+        cancel_this::on_trigger(token.clone(), || self.solve(adf, semantics))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bdd_solver::NaiveGreedySolver;
+    use rstest::rstest;
+
+    #[test]
+    fn test_semantics_is_two_valued() {
+        assert!(Semantics::TwoValuedComplete.is_two_valued());
+        assert!(Semantics::Stable.is_two_valued());
+        assert!(!Semantics::Admissible.is_two_valued());
+        assert!(!Semantics::Complete.is_two_valued());
+        assert!(!Semantics::Preferred.is_two_valued());
+        assert!(!Semantics::Grounded.is_two_valued());
+    }
+
+    #[rstest]
+    #[case(Semantics::TwoValuedComplete, 2.0)]
+    #[case(Semantics::Stable, 1.0)]
+    #[case(Semantics::Admissible, 3.0)]
+    #[case(Semantics::Complete, 3.0)]
+    #[case(Semantics::Preferred, 2.0)]
+    #[case(Semantics::Grounded, 1.0)]
+    fn test_solve(#[case] semantics: Semantics, #[case] expected: f64) {
+        let adf_str = r#"
+            s(0).
+            s(1).
+            ac(0, 1).
+            ac(1, 0).
+        "#;
+        let adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let solver = AdfSolver::from(NaiveGreedySolver::default());
+
+        let result = solver
+            .solve(&adf, semantics)
+            .expect("Solving should not be cancelled");
+        assert_eq!(result.model_count(), expected);
+    }
+
+    #[test]
+    fn test_solve_matches_interpretation_solver() {
+        let adf_str = r#"
+            s(a).
+            s(b).
+            s(c).
+            ac(a, neg(b)).
+            ac(b, neg(a)).
+            ac(c, and(a, b)).
+        "#;
+        let expr_adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let solver = AdfSolver::from(NaiveGreedySolver::default());
+        let adf = AdfBdds::from(&expr_adf);
+
+        let expected = solver
+            .interpretation_solver()
+            .solve_complete(&adf)
+            .expect("Solving should not be cancelled");
+        let result = solver
+            .solve(&expr_adf, Semantics::Complete)
+            .expect("Solving should not be cancelled");
+        assert_eq!(result.model_count(), expected.model_count());
+    }
+
+    #[test]
+    fn test_solve_with_token() {
+        let adf_str = r#"
+            s(0).
+            s(1).
+            ac(0, 1).
+            ac(1, 0).
+        "#;
+        let adf = AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let solver = AdfSolver::from(NaiveGreedySolver::default());
+
+        let token = CancelToken::new();
+        let result = solver
+            .solve_with_token(&adf, Semantics::Complete, &token)
+            .expect("Solving should not be cancelled");
+        assert_eq!(result.model_count(), 3.0);
+
+        // A single cancelled token aborts all solves that use it.
+        token.cancel();
+        for semantics in [Semantics::Admissible, Semantics::Stable] {
+            assert!(solver.solve_with_token(&adf, semantics, &token).is_err());
+        }
+    }
+
+    #[rstest]
+    #[case(Semantics::TwoValuedComplete, true)]
+    #[case(Semantics::Stable, true)]
+    #[case(Semantics::Admissible, true)]
+    #[case(Semantics::Complete, true)]
+    #[case(Semantics::Preferred, true)]
+    #[case(Semantics::Grounded, false)]
+    fn test_credulous_witness(#[case] semantics: Semantics, #[case] accepted: bool) {
+        let adf = AdfExpressions::parse("ac(a, neg(b)).\nac(b, neg(a)).").unwrap();
+        let solver = AdfSolver::from(NaiveGreedySolver::default());
+        let (a, b) = (Statement::from("a"), Statement::from("b"));
+
+        let witness = solver.credulous_witness(&adf, &a, semantics).unwrap();
+        assert_eq!(
+            solver.is_credulously_accepted(&adf, &a, semantics).unwrap(),
+            accepted
+        );
+        if accepted {
+            let expected = BTreeMap::from([(a, Some(true)), (b, Some(false))]);
+            assert_eq!(witness, Some(expected));
+        } else {
+            assert_eq!(witness, None);
+        }
+    }
+
+    #[test]
+    fn test_credulous_witness_is_minimal() {
+        let adf = AdfExpressions::parse("s(b).\ns(c).\nac(a, c(v)).\nac(c, or(b, c)).").unwrap();
+        let solver = AdfSolver::from(NaiveGreedySolver::default());
+        let a = Statement::from("a");
+
+        let witness = solver
+            .credulous_witness(&adf, &a, Semantics::Admissible)
+            .unwrap()
+            .unwrap();
+        let expected = BTreeMap::from([
+            (a.clone(), Some(true)),
+            (Statement::from("b"), None),
+            (Statement::from("c"), None),
+        ]);
+        assert_eq!(witness, expected);
+
+        let witness = solver
+            .credulous_witness(&adf, &a, Semantics::TwoValuedComplete)
+            .unwrap()
+            .unwrap();
+        assert_eq!(witness[&Statement::from("c")], Some(false));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::bdd_solver::{BddSolver, DynamicBddSolver};
+use cancel_this::Cancellable;
+use log::debug;
+use ruddy::split::Bdd;
+
+/// A stateful solver that maintains the conjunction of a monotonically growing sequence
+/// of constraints.
+///
+/// Each call to [`IncrementalSolver::add_constraint`] only merges the new constraint into
+/// the previously accumulated result, instead of recomputing the whole conjunction
+/// from scratch. The initial set of constraints is merged using the underlying
+/// [`BddSolver`].
+pub struct IncrementalSolver {
+    solver: DynamicBddSolver,
+    accumulated: Bdd,
+}
+
+impl IncrementalSolver {
+    /// Create a new [`IncrementalSolver`] with no constraints (i.e. the accumulated
+    /// conjunction is `true`).
+    pub fn new(solver: DynamicBddSolver) -> Self {
+        IncrementalSolver {
+            solver,
+            accumulated: Bdd::new_true(),
+        }
+    }
+
+    /// Create a new [`IncrementalSolver`] whose accumulated conjunction starts with
+    /// the given `base` constraints.
+    pub fn with_base(solver: DynamicBddSolver, base: &[Bdd]) -> Cancellable<Self> {
+        let accumulated = solver.solve_conjunction(base)?;
+        Ok(IncrementalSolver {
+            solver,
+            accumulated,
+        })
+    }
+
+    /// The conjunction of all constraints added so far.
+    pub fn accumulated(&self) -> &Bdd {
+        &self.accumulated
+    }
+
+    /// Conjoin the accumulated result with a new constraint and return the updated result.
+    ///
+    /// Once the accumulated result is `false`, it cannot change anymore, and the
+    /// constraint is ignored.
+    pub fn add_constraint(&mut self, constraint: &Bdd) -> Cancellable<&Bdd> {
+        if !self.accumulated.is_false() {
+            self.accumulated = self
+                .solver
+                .solve_conjunction(&[self.accumulated.clone(), constraint.clone()])?;
+            debug!(
+                "Added constraint with {} nodes; accumulated result has {} nodes",
+                constraint.node_count(),
+                self.accumulated.node_count()
+            );
+        }
+        Ok(&self.accumulated)
+    }
+
+    /// Conjoin the accumulated result with all the given constraints and return
+    /// the updated result.
+    pub fn add_constraints(&mut self, constraints: &[Bdd]) -> Cancellable<&Bdd> {
+        if !self.accumulated.is_false() {
+            let mut to_merge = Vec::with_capacity(constraints.len() + 1);
+            to_merge.push(self.accumulated.clone());
+            to_merge.extend_from_slice(constraints);
+            self.accumulated = self.solver.solve_conjunction(&to_merge)?;
+        }
+        Ok(&self.accumulated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bdd_solver::NaiveGreedySolver;
+    use ruddy::VariableId;
+
+    fn literal(id: u32, value: bool) -> Bdd {
+        Bdd::new_literal(VariableId::new(id), value)
+    }
+
+    #[test]
+    fn test_incremental_matches_batch() {
+        let constraints = vec![
+            literal(0, true).or(&literal(1, true)),
+            literal(1, false).or(&literal(2, true)),
+            literal(2, false).or(&literal(0, false)),
+        ];
+        let expected = NaiveGreedySolver::default()
+            .solve_conjunction(&constraints)
+            .expect("Solving should not be cancelled");
+
+        let mut solver = IncrementalSolver::new(Box::new(NaiveGreedySolver::default()));
+        assert!(solver.accumulated().is_true());
+        for constraint in &constraints {
+            solver
+                .add_constraint(constraint)
+                .expect("Solving should not be cancelled");
+        }
+        assert!(solver.accumulated().structural_eq(&expected));
+    }
+
+    #[test]
+    fn test_incremental_with_base() {
+        let base = vec![literal(0, true)];
+        let mut solver =
+            IncrementalSolver::with_base(Box::new(NaiveGreedySolver::default()), &base)
+                .expect("Solving should not be cancelled");
+        assert!(solver.accumulated().structural_eq(&base[0]));
+
+        let result = solver
+            .add_constraints(&[literal(1, true), literal(2, false)])
+            .expect("Solving should not be cancelled");
+        let expected = literal(0, true)
+            .and(&literal(1, true))
+            .and(&literal(2, false));
+        assert!(result.structural_eq(&expected));
+    }
+
+    #[test]
+    fn test_incremental_stays_false() {
+        let mut solver = IncrementalSolver::new(Box::new(NaiveGreedySolver::default()));
+        solver.add_constraint(&literal(0, true)).unwrap();
+        assert!(
+            solver
+                .add_constraint(&literal(0, false))
+                .unwrap()
+                .is_false()
+        );
+        assert!(solver.add_constraint(&literal(1, true)).unwrap().is_false());
+    }
+}
@@ -1,12 +1,14 @@
 use cancel_this::Cancellable;
 use ruddy::split::Bdd;
 
+mod incremental;
 mod naive_greedy;
 mod naive_greedy_shared;
 mod quadratic_greedy;
 mod quadratic_greedy_shared;
 
-pub use naive_greedy::NaiveGreedySolver;
+pub use incremental::IncrementalSolver;
+pub use naive_greedy::{Heuristic, NaiveGreedySolver};
 pub use naive_greedy_shared::NaiveGreedySolverShared;
 
 pub use quadratic_greedy::QuadraticGreedySolver;
@@ -17,11 +19,96 @@ pub use quadratic_greedy_shared::QuadraticGreedySolverShared;
 /// the conjunction of the given constraints.
 pub trait BddSolver {
     fn solve_conjunction(&self, constraints: &[Bdd]) -> Cancellable<Bdd>;
+
+    /// Same as [`BddSolver::solve_conjunction`], but fails with
+    /// [`SolveError::NodeLimitExceeded`] once a BDD with more than `node_limit` nodes is
+    /// produced. The outer result reports cancellation.
+    ///
+    /// The default implementation only checks the `constraints` and the final result.
+    /// Solvers that merge the constraints step by step should override it to also check
+    /// every intermediate result.
+    fn solve_conjunction_limited(
+        &self,
+        constraints: &[Bdd],
+        node_limit: usize,
+    ) -> Cancellable<Result<Bdd, SolveError>> {
+        if let Err(e) = constraints
+            .iter()
+            .try_for_each(|it| check_node_limit(it, node_limit))
+        {
+            return Ok(Err(e));
+        }
+        let result = self.solve_conjunction(constraints)?;
+        Ok(check_node_limit(&result, node_limit).map(|()| result))
+    }
 }
 
+/// Fail with [`SolveError::NodeLimitExceeded`] if `bdd` has more than `node_limit` nodes.
+pub(crate) fn check_node_limit(bdd: &Bdd, node_limit: usize) -> Result<(), SolveError> {
+    let node_count = bdd.node_count();
+    if node_count > node_limit {
+        Err(SolveError::NodeLimitExceeded {
+            limit: node_limit,
+            node_count,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// An error of a BDD computation that can fail for reasons other than cancellation
+/// (see [`BddSolver::solve_conjunction_limited`]).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SolveError {
+    /// The computation was cancelled.
+    Cancelled,
+    /// A BDD with `node_count` nodes was produced, which is more than the `limit`.
+    NodeLimitExceeded { limit: usize, node_count: usize },
+}
+
+impl std::fmt::Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Cancelled => write!(f, "Computation was cancelled"),
+            SolveError::NodeLimitExceeded { limit, node_count } => write!(
+                f,
+                "BDD has {} nodes, which exceeds the limit of {}",
+                node_count, limit
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
 /// A type alias for referencing BDD solver instances of an erased type.
 pub type DynamicBddSolver = Box<dyn BddSolver>;
 
+/// Assert that two solvers compute structurally equal conjunctions of the given `constraints`.
+///
+/// This is a testing utility available with the `test-util` feature.
+///
+/// # Panics
+///
+/// Fails if the results differ (the message lists the node counts of both results),
+/// or if one of the solvers is cancelled.
+#[cfg(any(test, feature = "test-util"))]
+pub fn assert_solvers_agree(a: &dyn BddSolver, b: &dyn BddSolver, constraints: &[Bdd]) {
+    let result_a = a
+        .solve_conjunction(constraints)
+        .expect("First solver was cancelled");
+    let result_b = b
+        .solve_conjunction(constraints)
+        .expect("Second solver was cancelled");
+    assert!(
+        result_a.structural_eq(&result_b),
+        "Solvers disagree on {} constraints: first result has {} nodes, second result has {} nodes",
+        constraints.len(),
+        result_a.node_count(),
+        result_b.node_count(),
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -344,4 +431,36 @@ mod tests {
 
     // Note: Cancellation is tested implicitly through the is_cancelled!() checks
     // in the solver implementations. The solvers will return Err when cancelled.
+
+    #[test]
+    fn test_assert_solvers_agree() {
+        let solvers: Vec<DynamicBddSolver> = vec![
+            Box::new(NaiveGreedySolver::default()),
+            Box::new(NaiveGreedySolverShared),
+            Box::new(QuadraticGreedySolver),
+            Box::new(QuadraticGreedySolverShared),
+        ];
+        for bdds in [make_test_bdds(), make_contradictory_bdds(), Vec::new()] {
+            for solver in &solvers {
+                assert_solvers_agree(solvers[0].as_ref(), solver.as_ref(), &bdds);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Solvers disagree")]
+    fn test_assert_solvers_agree_fails() {
+        struct FalseSolver;
+        impl BddSolver for FalseSolver {
+            fn solve_conjunction(&self, _constraints: &[Bdd]) -> Cancellable<Bdd> {
+                Ok(Bdd::new_false())
+            }
+        }
+
+        assert_solvers_agree(
+            &NaiveGreedySolver::default(),
+            &FalseSolver,
+            &make_test_bdds(),
+        );
+    }
 }
@@ -1,26 +1,180 @@
-use crate::bdd_solver::BddSolver;
+use crate::bdd_solver::{BddSolver, SolveError, check_node_limit};
 use cancel_this::Cancellable;
 use log::debug;
 use ruddy::split::Bdd;
 
+/// Determines which BDDs are merged in each step of [`NaiveGreedySolver`].
+///
+/// In all cases, the smallest remaining BDD is merged in each step. The heuristic only
+/// decides which BDD it is merged with. The final result is the same for all heuristics.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Heuristic {
+    /// Merge with the second-smallest BDD.
+    #[default]
+    SmallestFirst,
+    /// Merge with the BDD that produces the smallest result. This requires computing
+    /// all candidate conjunctions in each step.
+    MinGrowth,
+    /// Merge with the BDD that shares the most variables with the smallest BDD (ties are
+    /// broken by size). This is cheaper than [`Heuristic::MinGrowth`], but follows
+    /// a similar intuition.
+    SharedVariables,
+}
+
 /// A naive greedy solver that repeatedly merges the two smallest BDDs using split BDD representation.
 ///
 /// The algorithm sorts the BDDs by size and always merges the two smallest ones until
 /// only one remains. This is a simple greedy approach that doesn't require quadratic
-/// comparisons but may not always produce optimal intermediate BDD sizes.
+/// comparisons but may not always produce optimal intermediate BDD sizes. The choice of the
+/// second BDD can be adjusted using [`NaiveGreedySolver::with_heuristic`].
 #[derive(Default, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct NaiveGreedySolver;
+pub struct NaiveGreedySolver {
+    heuristic: Heuristic,
+}
 
-impl BddSolver for NaiveGreedySolver {
-    fn solve_conjunction(&self, constraints: &[Bdd]) -> Cancellable<Bdd> {
+impl NaiveGreedySolver {
+    /// Create a [`NaiveGreedySolver`] which uses the given [`Heuristic`].
+    pub fn with_heuristic(heuristic: Heuristic) -> Self {
+        NaiveGreedySolver { heuristic }
+    }
+
+    /// The [`Heuristic`] used by this solver.
+    pub fn heuristic(&self) -> Heuristic {
+        self.heuristic
+    }
+
+    /// Compute the conjunction of `constraints` and, if it is `false`, also find the first
+    /// prefix of `constraints` whose conjunction is `false`.
+    ///
+    /// The second value is the index of the last constraint of this prefix, i.e.
+    /// `constraints[..=index]` is unsatisfiable but `constraints[..index]` is not. It is `None`
+    /// if the conjunction of all constraints is satisfiable. This is intended for debugging:
+    /// the constraint at `index` is the one that "killed" the solution space.
+    ///
+    /// The full conjunction is first computed greedily as in
+    /// [`BddSolver::solve_conjunction`]. Only if it is `false` are the constraints merged again
+    /// one by one in the given order.
+    pub fn solve_conjunction_until_false(
+        &self,
+        constraints: &[Bdd],
+    ) -> Cancellable<(Bdd, Option<usize>)> {
+        use cancel_this::is_cancelled;
+
+        let result = self.solve_conjunction(constraints)?;
+        if !result.is_false() {
+            return Ok((result, None));
+        }
+
+        let mut prefix = Bdd::new_true();
+        for (index, constraint) in constraints.iter().enumerate() {
+            is_cancelled!()?;
+            prefix = prefix.and(constraint);
+            if prefix.is_false() {
+                debug!("Conjunction became false at constraint {}", index);
+                return Ok((prefix, Some(index)));
+            }
+        }
+
+        unreachable!("Conjunction of all constraints is false, but no prefix is false");
+    }
+
+    /// Remove the BDD that should be merged with `smallest` from the (non-empty) list
+    /// of `candidates` (sorted by size) and return the result of the merge.
+    ///
+    /// If a `node_limit` is given, every computed conjunction is checked against it. For
+    /// [`Heuristic::MinGrowth`], candidate results over the limit are discarded right away,
+    /// and the merge only fails if no candidate fits into the limit.
+    fn merge_with_partner(
+        &self,
+        smallest: &Bdd,
+        candidates: &mut Vec<Bdd>,
+        node_limit: Option<usize>,
+    ) -> Cancellable<Result<Bdd, SolveError>> {
+        use cancel_this::is_cancelled;
+
+        let check_limit = |bdd: &Bdd| match node_limit {
+            Some(limit) => check_node_limit(bdd, limit),
+            None => Ok(()),
+        };
+
+        let index = match self.heuristic {
+            Heuristic::SmallestFirst => 0,
+            Heuristic::MinGrowth => {
+                let mut best: Option<(usize, Bdd)> = None;
+                let mut exceeded = None;
+                for (i, candidate) in candidates.iter().enumerate() {
+                    is_cancelled!()?;
+                    let merged = smallest.and(candidate);
+                    if let Err(e) = check_limit(&merged) {
+                        exceeded.get_or_insert(e);
+                        continue;
+                    }
+                    let is_better = best
+                        .as_ref()
+                        .is_none_or(|(_, best)| merged.node_count() < best.node_count());
+                    if is_better {
+                        best = Some((i, merged));
+                    }
+                }
+                return Ok(match best {
+                    Some((best_index, best_result)) => {
+                        candidates.remove(best_index);
+                        Ok(best_result)
+                    }
+                    None => Err(exceeded.expect("Candidates are not empty")),
+                });
+            }
+            Heuristic::SharedVariables => {
+                let variables = smallest.used_variables();
+                let mut best_index = 0;
+                let mut best_shared = 0;
+                for (i, candidate) in candidates.iter().enumerate() {
+                    let shared = candidate.used_variables().intersection(&variables).count();
+                    // Candidates are sorted, so ties are resolved by size automatically.
+                    if shared > best_shared {
+                        best_index = i;
+                        best_shared = shared;
+                    }
+                }
+                best_index
+            }
+        };
+        let partner = candidates.remove(index);
+        let merged = smallest.and(&partner);
+        Ok(check_limit(&merged).map(|()| merged))
+    }
+
+    /// Implementation of [`BddSolver::solve_conjunction`] and
+    /// [`BddSolver::solve_conjunction_limited`]. The outer result reports cancellation,
+    /// the inner result reports exceeding the `node_limit`.
+    ///
+    /// The limit is checked for every constraint and after every conjunction (including
+    /// the candidates of [`Heuristic::MinGrowth`]). A single conjunction is performed
+    /// atomically, hence it can still produce a result over the limit, but since both of
+    /// its operands are within the limit, the result has at most `node_limit^2` nodes.
+    fn solve_conjunction_internal(
+        &self,
+        constraints: &[Bdd],
+        node_limit: Option<usize>,
+    ) -> Cancellable<Result<Bdd, SolveError>> {
         use cancel_this::is_cancelled;
 
+        let exceeded = node_limit.and_then(|limit| {
+            constraints
+                .iter()
+                .try_for_each(|it| check_node_limit(it, limit))
+                .err()
+        });
+        if let Some(e) = exceeded {
+            return Ok(Err(e));
+        }
+
         // Handle edge cases
         if constraints.is_empty() {
-            return Ok(Bdd::new_true());
+            return Ok(Ok(Bdd::new_true()));
         }
         if constraints.len() == 1 {
-            return Ok(constraints[0].clone());
+            return Ok(Ok(constraints[0].clone()));
         }
 
         // Create a working set of BDDs that we'll merge
@@ -39,22 +193,153 @@ impl BddSolver for NaiveGreedySolver {
                 to_merge.last().unwrap().node_count()
             );
 
-            // Take the two smallest
-            let smallest1 = to_merge.remove(0);
-            let smallest2 = to_merge.remove(0);
-
-            // Merge them
-            let merged = smallest1.and(&smallest2);
+            // Take the smallest and find a partner for it
+            let smallest = to_merge.remove(0);
+            let merged = match self.merge_with_partner(&smallest, &mut to_merge, node_limit)? {
+                Ok(merged) => merged,
+                Err(e) => {
+                    debug!("Merged BDD exceeds the node limit: {}", e);
+                    return Ok(Err(e));
+                }
+            };
 
             // Early termination if we reach false
             if merged.is_false() {
-                return Ok(Bdd::new_false());
+                return Ok(Ok(Bdd::new_false()));
             }
 
             // Add the result back
             to_merge.push(merged);
         }
 
-        Ok(to_merge.into_iter().next().unwrap())
+        Ok(Ok(to_merge.into_iter().next().unwrap()))
+    }
+}
+
+impl BddSolver for NaiveGreedySolver {
+    fn solve_conjunction(&self, constraints: &[Bdd]) -> Cancellable<Bdd> {
+        let result = self.solve_conjunction_internal(constraints, None)?;
+        Ok(result.expect("Correctness violation: Node limit exceeded without a limit."))
+    }
+
+    fn solve_conjunction_limited(
+        &self,
+        constraints: &[Bdd],
+        node_limit: usize,
+    ) -> Cancellable<Result<Bdd, SolveError>> {
+        self.solve_conjunction_internal(constraints, Some(node_limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use ruddy::VariableId;
+
+    fn literal(id: u32, value: bool) -> Bdd {
+        Bdd::new_literal(VariableId::new(id), value)
+    }
+
+    #[rstest]
+    #[case(Heuristic::SmallestFirst)]
+    #[case(Heuristic::MinGrowth)]
+    #[case(Heuristic::SharedVariables)]
+    fn test_heuristics_agree(#[case] heuristic: Heuristic) {
+        let constraints = vec![
+            literal(0, true).or(&literal(3, true)),
+            literal(1, true).xor(&literal(2, true)),
+            literal(3, false).or(&literal(1, true)),
+            literal(0, false).iff(&literal(2, true)),
+            literal(4, true).or(&literal(0, true)),
+        ];
+        let expected = constraints
+            .iter()
+            .fold(Bdd::new_true(), |acc, it| acc.and(it));
+
+        let solver = NaiveGreedySolver::with_heuristic(heuristic);
+        assert_eq!(solver.heuristic(), heuristic);
+        let result = solver.solve_conjunction(&constraints).unwrap();
+        assert!(result.structural_eq(&expected));
+
+        let contradiction = vec![literal(0, true), literal(1, true), literal(0, false)];
+        let result = solver.solve_conjunction(&contradiction).unwrap();
+        assert!(result.is_false());
+    }
+
+    #[rstest]
+    #[case(Heuristic::SmallestFirst)]
+    #[case(Heuristic::MinGrowth)]
+    #[case(Heuristic::SharedVariables)]
+    fn test_solve_conjunction_limited(#[case] heuristic: Heuristic) {
+        // The conjunction of xor(i, i+1) chains grows with every merge.
+        let constraints = (0..6)
+            .map(|i| literal(i, true).xor(&literal(i + 1, true)))
+            .collect::<Vec<_>>();
+        let expected = constraints
+            .iter()
+            .fold(Bdd::new_true(), |acc, it| acc.and(it));
+
+        let solver = NaiveGreedySolver::with_heuristic(heuristic);
+        let result = solver
+            .solve_conjunction_limited(&constraints, expected.node_count())
+            .unwrap()
+            .unwrap();
+        assert!(result.structural_eq(&expected));
+
+        let error = solver
+            .solve_conjunction_limited(&constraints, 5)
+            .unwrap()
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            SolveError::NodeLimitExceeded { limit: 5, node_count } if node_count > 5
+        ));
+
+        // Constraints over the limit fail immediately.
+        let error = solver
+            .solve_conjunction_limited(&constraints, 1)
+            .unwrap()
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            SolveError::NodeLimitExceeded { limit: 1, .. }
+        ));
+
+        let token = crate::CancelToken::new();
+        token.cancel();
+        let result = cancel_this::on_trigger(token, || {
+            solver.solve_conjunction_limited(&constraints, expected.node_count())
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_solve_conjunction_until_false() {
+        let solver = NaiveGreedySolver::default();
+
+        let constraints = vec![
+            literal(0, true).or(&literal(1, true)),
+            literal(1, false),
+            literal(2, true),
+            literal(0, false).or(&literal(2, false)),
+            literal(3, true),
+        ];
+        let (result, index) = solver.solve_conjunction_until_false(&constraints).unwrap();
+        assert!(result.is_false());
+        assert_eq!(index, Some(3));
+
+        let (result, index) = solver
+            .solve_conjunction_until_false(&constraints[..3])
+            .unwrap();
+        let expected = literal(0, true)
+            .and(&literal(1, false))
+            .and(&literal(2, true));
+        assert!(result.structural_eq(&expected));
+        assert_eq!(index, None);
+
+        let (result, index) = solver.solve_conjunction_until_false(&[]).unwrap();
+        assert!(result.is_true());
+        assert_eq!(index, None);
     }
 }
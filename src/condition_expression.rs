@@ -182,7 +182,8 @@ impl ConditionExpression {
     /// Parse a condition expression from a string.
     ///
     /// Supports the following syntax:
-    /// - `42` or `foo` - Statement reference (numeric or string label)
+    /// - `42` or `foo` - Statement reference (numeric or string label; labels consist of
+    ///   alphanumeric characters, `_` and `-`, e.g. `-3` or `foo-bar`)
     /// - `c(v)` - Constant true (verum)
     /// - `c(f)` - Constant false (falsum)
     /// - `neg(expr)` - Negation
@@ -195,6 +196,84 @@ impl ConditionExpression {
         crate::condition_expression_parser::parse(input)
     }
 
+    /// Format this expression in a human-readable infix notation (e.g. `a & !b | (c -> d)`).
+    ///
+    /// Operators (from the strongest to the weakest) are `!`, `&`, `^`, `|`, `->` and `<->`,
+    /// and parentheses are only used where necessary. Note that [`std::fmt::Display`] still
+    /// uses the prefix notation accepted by [`ConditionExpression::parse`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use biodivine_adf_solver::ConditionExpression;
+    ///
+    /// let expr = ConditionExpression::parse("or(and(a,neg(b)),imp(c,d))").unwrap();
+    /// assert_eq!(expr.to_infix_string(), "a & !b | (c -> d)");
+    /// ```
+    pub fn to_infix_string(&self) -> String {
+        crate::condition_expression_writer::write_infix(self)
+    }
+
+    /// Format this expression as a `sympy` logic expression (e.g. `And(a, Not(b))`), using
+    /// `name_of` to translate statements into symbol names.
+    ///
+    /// The operators are translated to `Not`, `And`, `Or`, `Xor`, `Implies` and
+    /// `Equivalent`, and the constants to `True`/`False`. The symbol names are not
+    /// checked, i.e. `name_of` must produce valid Python identifiers (in particular,
+    /// numeric statement labels must be prefixed).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use biodivine_adf_solver::ConditionExpression;
+    ///
+    /// let expr = ConditionExpression::parse("or(and(1,neg(2)),imp(3,c(f)))").unwrap();
+    /// let sympy = expr.to_sympy_string(|s| format!("s{}", s));
+    /// assert_eq!(sympy, "Or(And(s1, Not(s2)), Implies(s3, False))");
+    /// ```
+    pub fn to_sympy_string(&self, name_of: impl Fn(&Statement) -> String) -> String {
+        crate::condition_expression_writer::write_sympy(self, &name_of)
+    }
+
+    /// Format this expression as an SMT-LIB 2 boolean term (e.g. `(and a (not b))`), using
+    /// `name_of` to translate statements into symbol names.
+    ///
+    /// The operators are translated to `not`, `and`, `or`, `xor`, `=>` and `=`, and the
+    /// constants to `true`/`false`. As with [`ConditionExpression::to_sympy_string`], the
+    /// symbol names are not checked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use biodivine_adf_solver::ConditionExpression;
+    ///
+    /// let expr = ConditionExpression::parse("or(and(1,neg(2)),imp(3,c(f)))").unwrap();
+    /// let smtlib = expr.to_smtlib_string(|s| format!("s{}", s));
+    /// assert_eq!(smtlib, "(or (and s1 (not s2)) (=> s3 false))");
+    /// ```
+    pub fn to_smtlib_string(&self, name_of: impl Fn(&Statement) -> String) -> String {
+        crate::condition_expression_writer::write_smtlib(self, &name_of)
+    }
+
+    /// Parse a condition expression written in infix notation (e.g. `a & !b | (c -> d)`).
+    ///
+    /// Supported operators (from the strongest to the weakest) are `!`, `&`, `^`, `|`,
+    /// `->` (right-associative) and `<->`, together with parentheses, constants
+    /// `true`/`false`, and statement labels as atoms. This is the inverse of
+    /// [`ConditionExpression::to_infix_string`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use biodivine_adf_solver::ConditionExpression;
+    ///
+    /// let expr = ConditionExpression::parse_infix("a & !b | (c -> d)").unwrap();
+    /// assert_eq!(expr, ConditionExpression::parse("or(and(a,neg(b)),imp(c,d))").unwrap());
+    /// ```
+    pub fn parse_infix(input: &str) -> Result<Self, String> {
+        crate::condition_expression_parser::parse_infix(input)
+    }
+
     /// Collect all statement references in this expression.
     /// Returns a sorted vector of all statements referenced in the expression.
     pub fn collect_statements(&self) -> Vec<Statement> {
@@ -32,10 +32,12 @@ fn tokenize(input: &str) -> Result<Vec<Token>, String> {
                 tokens.push(Token::Comma);
                 chars.next();
             }
-            '0'..='9' | 'a'..='z' | 'A'..='Z' | '_' => {
+            // Labels can also contain `-` (e.g. `-3` or `foo-bar`). Such labels are just
+            // names, they are not interpreted as (negative) numbers.
+            '0'..='9' | 'a'..='z' | 'A'..='Z' | '_' | '-' => {
                 let mut ident = String::new();
                 while let Some(&ch) = chars.peek() {
-                    if ch.is_alphanumeric() || ch == '_' {
+                    if ch.is_alphanumeric() || ch == '_' || ch == '-' {
                         ident.push(ch);
                         chars.next();
                     } else {
@@ -184,6 +186,11 @@ impl Parser {
     fn parse_operands(&mut self) -> Result<Vec<ConditionExpression>, String> {
         let mut operands = Vec::new();
 
+        // Empty operand list, e.g. `and()`
+        if let Some(Token::RightParen) = self.peek() {
+            return Ok(operands);
+        }
+
         // Parse first operand
         operands.push(self.parse_expression()?);
 
@@ -204,11 +211,13 @@ impl Parser {
 /// - `c(v)` - Constant true (verum)
 /// - `c(f)` - Constant false (falsum)
 /// - `neg(expr)` - Negation
-/// - `and(expr1, expr2, ...)` - Logical AND
-/// - `or(expr1, expr2, ...)` - Logical OR
+/// - `and(expr1, expr2, ...)` - Logical AND (possibly empty)
+/// - `or(expr1, expr2, ...)` - Logical OR (possibly empty)
 /// - `xor(expr1, expr2)` - Exclusive OR
 /// - `imp(expr1, expr2)` - Implication
 /// - `iff(expr1, expr2)` - Equivalence
+///
+/// This is the inverse of [`crate::condition_expression_writer::write`].
 pub fn parse(input: &str) -> Result<ConditionExpression, String> {
     let tokens = tokenize(input)?;
     let mut parser = Parser::new(tokens);
@@ -225,6 +234,264 @@ pub fn parse(input: &str) -> Result<ConditionExpression, String> {
     Ok(expr)
 }
 
+/// Tokens for parsing condition expressions in infix notation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum InfixToken {
+    Identifier(String),
+    /// A label in double quotes, which is always a statement reference.
+    Quoted(String),
+    LeftParen,
+    RightParen,
+    Not,
+    And,
+    Or,
+    Xor,
+    Implies,
+    Iff,
+}
+
+/// Tokenize an infix condition expression string.
+fn tokenize_infix(input: &str) -> Result<Vec<InfixToken>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&ch) = chars.peek() {
+        match ch {
+            ch if ch.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                tokens.push(InfixToken::LeftParen);
+                chars.next();
+            }
+            ')' => {
+                tokens.push(InfixToken::RightParen);
+                chars.next();
+            }
+            '!' => {
+                tokens.push(InfixToken::Not);
+                chars.next();
+            }
+            '&' => {
+                tokens.push(InfixToken::And);
+                chars.next();
+            }
+            '|' => {
+                tokens.push(InfixToken::Or);
+                chars.next();
+            }
+            '^' => {
+                tokens.push(InfixToken::Xor);
+                chars.next();
+            }
+            '-' => {
+                chars.next();
+                if chars.next() != Some('>') {
+                    return Err("Expected '->'".to_string());
+                }
+                tokens.push(InfixToken::Implies);
+            }
+            '<' => {
+                chars.next();
+                if chars.next() != Some('-') || chars.next() != Some('>') {
+                    return Err("Expected '<->'".to_string());
+                }
+                tokens.push(InfixToken::Iff);
+            }
+            '0'..='9' | 'a'..='z' | 'A'..='Z' | '_' => {
+                let mut ident = String::new();
+                while let Some(&ch) = chars.peek() {
+                    if ch.is_alphanumeric() || ch == '_' {
+                        ident.push(ch);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(InfixToken::Identifier(ident));
+            }
+            '"' => {
+                chars.next();
+                let mut label = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(ch @ ('"' | '\\')) => label.push(ch),
+                            Some(ch) => return Err(format!("Invalid escape sequence: \\{}", ch)),
+                            None => return Err("Unterminated quoted label".to_string()),
+                        },
+                        Some(ch) => label.push(ch),
+                        None => return Err("Unterminated quoted label".to_string()),
+                    }
+                }
+                tokens.push(InfixToken::Quoted(label));
+            }
+            _ => {
+                return Err(format!("Unexpected character: {}", ch));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive descent parser for condition expressions in infix notation.
+///
+/// Each level of the grammar corresponds to one operator precedence:
+/// `<->` < `->` < `|` < `^` < `&` < `!`.
+struct InfixParser {
+    tokens: Vec<InfixToken>,
+    position: usize,
+}
+
+impl InfixParser {
+    fn new(tokens: Vec<InfixToken>) -> Self {
+        InfixParser {
+            tokens,
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<&InfixToken> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<InfixToken> {
+        if self.position < self.tokens.len() {
+            let token = self.tokens[self.position].clone();
+            self.position += 1;
+            Some(token)
+        } else {
+            None
+        }
+    }
+
+    /// Consume the next token if it is equal to `expected`.
+    fn consume(&mut self, expected: &InfixToken) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Equivalence (left-associative).
+    fn parse_equivalence(&mut self) -> Result<ConditionExpression, String> {
+        let mut result = self.parse_implication()?;
+        while self.consume(&InfixToken::Iff) {
+            let right = self.parse_implication()?;
+            result = ConditionExpression::equivalence(result, right);
+        }
+        Ok(result)
+    }
+
+    /// Implication (right-associative).
+    fn parse_implication(&mut self) -> Result<ConditionExpression, String> {
+        let left = self.parse_or()?;
+        if self.consume(&InfixToken::Implies) {
+            let right = self.parse_implication()?;
+            Ok(ConditionExpression::implication(left, right))
+        } else {
+            Ok(left)
+        }
+    }
+
+    /// Disjunction of one or more operands.
+    fn parse_or(&mut self) -> Result<ConditionExpression, String> {
+        let mut operands = vec![self.parse_xor()?];
+        while self.consume(&InfixToken::Or) {
+            operands.push(self.parse_xor()?);
+        }
+        if operands.len() == 1 {
+            Ok(operands.remove(0))
+        } else {
+            Ok(ConditionExpression::or(&operands))
+        }
+    }
+
+    /// Exclusive or (left-associative).
+    fn parse_xor(&mut self) -> Result<ConditionExpression, String> {
+        let mut result = self.parse_and()?;
+        while self.consume(&InfixToken::Xor) {
+            let right = self.parse_and()?;
+            result = ConditionExpression::exclusive_or(result, right);
+        }
+        Ok(result)
+    }
+
+    /// Conjunction of one or more operands.
+    fn parse_and(&mut self) -> Result<ConditionExpression, String> {
+        let mut operands = vec![self.parse_unary()?];
+        while self.consume(&InfixToken::And) {
+            operands.push(self.parse_unary()?);
+        }
+        if operands.len() == 1 {
+            Ok(operands.remove(0))
+        } else {
+            Ok(ConditionExpression::and(&operands))
+        }
+    }
+
+    /// Negation, parenthesized expression, constant or statement.
+    fn parse_unary(&mut self) -> Result<ConditionExpression, String> {
+        match self.next() {
+            Some(InfixToken::Not) => Ok(ConditionExpression::negation(self.parse_unary()?)),
+            Some(InfixToken::LeftParen) => {
+                let expr = self.parse_equivalence()?;
+                match self.next() {
+                    Some(InfixToken::RightParen) => Ok(expr),
+                    Some(token) => Err(format!("Expected RightParen, found {:?}", token)),
+                    None => Err("Expected RightParen, found end of input".to_string()),
+                }
+            }
+            Some(InfixToken::Identifier(ident)) => match ident.as_str() {
+                "true" => Ok(ConditionExpression::constant(true)),
+                "false" => Ok(ConditionExpression::constant(false)),
+                _ => Ok(ConditionExpression::statement(Statement::from(ident))),
+            },
+            Some(InfixToken::Quoted(label)) => {
+                Ok(ConditionExpression::statement(Statement::from(label)))
+            }
+            Some(token) => Err(format!("Unexpected token: {:?}", token)),
+            None => Err("Unexpected end of input".to_string()),
+        }
+    }
+}
+
+/// Parse a condition expression written in infix notation.
+///
+/// Supports the following syntax (from the weakest to the strongest operator):
+/// - `a <-> b` - Equivalence (left-associative)
+/// - `a -> b` - Implication (right-associative)
+/// - `a | b | ...` - Logical OR
+/// - `a ^ b` - Exclusive OR (left-associative)
+/// - `a & b & ...` - Logical AND
+/// - `!a` - Negation
+/// - `(expr)` - Parentheses
+/// - `true`/`false` - Constants
+/// - `42` or `foo` - Statement reference (numeric or string label)
+/// - `"foo-bar"` - Statement reference with an arbitrary label (`"` and `\` must be
+///   escaped with a backslash); quoted labels are never constants
+///
+/// This is the inverse of [`crate::condition_expression_writer::write_infix`].
+pub fn parse_infix(input: &str) -> Result<ConditionExpression, String> {
+    let tokens = tokenize_infix(input)?;
+    let mut parser = InfixParser::new(tokens);
+    let expr = parser.parse_equivalence()?;
+
+    // Ensure we've consumed all tokens
+    if parser.position < parser.tokens.len() {
+        return Err(format!(
+            "Unexpected tokens after expression: {:?}",
+            &parser.tokens[parser.position..]
+        ));
+    }
+
+    Ok(expr)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -298,6 +565,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tokenize_labels_with_dash() {
+        let tokens = tokenize("and(-3, foo-bar)").unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Identifier("and".to_string()),
+                Token::LeftParen,
+                Token::Identifier("-3".to_string()),
+                Token::Comma,
+                Token::Identifier("foo-bar".to_string()),
+                Token::RightParen,
+            ]
+        );
+    }
+
     #[test]
     fn test_tokenize_invalid_character() {
         let result = tokenize("and(1@2)");
@@ -656,4 +939,267 @@ mod tests {
             ]
         );
     }
+
+    // Infix parser tests
+
+    fn st(label: &str) -> ConditionExpression {
+        ConditionExpression::statement(Statement::from(label))
+    }
+
+    #[test]
+    fn test_tokenize_infix_operators() {
+        let tokens = tokenize_infix("!a & b | c ^ d -> e <-> (f)").unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                InfixToken::Not,
+                InfixToken::Identifier("a".to_string()),
+                InfixToken::And,
+                InfixToken::Identifier("b".to_string()),
+                InfixToken::Or,
+                InfixToken::Identifier("c".to_string()),
+                InfixToken::Xor,
+                InfixToken::Identifier("d".to_string()),
+                InfixToken::Implies,
+                InfixToken::Identifier("e".to_string()),
+                InfixToken::Iff,
+                InfixToken::LeftParen,
+                InfixToken::Identifier("f".to_string()),
+                InfixToken::RightParen,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_infix_invalid() {
+        assert!(tokenize_infix("a - b").is_err());
+        assert!(tokenize_infix("a <- b").is_err());
+        assert!(tokenize_infix("a , b").is_err());
+    }
+
+    #[test]
+    fn test_parse_infix_atoms() {
+        assert_eq!(parse_infix("42").unwrap(), st("42"));
+        assert_eq!(
+            parse_infix("true").unwrap(),
+            ConditionExpression::constant(true)
+        );
+        assert_eq!(
+            parse_infix("false").unwrap(),
+            ConditionExpression::constant(false)
+        );
+        assert_eq!(
+            parse_infix("!!a").unwrap(),
+            ConditionExpression::negation(ConditionExpression::negation(st("a")))
+        );
+        assert_eq!(parse_infix("((a))").unwrap(), st("a"));
+    }
+
+    #[test]
+    fn test_parse_infix_precedence() {
+        let expected = ConditionExpression::or(&[
+            ConditionExpression::and(&[st("a"), ConditionExpression::negation(st("b"))]),
+            ConditionExpression::implication(st("c"), st("d")),
+        ]);
+        assert_eq!(parse_infix("a & !b | (c -> d)").unwrap(), expected);
+
+        // `&` binds stronger than `^`, which binds stronger than `|`.
+        let expected = ConditionExpression::or(&[
+            ConditionExpression::exclusive_or(
+                ConditionExpression::and(&[st("a"), st("b")]),
+                st("c"),
+            ),
+            st("d"),
+        ]);
+        assert_eq!(parse_infix("a & b ^ c | d").unwrap(), expected);
+
+        // `|` binds stronger than `->`, which binds stronger than `<->`.
+        let expected = ConditionExpression::equivalence(
+            ConditionExpression::implication(ConditionExpression::or(&[st("a"), st("b")]), st("c")),
+            st("d"),
+        );
+        assert_eq!(parse_infix("a | b -> c <-> d").unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parse_infix_associativity() {
+        assert_eq!(
+            parse_infix("a -> b -> c").unwrap(),
+            ConditionExpression::implication(
+                st("a"),
+                ConditionExpression::implication(st("b"), st("c"))
+            )
+        );
+        assert_eq!(
+            parse_infix("a ^ b ^ c").unwrap(),
+            ConditionExpression::exclusive_or(
+                ConditionExpression::exclusive_or(st("a"), st("b")),
+                st("c")
+            )
+        );
+        assert_eq!(
+            parse_infix("a & b & c").unwrap(),
+            ConditionExpression::and(&[st("a"), st("b"), st("c")])
+        );
+    }
+
+    #[test]
+    fn test_parse_infix_errors() {
+        assert!(parse_infix("").is_err());
+        assert!(parse_infix("a &").is_err());
+        assert!(parse_infix("(a | b").is_err());
+        assert!(parse_infix("a b").is_err());
+        assert!(parse_infix("a & | b").is_err());
+        assert!(parse_infix("\"a").is_err());
+        assert!(parse_infix("\"a\\n\"").is_err());
+    }
+
+    #[test]
+    fn test_parse_infix_quoted_labels() {
+        let st = |label: &str| ConditionExpression::statement(Statement::from(label));
+        assert_eq!(parse_infix("\"true\""), Ok(st("true")));
+        assert_eq!(
+            parse_infix("\"-3\" -> foo"),
+            Ok(ConditionExpression::implication(st("-3"), st("foo")))
+        );
+        assert_eq!(
+            parse_infix("!\"a \\\"b\\\\\""),
+            Ok(ConditionExpression::negation(st("a \"b\\")))
+        );
+    }
+
+    #[test]
+    fn test_parse_infix_roundtrip() {
+        let inputs = [
+            "a",
+            "true",
+            "!a",
+            "!(a & b)",
+            "a & !b | (c -> d)",
+            "(a | b) & c",
+            "(a & b) & c",
+            "a -> b -> c",
+            "(a -> b) -> c",
+            "a ^ b ^ c",
+            "a <-> (b <-> c)",
+            "1 & 2 <-> !3 | 4 ^ 5",
+            "\"foo-bar\" & !\"-3\"",
+            "\"true\" | false",
+        ];
+        for input in inputs {
+            let parsed = parse_infix(input).unwrap();
+            assert_eq!(parsed.to_infix_string(), input);
+        }
+    }
+
+    #[test]
+    fn test_parse_infix_matches_prefix() {
+        let prefix = "and(and(and(9,neg(7)),or(neg(10),neg(1))),or(4,6))";
+        let expr = parse(prefix).unwrap();
+        assert_eq!(parse_infix(&expr.to_infix_string()).unwrap(), expr);
+    }
+
+    #[test]
+    fn test_parse_empty_operands() {
+        let expr = parse("and(or(),and())").unwrap();
+        assert_eq!(
+            expr,
+            ConditionExpression::and(&[
+                ConditionExpression::or(&[]),
+                ConditionExpression::and(&[]),
+            ])
+        );
+        assert_eq!(parse(&expr.to_string()).unwrap(), expr);
+        assert!(parse("and(,)").is_err());
+    }
+
+    // Property-based round-trip tests
+
+    use proptest::prelude::*;
+
+    /// Statement labels that are not keywords of either syntax (including labels
+    /// with `-`).
+    fn arb_statement() -> impl Strategy<Value = Statement> {
+        prop_oneof![
+            any::<u32>().prop_map(|it| it.to_string()),
+            any::<i32>().prop_map(|it| it.to_string()),
+            "[a-zA-Z_-][a-zA-Z0-9_-]{0,6}".prop_filter("Label must not be a keyword", |it| {
+                !matches!(
+                    it.as_str(),
+                    "neg" | "and" | "or" | "xor" | "imp" | "iff" | "true" | "false"
+                )
+            }),
+        ]
+        .prop_map(Statement::from)
+    }
+
+    /// Statement labels for the infix syntax, which also include keywords and labels
+    /// with characters that must be quoted.
+    fn arb_infix_statement() -> impl Strategy<Value = Statement> {
+        prop_oneof![
+            arb_statement(),
+            prop::sample::select(vec!["true", "false", "neg", "c", "-", "->", "<->", ""])
+                .prop_map(Statement::from),
+            "[a-z\\- \"\\\\!&|^()<>]{1,6}".prop_map(Statement::from),
+        ]
+    }
+
+    /// Arbitrary expressions, including `and`/`or` with zero or one operand.
+    fn arb_expression() -> impl Strategy<Value = ConditionExpression> {
+        let leaf = prop_oneof![
+            any::<bool>().prop_map(ConditionExpression::constant),
+            arb_statement().prop_map(ConditionExpression::statement),
+        ];
+        leaf.prop_recursive(6, 64, 4, |inner| {
+            prop_oneof![
+                inner.clone().prop_map(ConditionExpression::negation),
+                prop::collection::vec(inner.clone(), 0..4)
+                    .prop_map(|it| ConditionExpression::and(&it)),
+                prop::collection::vec(inner.clone(), 0..4)
+                    .prop_map(|it| ConditionExpression::or(&it)),
+                (inner.clone(), inner.clone())
+                    .prop_map(|(l, r)| ConditionExpression::exclusive_or(l, r)),
+                (inner.clone(), inner.clone())
+                    .prop_map(|(l, r)| ConditionExpression::implication(l, r)),
+                (inner.clone(), inner).prop_map(|(l, r)| ConditionExpression::equivalence(l, r)),
+            ]
+        })
+    }
+
+    /// Same as [`arb_expression`], but `and`/`or` always have at least two operands
+    /// (the infix syntax cannot represent the remaining cases structurally).
+    fn arb_infix_expression() -> impl Strategy<Value = ConditionExpression> {
+        let leaf = prop_oneof![
+            any::<bool>().prop_map(ConditionExpression::constant),
+            arb_infix_statement().prop_map(ConditionExpression::statement),
+        ];
+        leaf.prop_recursive(6, 64, 4, |inner| {
+            prop_oneof![
+                inner.clone().prop_map(ConditionExpression::negation),
+                prop::collection::vec(inner.clone(), 2..4)
+                    .prop_map(|it| ConditionExpression::and(&it)),
+                prop::collection::vec(inner.clone(), 2..4)
+                    .prop_map(|it| ConditionExpression::or(&it)),
+                (inner.clone(), inner.clone())
+                    .prop_map(|(l, r)| ConditionExpression::exclusive_or(l, r)),
+                (inner.clone(), inner.clone())
+                    .prop_map(|(l, r)| ConditionExpression::implication(l, r)),
+                (inner.clone(), inner).prop_map(|(l, r)| ConditionExpression::equivalence(l, r)),
+            ]
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_parse_write_roundtrip(expr in arb_expression()) {
+            let written = crate::condition_expression_writer::write(&expr);
+            prop_assert_eq!(parse(&written), Ok(expr));
+        }
+
+        #[test]
+        fn prop_parse_infix_write_infix_roundtrip(expr in arb_infix_expression()) {
+            let written = crate::condition_expression_writer::write_infix(&expr);
+            prop_assert_eq!(parse_infix(&written), Ok(expr));
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use crate::condition_expression::ConditionExpression;
+use crate::statement::Statement;
 use std::fmt::Write;
 
 /// Format a condition expression to a string.
@@ -84,10 +85,309 @@ fn write_operands(f: &mut impl Write, operands: &[ConditionExpression]) -> std::
     Ok(())
 }
 
+/// Binding strength of `<->` (the weakest operator).
+const PRECEDENCE_EQUIVALENCE: u8 = 1;
+/// Binding strength of `->`.
+const PRECEDENCE_IMPLICATION: u8 = 2;
+/// Binding strength of `|`.
+const PRECEDENCE_OR: u8 = 3;
+/// Binding strength of `^`.
+const PRECEDENCE_XOR: u8 = 4;
+/// Binding strength of `&`.
+const PRECEDENCE_AND: u8 = 5;
+/// Binding strength of atoms and negations (never need parentheses).
+const PRECEDENCE_ATOM: u8 = 6;
+
+/// Format a condition expression to a human-readable infix string.
+///
+/// Uses the following syntax (from the weakest to the strongest operator):
+/// - `a <-> b` - Equivalence (left-associative)
+/// - `a -> b` - Implication (right-associative)
+/// - `a | b | ...` - Logical OR
+/// - `a ^ b` - Exclusive OR (left-associative)
+/// - `a & b & ...` - Logical AND
+/// - `!a` - Negation
+/// - `true`/`false` - Constants
+/// - `42` or `foo` - Statement reference
+/// - `"foo-bar"` - Statement reference with a label that is not alphanumeric or that
+///   is equal to a constant (`"` and `\` are escaped with a backslash)
+///
+/// Parentheses are only emitted where the precedence (or associativity) of the operators
+/// requires them. Nested `and`/`or` operands are always parenthesized, such that the
+/// structure of the expression is preserved.
+pub fn write_infix(expr: &ConditionExpression) -> String {
+    let mut result = String::new();
+    write_infix_to(&mut result, expr).expect("Writing to String should never fail");
+    result
+}
+
+/// Compute the binding strength of the top-level operator of an infix expression.
+fn infix_precedence(expr: &ConditionExpression) -> u8 {
+    if let Some(operands) = expr.as_and() {
+        match operands {
+            [] => PRECEDENCE_ATOM,
+            [operand] => infix_precedence(operand),
+            _ => PRECEDENCE_AND,
+        }
+    } else if let Some(operands) = expr.as_or() {
+        match operands {
+            [] => PRECEDENCE_ATOM,
+            [operand] => infix_precedence(operand),
+            _ => PRECEDENCE_OR,
+        }
+    } else if expr.is_exclusive_or() {
+        PRECEDENCE_XOR
+    } else if expr.is_implication() {
+        PRECEDENCE_IMPLICATION
+    } else if expr.is_equivalence() {
+        PRECEDENCE_EQUIVALENCE
+    } else {
+        PRECEDENCE_ATOM
+    }
+}
+
+/// Write a condition expression in infix notation to a formatter.
+fn write_infix_to(f: &mut impl Write, expr: &ConditionExpression) -> std::fmt::Result {
+    if let Some(value) = expr.as_constant() {
+        write!(f, "{}", value)
+    } else if let Some(statement) = expr.as_statement() {
+        write_infix_label(f, statement)
+    } else if let Some(operand) = expr.as_negation() {
+        write!(f, "!")?;
+        write_infix_operand(f, operand, PRECEDENCE_ATOM)
+    } else if let Some(operands) = expr.as_and() {
+        match operands {
+            // Empty conjunction is trivially true.
+            [] => return write!(f, "true"),
+            [operand] => return write_infix_to(f, operand),
+            _ => (),
+        }
+        write_infix_operands(f, operands, " & ", PRECEDENCE_AND + 1)
+    } else if let Some(operands) = expr.as_or() {
+        match operands {
+            // Empty disjunction is trivially false.
+            [] => return write!(f, "false"),
+            [operand] => return write_infix_to(f, operand),
+            _ => (),
+        }
+        write_infix_operands(f, operands, " | ", PRECEDENCE_OR + 1)
+    } else if let Some((left, right)) = expr.as_exclusive_or() {
+        write_infix_operand(f, left, PRECEDENCE_XOR)?;
+        write!(f, " ^ ")?;
+        write_infix_operand(f, right, PRECEDENCE_XOR + 1)
+    } else if let Some((left, right)) = expr.as_implication() {
+        write_infix_operand(f, left, PRECEDENCE_IMPLICATION + 1)?;
+        write!(f, " -> ")?;
+        write_infix_operand(f, right, PRECEDENCE_IMPLICATION)
+    } else if let Some((left, right)) = expr.as_equivalence() {
+        write_infix_operand(f, left, PRECEDENCE_EQUIVALENCE)?;
+        write!(f, " <-> ")?;
+        write_infix_operand(f, right, PRECEDENCE_EQUIVALENCE + 1)
+    } else {
+        unreachable!("All ConditionExpression variants should be handled")
+    }
+}
+
+/// Write a statement label in infix notation.
+///
+/// Labels that could be mistaken for something else (e.g. `true`, `foo-bar`, or labels with
+/// whitespace) are written in double quotes, with `"` and `\` escaped using a backslash.
+fn write_infix_label(f: &mut impl Write, statement: &Statement) -> std::fmt::Result {
+    let label = statement.label();
+    let mut chars = label.chars();
+    let is_plain = chars
+        .next()
+        .is_some_and(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+        && chars.all(|ch| ch.is_alphanumeric() || ch == '_')
+        && label != "true"
+        && label != "false";
+    if is_plain {
+        return write!(f, "{}", label);
+    }
+
+    write!(f, "\"")?;
+    for ch in label.chars() {
+        if ch == '"' || ch == '\\' {
+            write!(f, "\\")?;
+        }
+        write!(f, "{}", ch)?;
+    }
+    write!(f, "\"")
+}
+
+/// Write an infix operand, adding parentheses if it binds weaker than `min_precedence`.
+fn write_infix_operand(
+    f: &mut impl Write,
+    operand: &ConditionExpression,
+    min_precedence: u8,
+) -> std::fmt::Result {
+    if infix_precedence(operand) < min_precedence {
+        write!(f, "(")?;
+        write_infix_to(f, operand)?;
+        write!(f, ")")
+    } else {
+        write_infix_to(f, operand)
+    }
+}
+
+/// Write multiple infix operands separated by the given operator.
+fn write_infix_operands(
+    f: &mut impl Write,
+    operands: &[ConditionExpression],
+    separator: &str,
+    min_precedence: u8,
+) -> std::fmt::Result {
+    for (i, operand) in operands.iter().enumerate() {
+        if i > 0 {
+            write!(f, "{}", separator)?;
+        }
+        write_infix_operand(f, operand, min_precedence)?;
+    }
+    Ok(())
+}
+
+/// Format a condition expression as a `sympy` logic expression, using `name_of`
+/// to translate statements into symbol names.
+///
+/// Uses the following syntax:
+/// - `True`/`False` - Constants
+/// - `Not(expr)` - Negation
+/// - `And(expr1, expr2, ...)` - Logical AND
+/// - `Or(expr1, expr2, ...)` - Logical OR
+/// - `Xor(expr1, expr2)` - Exclusive OR
+/// - `Implies(expr1, expr2)` - Implication
+/// - `Equivalent(expr1, expr2)` - Equivalence
+///
+/// Empty `And`/`Or` operators are written as `True`/`False`, respectively.
+pub fn write_sympy(expr: &ConditionExpression, name_of: &dyn Fn(&Statement) -> String) -> String {
+    let mut result = String::new();
+    write_sympy_to(&mut result, expr, name_of).expect("Writing to String should never fail");
+    result
+}
+
+/// Write a condition expression as a `sympy` expression to a formatter.
+fn write_sympy_to(
+    f: &mut impl Write,
+    expr: &ConditionExpression,
+    name_of: &dyn Fn(&Statement) -> String,
+) -> std::fmt::Result {
+    if let Some(value) = expr.as_constant() {
+        write!(f, "{}", if value { "True" } else { "False" })
+    } else if let Some(statement) = expr.as_statement() {
+        write!(f, "{}", name_of(statement))
+    } else if let Some(operand) = expr.as_negation() {
+        write_sympy_call(f, "Not", &[operand], name_of)
+    } else if let Some(operands) = expr.as_and() {
+        if operands.is_empty() {
+            return write!(f, "True");
+        }
+        write_sympy_call(f, "And", &operands.iter().collect::<Vec<_>>(), name_of)
+    } else if let Some(operands) = expr.as_or() {
+        if operands.is_empty() {
+            return write!(f, "False");
+        }
+        write_sympy_call(f, "Or", &operands.iter().collect::<Vec<_>>(), name_of)
+    } else if let Some((left, right)) = expr.as_exclusive_or() {
+        write_sympy_call(f, "Xor", &[left, right], name_of)
+    } else if let Some((left, right)) = expr.as_implication() {
+        write_sympy_call(f, "Implies", &[left, right], name_of)
+    } else if let Some((left, right)) = expr.as_equivalence() {
+        write_sympy_call(f, "Equivalent", &[left, right], name_of)
+    } else {
+        unreachable!("All ConditionExpression variants should be handled")
+    }
+}
+
+/// Write a `sympy` function call with the given operands separated by `, `.
+fn write_sympy_call(
+    f: &mut impl Write,
+    function: &str,
+    operands: &[&ConditionExpression],
+    name_of: &dyn Fn(&Statement) -> String,
+) -> std::fmt::Result {
+    write!(f, "{}(", function)?;
+    for (i, operand) in operands.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write_sympy_to(f, operand, name_of)?;
+    }
+    write!(f, ")")
+}
+
+/// Format a condition expression as an SMT-LIB 2 boolean term, using `name_of`
+/// to translate statements into symbol names.
+///
+/// Uses the following syntax:
+/// - `true`/`false` - Constants
+/// - `(not expr)` - Negation
+/// - `(and expr1 expr2 ...)` - Logical AND
+/// - `(or expr1 expr2 ...)` - Logical OR
+/// - `(xor expr1 expr2)` - Exclusive OR
+/// - `(=> expr1 expr2)` - Implication
+/// - `(= expr1 expr2)` - Equivalence
+///
+/// Since SMT-LIB requires at least two operands, empty `And`/`Or` operators are written as
+/// `true`/`false`, and operators with a single operand are replaced by the operand.
+pub fn write_smtlib(expr: &ConditionExpression, name_of: &dyn Fn(&Statement) -> String) -> String {
+    let mut result = String::new();
+    write_smtlib_to(&mut result, expr, name_of).expect("Writing to String should never fail");
+    result
+}
+
+/// Write a condition expression as an SMT-LIB term to a formatter.
+fn write_smtlib_to(
+    f: &mut impl Write,
+    expr: &ConditionExpression,
+    name_of: &dyn Fn(&Statement) -> String,
+) -> std::fmt::Result {
+    if let Some(value) = expr.as_constant() {
+        write!(f, "{}", if value { "true" } else { "false" })
+    } else if let Some(statement) = expr.as_statement() {
+        write!(f, "{}", name_of(statement))
+    } else if let Some(operand) = expr.as_negation() {
+        write_smtlib_application(f, "not", &[operand], name_of)
+    } else if let Some(operands) = expr.as_and() {
+        match operands {
+            [] => write!(f, "true"),
+            [operand] => write_smtlib_to(f, operand, name_of),
+            _ => write_smtlib_application(f, "and", &operands.iter().collect::<Vec<_>>(), name_of),
+        }
+    } else if let Some(operands) = expr.as_or() {
+        match operands {
+            [] => write!(f, "false"),
+            [operand] => write_smtlib_to(f, operand, name_of),
+            _ => write_smtlib_application(f, "or", &operands.iter().collect::<Vec<_>>(), name_of),
+        }
+    } else if let Some((left, right)) = expr.as_exclusive_or() {
+        write_smtlib_application(f, "xor", &[left, right], name_of)
+    } else if let Some((left, right)) = expr.as_implication() {
+        write_smtlib_application(f, "=>", &[left, right], name_of)
+    } else if let Some((left, right)) = expr.as_equivalence() {
+        write_smtlib_application(f, "=", &[left, right], name_of)
+    } else {
+        unreachable!("All ConditionExpression variants should be handled")
+    }
+}
+
+/// Write an SMT-LIB function application with the given operands separated by spaces.
+fn write_smtlib_application(
+    f: &mut impl Write,
+    function: &str,
+    operands: &[&ConditionExpression],
+    name_of: &dyn Fn(&Statement) -> String,
+) -> std::fmt::Result {
+    write!(f, "({}", function)?;
+    for operand in operands {
+        write!(f, " ")?;
+        write_smtlib_to(f, operand, name_of)?;
+    }
+    write!(f, ")")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::statement::Statement;
 
     #[test]
     fn test_write_constant_true() {
@@ -392,4 +692,131 @@ mod tests {
         assert_eq!(written, "and(1,2)");
         assert!(reparsed.is_and());
     }
+
+    // Infix writer tests
+
+    fn st(label: &str) -> ConditionExpression {
+        ConditionExpression::statement(Statement::from(label))
+    }
+
+    #[test]
+    fn test_write_infix_atoms() {
+        assert_eq!(write_infix(&ConditionExpression::constant(true)), "true");
+        assert_eq!(write_infix(&ConditionExpression::constant(false)), "false");
+        assert_eq!(write_infix(&st("42")), "42");
+        assert_eq!(write_infix(&ConditionExpression::negation(st("a"))), "!a");
+        assert_eq!(write_infix(&ConditionExpression::and(&[])), "true");
+        assert_eq!(write_infix(&ConditionExpression::or(&[])), "false");
+    }
+
+    #[test]
+    fn test_write_infix_quoted_labels() {
+        assert_eq!(write_infix(&st("foo_1")), "foo_1");
+        assert_eq!(write_infix(&st("foo-bar")), "\"foo-bar\"");
+        assert_eq!(write_infix(&st("-3")), "\"-3\"");
+        assert_eq!(write_infix(&st("true")), "\"true\"");
+        assert_eq!(write_infix(&st("a \"b\\")), "\"a \\\"b\\\\\"");
+        assert_eq!(write_infix(&st("")), "\"\"");
+    }
+
+    #[test]
+    fn test_write_infix_minimal_parentheses() {
+        // or(and(a, neg(b)), imp(c, d))
+        let expr = ConditionExpression::or(&[
+            ConditionExpression::and(&[st("a"), ConditionExpression::negation(st("b"))]),
+            ConditionExpression::implication(st("c"), st("d")),
+        ]);
+        assert_eq!(write_infix(&expr), "a & !b | (c -> d)");
+
+        // and(or(a, b), c)
+        let expr =
+            ConditionExpression::and(&[ConditionExpression::or(&[st("a"), st("b")]), st("c")]);
+        assert_eq!(write_infix(&expr), "(a | b) & c");
+
+        // neg(and(a, b))
+        let expr = ConditionExpression::negation(ConditionExpression::and(&[st("a"), st("b")]));
+        assert_eq!(write_infix(&expr), "!(a & b)");
+
+        // xor(and(a, b), c)
+        let expr = ConditionExpression::exclusive_or(
+            ConditionExpression::and(&[st("a"), st("b")]),
+            st("c"),
+        );
+        assert_eq!(write_infix(&expr), "a & b ^ c");
+    }
+
+    #[test]
+    fn test_write_infix_associativity() {
+        // Implication is right-associative.
+        let right = ConditionExpression::implication(
+            st("a"),
+            ConditionExpression::implication(st("b"), st("c")),
+        );
+        assert_eq!(write_infix(&right), "a -> b -> c");
+        let left = ConditionExpression::implication(
+            ConditionExpression::implication(st("a"), st("b")),
+            st("c"),
+        );
+        assert_eq!(write_infix(&left), "(a -> b) -> c");
+
+        // Exclusive or and equivalence are left-associative.
+        let left = ConditionExpression::exclusive_or(
+            ConditionExpression::exclusive_or(st("a"), st("b")),
+            st("c"),
+        );
+        assert_eq!(write_infix(&left), "a ^ b ^ c");
+        let right = ConditionExpression::equivalence(
+            st("a"),
+            ConditionExpression::equivalence(st("b"), st("c")),
+        );
+        assert_eq!(write_infix(&right), "a <-> (b <-> c)");
+
+        // Nested n-ary operators keep their structure.
+        let nested =
+            ConditionExpression::and(&[ConditionExpression::and(&[st("a"), st("b")]), st("c")]);
+        assert_eq!(write_infix(&nested), "(a & b) & c");
+    }
+
+    #[test]
+    fn test_write_infix_single_operand() {
+        // Single-operand conjunction is written as its operand, including its parentheses.
+        let expr = ConditionExpression::and(&[
+            ConditionExpression::or(&[ConditionExpression::or(&[st("a"), st("b")])]),
+            st("c"),
+        ]);
+        assert_eq!(write_infix(&expr), "(a | b) & c");
+    }
+
+    #[test]
+    fn test_write_sympy() {
+        let expr = ConditionExpression::parse("or(and(a,neg(b)),imp(c,iff(a,xor(b,c(v)))),and())")
+            .unwrap();
+        assert_eq!(
+            write_sympy(&expr, &|s: &Statement| s.to_string()),
+            "Or(And(a, Not(b)), Implies(c, Equivalent(a, Xor(b, True))), True)"
+        );
+
+        let expr = ConditionExpression::parse("or()").unwrap();
+        assert_eq!(write_sympy(&expr, &|s: &Statement| s.to_string()), "False");
+
+        let expr = ConditionExpression::parse("and(1,neg(2))").unwrap();
+        let named = write_sympy(&expr, &|s: &Statement| format!("x_{}", s));
+        assert_eq!(named, "And(x_1, Not(x_2))");
+    }
+
+    #[test]
+    fn test_write_smtlib() {
+        let name_of = |s: &Statement| format!("x_{}", s);
+        let expr = ConditionExpression::parse("or(and(a,neg(b)),imp(c,iff(a,xor(b,c(v)))),and())")
+            .unwrap();
+        assert_eq!(
+            write_smtlib(&expr, &name_of),
+            "(or (and x_a (not x_b)) (=> x_c (= x_a (xor x_b true))) true)"
+        );
+
+        let expr = ConditionExpression::parse("and(or(),or(a))").unwrap();
+        assert_eq!(write_smtlib(&expr, &name_of), "(and false x_a)");
+        let expr = ConditionExpression::parse("c(f)").unwrap();
+        assert_eq!(write_smtlib(&expr, &name_of), "false");
+    }
 }
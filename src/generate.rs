@@ -0,0 +1,174 @@
+use crate::{AdfExpressions, ConditionExpression, Statement};
+
+/// Generate a random [`AdfExpressions`] with `n_statements` statements named `0..n_statements`.
+///
+/// Every statement depends on each *other* statement independently with
+/// probability `edge_probability`. Statements without dependencies are free (they have
+/// no condition). Otherwise, the condition is a random tree of `and`/`or` operators
+/// whose leaves are (possibly negated) dependencies. Each dependency appears in the
+/// condition at least once, and the number of leaves is sampled uniformly such that it is
+/// `avg_condition_size` on average (unless there are more dependencies than that).
+///
+/// The result only depends on the arguments, i.e. the same `seed` always produces
+/// the same ADF.
+///
+/// # Panics
+///
+/// The `edge_probability` must be in `[0, 1]` and `avg_condition_size` must be positive.
+///
+/// # Example
+///
+/// ```
+/// # use biodivine_adf_solver::generate::random_adf;
+/// let adf = random_adf(10, 3, 0.2, 42);
+/// assert_eq!(adf.len(), 10);
+/// assert_eq!(adf, random_adf(10, 3, 0.2, 42));
+/// ```
+pub fn random_adf(
+    n_statements: usize,
+    avg_condition_size: usize,
+    edge_probability: f64,
+    seed: u64,
+) -> AdfExpressions {
+    assert!(
+        (0.0..=1.0).contains(&edge_probability),
+        "Edge probability must be in [0, 1], got {}",
+        edge_probability
+    );
+    assert!(
+        avg_condition_size > 0,
+        "Average condition size must be positive"
+    );
+
+    let mut rng = SplitMix64::new(seed);
+    let mut adf = AdfExpressions::new();
+    for target in 0..n_statements {
+        let dependencies = (0..n_statements)
+            .filter(|source| *source != target && rng.next_f64() < edge_probability)
+            .collect::<Vec<_>>();
+        if dependencies.is_empty() {
+            adf.add_statement(Statement::from(target));
+            continue;
+        }
+
+        // Uniform in [1, 2 * avg - 1], hence the mean is `avg_condition_size`.
+        let size = 1 + rng.next_below(2 * avg_condition_size - 1);
+        let mut leaves = dependencies.clone();
+        while leaves.len() < size {
+            leaves.push(dependencies[rng.next_below(dependencies.len())]);
+        }
+        rng.shuffle(&mut leaves);
+
+        let condition = random_tree(&mut rng, &leaves);
+        adf.update_condition(Statement::from(target), condition);
+    }
+    adf
+}
+
+/// Build a random `and`/`or` tree over the (non-empty) list of `leaves`.
+fn random_tree(rng: &mut SplitMix64, leaves: &[usize]) -> ConditionExpression {
+    if let [leaf] = leaves {
+        let literal = ConditionExpression::statement(Statement::from(*leaf));
+        return if rng.next_bool() {
+            ConditionExpression::negation(literal)
+        } else {
+            literal
+        };
+    }
+
+    let split = 1 + rng.next_below(leaves.len() - 1);
+    let operands = [
+        random_tree(rng, &leaves[..split]),
+        random_tree(rng, &leaves[split..]),
+    ];
+    if rng.next_bool() {
+        ConditionExpression::and(&operands)
+    } else {
+        ConditionExpression::or(&operands)
+    }
+}
+
+/// A small deterministic pseudo-random generator (SplitMix64).
+///
+/// This is not suitable for anything security-related, but it is fast, has good statistical
+/// properties, and (unlike external generators) its output is guaranteed to stay the same
+/// across versions, which keeps the generated benchmarks reproducible.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A uniformly distributed number in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn next_bool(&mut self) -> bool {
+        self.next_u64() >> 63 == 1
+    }
+
+    /// A (nearly) uniformly distributed number in `[0, bound)`. The `bound` must be positive.
+    fn next_below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// Fisher-Yates shuffle.
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.next_below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_random_adf_is_reproducible() {
+        let a = random_adf(30, 4, 0.1, 7);
+        let b = random_adf(30, 4, 0.1, 7);
+        let c = random_adf(30, 4, 0.1, 8);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_random_adf_structure() {
+        let adf = random_adf(20, 3, 0.2, 1);
+        assert_eq!(adf.len(), 20);
+        assert!(adf.find_missing_statements().is_empty());
+        for (statement, condition) in adf.conditions() {
+            let used = condition.collect_statements();
+            assert!(!used.is_empty());
+            assert!(!used.contains(&statement));
+        }
+
+        let free = random_adf(5, 3, 0.0, 1);
+        assert_eq!(free.conditions().count(), 0);
+
+        let dense = random_adf(5, 1, 1.0, 1);
+        for (statement, condition) in dense.conditions() {
+            let used = condition
+                .collect_statements()
+                .into_iter()
+                .collect::<BTreeSet<_>>();
+            assert_eq!(used.len(), 4);
+            assert!(!used.contains(&statement));
+        }
+    }
+}
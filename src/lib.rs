@@ -1,6 +1,10 @@
 mod adf_bdds;
+mod adf_cache;
 mod adf_expressions;
 mod adf_interpretation_solver;
+#[cfg(feature = "sat")]
+mod adf_sat_solver;
+mod adf_solver;
 mod bn_conversions;
 mod condition_expression;
 mod condition_expression_parser;
@@ -8,13 +12,23 @@ mod condition_expression_writer;
 mod statement;
 
 pub mod bdd_solver;
+pub mod generate;
 pub mod model_set;
 
-pub use adf_bdds::{AdfBdds, DirectEncoding, DirectMap, DualEncoding, DualMap};
-pub use adf_expressions::AdfExpressions;
-pub use adf_interpretation_solver::AdfInterpretationSolver;
+pub use adf_bdds::{
+    AdfBdds, DirectEncoding, DirectMap, DualEncoding, DualMap, EncodingError, LinkType, Polarity,
+};
+pub use adf_cache::AdfCache;
+pub use adf_expressions::{
+    AdfDialect, AdfExpressions, DuplicateHandling, ESTIMATED_BYTES_PER_SYMBOL, ParseOptions,
+    SupportSemantics,
+};
+pub use adf_interpretation_solver::{AdfInterpretationSolver, FreePolicy, Progress};
+#[cfg(feature = "sat")]
+pub use adf_sat_solver::AdfSatSolver;
+pub use adf_solver::{AdfSolver, CancelToken, Semantics};
 pub use condition_expression::{ConditionExpression, ConditionExpressionNode};
-pub use model_set::three_valued::ModelSetThreeValued;
+pub use model_set::three_valued::{ModelSetThreeValued, StatementStatus};
 pub use model_set::two_valued::ModelSetTwoValued;
 pub use model_set::{DynamicModelSet, ModelSet};
 pub use statement::Statement;
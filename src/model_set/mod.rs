@@ -1,4 +1,6 @@
+use crate::Statement;
 use ruddy::split::Bdd;
+use std::io::Write;
 
 pub mod three_valued;
 pub mod two_valued;
@@ -11,4 +13,66 @@ pub trait ModelSet {
 
     /// Count the models in this set (possibly overflowing to [`f64::INFINITY`]).
     fn model_count(&self) -> f64;
+
+    /// Render [`ModelSet::model_count`] in a human-readable form.
+    ///
+    /// Counts that are exactly representable are printed as integers. Larger finite counts
+    /// are printed as an approximate power of two. If the count overflows, the result
+    /// only says that it is larger than the largest exactly representable integer
+    /// (implementations can override this to provide a tighter bound).
+    fn model_count_display(&self) -> String {
+        format_model_count(self.model_count(), None)
+    }
+}
+
+/// The largest integer such that all smaller integers are exactly representable in [`f64`].
+const MAX_EXACT_COUNT: f64 = 9007199254740992.0; // 2^53
+
+/// Shared implementation of [`ModelSet::model_count_display`].
+///
+/// For overflowing counts, the (symbolically written) `upper_bound` is reported if known.
+pub(crate) fn format_model_count(count: f64, upper_bound: Option<String>) -> String {
+    if count.is_infinite() {
+        match upper_bound {
+            Some(bound) => format!("≤ {} (overflow)", bound),
+            None => "≥ 2^53 (overflow)".to_string(),
+        }
+    } else if count.fract() == 0.0 && count <= MAX_EXACT_COUNT {
+        format!("{:.0}", count)
+    } else {
+        format!("≈ 2^{:.2}", count.log2())
+    }
+}
+
+/// Shared implementation of the `to_csv` methods of model sets.
+///
+/// Writes a header with the labels of `statements`, followed by one line for every row.
+/// Labels containing commas, quotes or line breaks are quoted.
+pub(crate) fn write_csv<'a>(
+    writer: &mut impl Write,
+    statements: impl Iterator<Item = &'a Statement>,
+    rows: &[Vec<&str>],
+) -> Result<(), String> {
+    let header = statements
+        .map(|s| {
+            let label = s.label();
+            if label.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", label.replace('"', "\"\""))
+            } else {
+                label.to_string()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut buffer = String::new();
+    buffer.push_str(&header.join(","));
+    buffer.push('\n');
+    for row in rows {
+        buffer.push_str(&row.join(","));
+        buffer.push('\n');
+    }
+
+    writer
+        .write_all(buffer.as_bytes())
+        .map_err(|e| format!("Failed to write CSV: {}", e))
 }
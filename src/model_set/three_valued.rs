@@ -1,11 +1,28 @@
-use crate::model_set::ModelSet;
-use crate::{AdfBdds, DualEncoding, Statement};
+use crate::model_set::{ModelSet, format_model_count, write_csv};
+use crate::{AdfBdds, DualEncoding, ModelSetTwoValued, Statement};
+use cancel_this::{Cancellable, is_cancelled};
 use log::trace;
 use ruddy::VariableId;
 use ruddy::split::Bdd;
 use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
 use std::sync::Arc;
 
+/// Classification of a single statement across a set of three-valued interpretations.
+///
+/// See [`ModelSetThreeValued::statement_classification`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum StatementStatus {
+    /// The statement is `1` in every interpretation.
+    AlwaysTrue,
+    /// The statement is `0` in every interpretation.
+    AlwaysFalse,
+    /// The statement is `*` in every interpretation.
+    AlwaysUndefined,
+    /// The value of the statement differs between interpretations.
+    Varies,
+}
+
 #[derive(Clone)]
 pub struct ModelSetThreeValued {
     symbolic_set: Bdd,
@@ -21,6 +38,15 @@ impl PartialEq for ModelSetThreeValued {
 
 impl Eq for ModelSetThreeValued {}
 
+impl std::fmt::Debug for ModelSetThreeValued {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ModelSetThreeValued")
+            .field("models", &self.model_count())
+            .field("nodes", &self.symbolic_set.node_count())
+            .finish()
+    }
+}
+
 impl ModelSet for ModelSetThreeValued {
     fn symbolic_set(&self) -> &Bdd {
         ModelSetThreeValued::symbolic_set(self)
@@ -29,6 +55,10 @@ impl ModelSet for ModelSetThreeValued {
     fn model_count(&self) -> f64 {
         ModelSetThreeValued::model_count(self)
     }
+
+    fn model_count_display(&self) -> String {
+        ModelSetThreeValued::model_count_display(self)
+    }
 }
 
 impl ModelSetThreeValued {
@@ -55,11 +85,40 @@ impl ModelSetThreeValued {
         &self.encoding
     }
 
+    /// Move this set to a different `encoding` with the same [`DualMap`](crate::DualMap)
+    /// as the current encoding.
+    ///
+    /// This is the three-valued counterpart of [`ModelSetTwoValued::rebind`]. Returns an
+    /// error if the variable maps of the two encodings are not equal.
+    pub fn rebind(&self, encoding: Arc<DualEncoding>) -> Result<ModelSetThreeValued, String> {
+        if self.encoding.var_map() != encoding.var_map() {
+            return Err(format!(
+                "Cannot rebind a set over {} statements to an incompatible encoding over {} statements",
+                self.encoding.var_map().size(),
+                encoding.var_map().size()
+            ));
+        }
+        Ok(ModelSetThreeValued {
+            symbolic_set: self.symbolic_set.clone(),
+            encoding,
+        })
+    }
+
     /// Count the models in this set (possibly overflowing to [`f64::INFINITY`]).
     pub fn model_count(&self) -> f64 {
         self.encoding.count_dual_valuations(&self.symbolic_set)
     }
 
+    /// Render [`ModelSetThreeValued::model_count`] in a human-readable form (see
+    /// [`ModelSet::model_count_display`]).
+    ///
+    /// If the count overflows, the result is bounded by `3^n`, where `n` is the number
+    /// of statements in the encoding.
+    pub fn model_count_display(&self) -> String {
+        let statements = self.encoding.var_map().size();
+        format_model_count(self.model_count(), Some(format!("3^{}", statements)))
+    }
+
     /// Extract the model with the highest number of fixed variable values (i.e. `1` or `0`
     /// instead of `*`).
     ///
@@ -75,6 +134,209 @@ impl ModelSetThreeValued {
         self.symbolic_set.is_false()
     }
 
+    /// Format the interpretations in this set as a list of extensions using the bracket
+    /// notation of the ICCMA competition, e.g. `[[a,b],[c]]`.
+    ///
+    /// Following the argumentation conventions, an extension only lists statements
+    /// that are *true* (accepted) in the interpretation. Statements that are false or
+    /// undefined (`*`) are omitted. Consequently, distinct interpretations can produce
+    /// the same extension.
+    ///
+    /// The interpretations are enumerated explicitly (in the order of
+    /// [`ModelSetThreeValued::interpretations`]), so this is only suitable for reasonably
+    /// small sets.
+    pub fn to_iccma_extensions(&self) -> String {
+        let extensions = self
+            .interpretations()
+            .into_iter()
+            .map(|interpretation| {
+                let accepted = interpretation
+                    .into_iter()
+                    .filter(|(_, value)| *value == Some(true))
+                    .map(|(s, _)| s.to_string())
+                    .collect::<Vec<_>>();
+                format!("[{}]", accepted.join(","))
+            })
+            .collect::<Vec<_>>();
+
+        format!("[{}]", extensions.join(","))
+    }
+
+    /// Explicitly enumerate the interpretations in this set, sorted in ascending order.
+    ///
+    /// Each interpretation assigns `Some(value)` to fixed statements and `None` to undefined
+    /// (`*`) statements. This is only suitable for reasonably small sets.
+    pub fn interpretations(&self) -> Vec<BTreeMap<Statement, Option<bool>>> {
+        let var_map = self.encoding.var_map();
+        let mut result = Vec::new();
+        let mut remaining = self.symbolic_set.clone();
+        while !remaining.is_false() {
+            let model = self.encoding.most_fixed_model(&remaining);
+
+            let mut model_bdd = Bdd::new_true();
+            for (var, value) in &model {
+                model_bdd = model_bdd.and(&Bdd::new_literal(*var, *value));
+            }
+            remaining = remaining.and(&model_bdd.not());
+
+            let interpretation = var_map
+                .statements()
+                .map(|s| {
+                    let (p_var, n_var) = var_map[s];
+                    let value = match (model.get(&p_var), model.get(&n_var)) {
+                        (Some(true), Some(false)) => Some(true),
+                        (Some(false), Some(true)) => Some(false),
+                        _ => None,
+                    };
+                    (s.clone(), value)
+                })
+                .collect();
+            result.push(interpretation);
+        }
+
+        result.sort();
+        result
+    }
+
+    /// Write the interpretations of this set as CSV: a header with the statement labels,
+    /// followed by one row per interpretation with `0`/`1` cells for fixed statements and
+    /// `u` for undefined statements. The rows follow the order of
+    /// [`ModelSetThreeValued::interpretations`].
+    ///
+    /// The interpretations are enumerated explicitly. Hence, this returns an error (without
+    /// writing anything) if the set contains more than `max_models` interpretations.
+    pub fn to_csv(&self, writer: &mut impl Write, max_models: usize) -> Result<(), String> {
+        if self.model_count() > max_models as f64 {
+            return Err(format!(
+                "Set contains {} interpretations, but at most {} are allowed",
+                self.model_count_display(),
+                max_models
+            ));
+        }
+
+        let rows = self
+            .interpretations()
+            .into_iter()
+            .map(|interpretation| {
+                interpretation
+                    .values()
+                    .map(|value| match value {
+                        Some(true) => "1",
+                        Some(false) => "0",
+                        None => "u",
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        write_csv(writer, self.encoding.var_map().statements(), &rows)
+    }
+
+    /// Restrict this set to interpretations that are at least as informative as the given
+    /// `interpretation` (w.r.t. the information ordering `⊑`), i.e. those that fix every
+    /// statement that is fixed in `interpretation` to the same value. Statements that are
+    /// undefined (`None`) or missing in `interpretation` are not restricted.
+    ///
+    /// The filter is symbolic, hence the interpretations are not enumerated.
+    ///
+    /// # Panics
+    ///
+    /// Every statement of `interpretation` must be present in the encoding.
+    pub fn above(&self, interpretation: &BTreeMap<Statement, Option<bool>>) -> ModelSetThreeValued {
+        let var_map = self.encoding.var_map();
+        let mut result = self.symbolic_set.clone();
+        for (statement, value) in interpretation {
+            assert!(
+                var_map.get(statement).is_some(),
+                "Statement {} not found in the encoding",
+                statement
+            );
+            if let Some(value) = value {
+                let (p_lit, n_lit) = var_map.make_literals(statement);
+                let fixed = if *value {
+                    p_lit.and(&n_lit.not())
+                } else {
+                    p_lit.not().and(&n_lit)
+                };
+                result = result.and(&fixed);
+            }
+        }
+
+        ModelSetThreeValued {
+            symbolic_set: result,
+            encoding: self.encoding.clone(),
+        }
+    }
+
+    /// Compute the Hasse diagram of the information ordering (`⊑`) of the interpretations
+    /// in this set.
+    ///
+    /// The interpretations are indexed based on [`ModelSetThreeValued::interpretations`].
+    /// A pair `(i, j)` means that interpretation `j` covers interpretation `i`, i.e. `j`
+    /// fixes every statement that is fixed in `i` to the same value (and at least one
+    /// more), and there is no other interpretation in this set between `i` and `j`.
+    ///
+    /// The computation requires (at least) quadratic time in the number of interpretations.
+    /// Hence, it returns an error if the set contains more than `max_size` interpretations.
+    pub fn information_order_edges(&self, max_size: usize) -> Result<Vec<(usize, usize)>, String> {
+        let count = self.model_count();
+        if count > max_size as f64 {
+            return Err(format!(
+                "Set contains {} interpretations, but at most {} are allowed",
+                self.model_count_display(),
+                max_size
+            ));
+        }
+
+        let interpretations = self.interpretations();
+        let is_below = |i: usize, j: usize| {
+            i != j
+                && interpretations[i]
+                    .iter()
+                    .all(|(s, value)| value.is_none() || interpretations[j].get(s) == Some(value))
+        };
+
+        let mut edges = Vec::new();
+        for i in 0..interpretations.len() {
+            for j in 0..interpretations.len() {
+                if is_below(i, j)
+                    && !(0..interpretations.len()).any(|k| is_below(i, k) && is_below(k, j))
+                {
+                    edges.push((i, j));
+                }
+            }
+        }
+        Ok(edges)
+    }
+
+    /// Classify every statement based on the values it takes across all interpretations
+    /// in this set (e.g. to find statements that are decided in every complete
+    /// interpretation).
+    ///
+    /// Returns an empty map if the set is empty.
+    pub fn statement_classification(&self) -> BTreeMap<Statement, StatementStatus> {
+        let mut result = BTreeMap::new();
+        if self.is_empty() {
+            return result;
+        }
+
+        for statement in self.encoding.var_map().statements() {
+            let (p_lit, n_lit) = self.encoding.var_map().make_literals(statement);
+            let can_be_true = !self.symbolic_set.and(&p_lit).and(&n_lit.not()).is_false();
+            let can_be_false = !self.symbolic_set.and(&p_lit.not()).and(&n_lit).is_false();
+            let can_be_undefined = !self.symbolic_set.and(&p_lit).and(&n_lit).is_false();
+
+            let status = match (can_be_true, can_be_false, can_be_undefined) {
+                (true, false, false) => StatementStatus::AlwaysTrue,
+                (false, true, false) => StatementStatus::AlwaysFalse,
+                (false, false, true) => StatementStatus::AlwaysUndefined,
+                _ => StatementStatus::Varies,
+            };
+            result.insert(statement.clone(), status);
+        }
+
+        result
+    }
+
     /// Compute the set of ADF interpretations that have *exactly* `k` free statements.
     ///
     /// Under normal circumstances, this should be a relatively fast operation, where the
@@ -139,16 +401,76 @@ impl ModelSetThreeValued {
         }
     }
 
+    /// Remove all two-valued interpretations from this set, i.e. keep only interpretations
+    /// with at least one undefined (`*`) statement.
+    pub fn proper_three_valued(&self) -> ModelSetThreeValued {
+        let mut some_undefined = Bdd::new_false();
+        for statement in self.encoding.var_map().statements() {
+            let (p_lit, n_lit) = self.encoding.var_map().make_literals(statement);
+            some_undefined = some_undefined.or(&p_lit.and(&n_lit));
+        }
+
+        ModelSetThreeValued {
+            symbolic_set: self.symbolic_set.and(&some_undefined),
+            encoding: self.encoding.clone(),
+        }
+    }
+
+    /// Extract the two-valued interpretations (i.e. without undefined statements) of this
+    /// set as a [`ModelSetTwoValued`] in the direct encoding of the given `adf`.
+    ///
+    /// # Panics
+    ///
+    /// The `adf` must have the same statements as the encoding of this set.
+    pub fn two_valued_subset(&self, adf: &AdfBdds) -> ModelSetTwoValued {
+        let dual_map = self.encoding.var_map();
+        let direct_map = adf.direct_encoding().var_map();
+        assert!(
+            dual_map.statements().eq(direct_map.statements()),
+            "The ADF does not match the encoding of this set"
+        );
+
+        let mut result = self.symbolic_set.clone();
+        let mut dual_vars = Vec::new();
+        for statement in dual_map.statements().rev() {
+            let (p_var, n_var) = dual_map[statement];
+            let (p_lit, n_lit) = dual_map.make_literals(statement);
+            // The statement must be decided, and its direct variable is the positive one.
+            result = result
+                .and(&p_lit.xor(&n_lit))
+                .and(&direct_map.make_literal(statement, true).iff(&p_lit));
+            dual_vars.extend([p_var, n_var]);
+        }
+
+        adf.mk_two_valued_set(result.exists(&dual_vars))
+    }
+
     /// Extend this set with every "looser" interpretation of the interpretations that are
     /// already in the set. In this context, "looser" means the interpretation has `*` in place
-    /// of some `1` or `0`.
+    /// of some `1` or `0`. Statements in `fixed_inputs` are never made looser.
+    ///
+    /// This operation is not cancellable. Use
+    /// [`ModelSetThreeValued::try_extend_with_looser_models`] for large sets that may need
+    /// to be interrupted.
     pub fn extend_with_looser_models(
         &self,
         fixed_inputs: &BTreeSet<Statement>,
     ) -> ModelSetThreeValued {
+        self.try_extend_with_looser_models(fixed_inputs)
+            .expect("Extension with looser models was cancelled")
+    }
+
+    /// Cancellable version of [`ModelSetThreeValued::extend_with_looser_models`].
+    /// Cancellation is checked once for every statement.
+    pub fn try_extend_with_looser_models(
+        &self,
+        fixed_inputs: &BTreeSet<Statement>,
+    ) -> Cancellable<ModelSetThreeValued> {
         let mut result = self.symbolic_set.clone();
 
         for (i, s) in self.encoding.var_map().statements().rev().enumerate() {
+            is_cancelled!()?;
+
             if fixed_inputs.contains(s) {
                 // Fixed inputs can stay fixed.
                 continue;
@@ -188,17 +510,18 @@ impl ModelSetThreeValued {
             }
         }
 
-        ModelSetThreeValued {
+        Ok(ModelSetThreeValued {
             symbolic_set: result,
             encoding: self.encoding.clone(),
-        }
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::StatementStatus;
     use crate::{AdfBdds, ModelSetThreeValued, Statement};
-    use std::collections::BTreeSet;
+    use std::collections::{BTreeMap, BTreeSet};
 
     fn create_test_adf_bdds() -> AdfBdds {
         let adf_str = r#"
@@ -211,6 +534,25 @@ mod tests {
         AdfBdds::from(&expr_adf)
     }
 
+    #[test]
+    fn test_rebind() {
+        let first = create_test_adf_bdds();
+        let second = create_test_adf_bdds();
+        let valid = first.dual_encoding().valid().clone();
+        let a = first.mk_three_valued_set(valid.clone());
+        assert!(a != second.mk_three_valued_set(valid.clone()));
+
+        let a = a
+            .rebind(second.dual_encoding_arc().clone())
+            .expect("Encodings are compatible");
+        assert!(a == second.mk_three_valued_set(valid));
+
+        let other = AdfBdds::from(
+            &crate::AdfExpressions::parse("s(0).\ns(1).\ns(2).").expect("Failed to parse ADF"),
+        );
+        assert!(a.rebind(other.dual_encoding_arc().clone()).is_err());
+    }
+
     #[test]
     fn test_symbolic_set_accessor() {
         let adf = create_test_adf_bdds();
@@ -476,4 +818,228 @@ mod tests {
         let intersection = original_set.intersect(&extended);
         assert_eq!(original_set.model_count(), intersection.model_count());
     }
+
+    #[test]
+    fn test_try_extend_with_looser_models() {
+        let adf = create_test_adf_bdds();
+        let dual = adf.dual_encoding();
+        let (p0, n0) = dual.var_map().make_literals(&Statement::from(0));
+        let set = adf.mk_three_valued_set(p0.and(&n0.not()).and(dual.valid()));
+
+        let extended = set.try_extend_with_looser_models(&BTreeSet::new()).unwrap();
+        assert!(extended == set.extend_with_looser_models(&BTreeSet::new()));
+
+        let token = crate::CancelToken::new();
+        token.cancel();
+        let result = cancel_this::on_trigger(token, || {
+            set.try_extend_with_looser_models(&BTreeSet::new())
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_to_iccma_extensions() {
+        let adf = create_test_adf_bdds();
+        let dual = adf.dual_encoding();
+        let var_map = dual.var_map();
+
+        let empty = adf.mk_three_valued_set(ruddy::split::Bdd::new_false());
+        assert_eq!(empty.to_iccma_extensions(), "[]");
+
+        // (1, 1) is listed as [0,1]
+        let (p0, n0) = var_map[&Statement::from(0)];
+        let (p1, n1) = var_map[&Statement::from(1)];
+        let single =
+            adf.mk_three_valued_interpretation([(p0, true), (n0, false), (p1, true), (n1, false)]);
+        assert_eq!(single.to_iccma_extensions(), "[[0,1]]");
+
+        // (*, 0) has no accepted statements
+        let undecided =
+            adf.mk_three_valued_interpretation([(p0, true), (n0, true), (p1, false), (n1, true)]);
+        assert_eq!(undecided.to_iccma_extensions(), "[[]]");
+
+        // Undefined statements are ordered first.
+        let both = single.union(&undecided).to_iccma_extensions();
+        assert_eq!(both, "[[],[0,1]]");
+    }
+
+    #[test]
+    fn test_statement_classification() {
+        let adf_str = r#"
+            s(a).
+            s(b).
+            s(c).
+            s(d).
+            ac(a, c(v)).
+            ac(b, neg(a)).
+            ac(c, d).
+            ac(d, c).
+        "#;
+        let expr_adf = crate::AdfExpressions::parse(adf_str).expect("Failed to parse ADF");
+        let adf = AdfBdds::from(&expr_adf);
+        let solver =
+            crate::AdfInterpretationSolver::from(crate::bdd_solver::NaiveGreedySolver::default());
+        let complete = solver.solve_complete(&adf).unwrap();
+
+        let classification = complete.statement_classification();
+        assert_eq!(classification.len(), 4);
+        assert_eq!(
+            classification[&Statement::from("a")],
+            StatementStatus::AlwaysTrue
+        );
+        assert_eq!(
+            classification[&Statement::from("b")],
+            StatementStatus::AlwaysFalse
+        );
+        assert_eq!(
+            classification[&Statement::from("c")],
+            StatementStatus::Varies
+        );
+        assert_eq!(
+            classification[&Statement::from("d")],
+            StatementStatus::Varies
+        );
+
+        // The grounded interpretation leaves the cycle undefined.
+        let grounded = solver.solve_grounded(&adf).unwrap();
+        let classification = grounded.statement_classification();
+        assert_eq!(
+            classification[&Statement::from("c")],
+            StatementStatus::AlwaysUndefined
+        );
+    }
+
+    #[test]
+    fn test_statement_classification_empty() {
+        let adf = create_test_adf_bdds();
+        let empty = adf.mk_three_valued_set(ruddy::split::Bdd::new_false());
+        assert!(empty.statement_classification().is_empty());
+    }
+
+    #[test]
+    fn test_debug_summary() {
+        let adf = create_test_adf_bdds();
+        let set = adf.mk_three_valued_set(adf.dual_encoding().valid().clone());
+        assert_eq!(
+            format!("{:?}", set),
+            format!(
+                "ModelSetThreeValued {{ models: 9.0, nodes: {} }}",
+                set.symbolic_set().node_count()
+            )
+        );
+    }
+
+    #[test]
+    fn test_proper_three_valued() {
+        let adf = create_test_adf_bdds();
+        let all = adf.mk_three_valued_set(adf.dual_encoding().valid().clone());
+
+        // 9 interpretations over two statements, 4 of which are two-valued.
+        let proper = all.proper_three_valued();
+        assert_eq!(proper.model_count(), 5.0);
+        assert!(proper.proper_three_valued() == proper);
+        assert!(proper.intersect(&all) == proper);
+
+        let empty = all.minus(&all);
+        assert!(empty.proper_three_valued().is_empty());
+    }
+
+    #[test]
+    fn test_two_valued_subset() {
+        let adf = create_test_adf_bdds();
+        let all = adf.mk_three_valued_set(adf.dual_encoding().valid().clone());
+
+        let two_valued = all.two_valued_subset(&adf);
+        assert_eq!(two_valued.model_count(), 4.0);
+        assert!(all.proper_three_valued().two_valued_subset(&adf).is_empty());
+
+        // The only complete interpretation of the test ADF is two-valued.
+        let solver =
+            crate::AdfInterpretationSolver::from(crate::bdd_solver::NaiveGreedySolver::default());
+        let complete = solver.solve_complete(&adf).unwrap();
+        let expected = solver.solve_complete_two_valued(&adf).unwrap();
+        assert!(complete.two_valued_subset(&adf) == expected);
+    }
+
+    #[test]
+    fn test_information_order_edges() {
+        let adf = create_test_adf_bdds();
+        let dual = adf.dual_encoding();
+        let (p0, n0) = dual.var_map().make_literals(&Statement::from(0));
+        let (p1, n1) = dual.var_map().make_literals(&Statement::from(1));
+
+        // Interpretations (0, 1): (*, *), (*, 1), (1, 1), (0, 1).
+        let set = adf.mk_three_valued_set(
+            p1.and(&n1.not())
+                .or(&p0.and(&n0).and(&p1).and(&n1))
+                .and(dual.valid()),
+        );
+        let interpretations = set.interpretations();
+        assert_eq!(interpretations.len(), 4);
+        let index = |a: Option<bool>, b: Option<bool>| {
+            interpretations
+                .iter()
+                .position(|it| it[&Statement::from(0)] == a && it[&Statement::from(1)] == b)
+                .unwrap()
+        };
+        let bottom = index(None, None);
+        let middle = index(None, Some(true));
+
+        let mut expected = vec![
+            (bottom, middle),
+            (middle, index(Some(true), Some(true))),
+            (middle, index(Some(false), Some(true))),
+        ];
+        expected.sort();
+        assert_eq!(set.information_order_edges(4), Ok(expected));
+        assert!(set.information_order_edges(3).is_err());
+
+        let empty = adf.mk_three_valued_set(ruddy::split::Bdd::new_false());
+        assert!(empty.interpretations().is_empty());
+        assert_eq!(empty.information_order_edges(0), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_above() {
+        let adf = create_test_adf_bdds();
+        let dual = adf.dual_encoding();
+        let all = adf.mk_three_valued_set(dual.valid().clone());
+        let s0 = Statement::from(0);
+        let s1 = Statement::from(1);
+
+        let bottom = BTreeMap::from([(s0.clone(), None), (s1.clone(), None)]);
+        assert!(all.above(&bottom) == all);
+        assert!(all.above(&BTreeMap::new()) == all);
+
+        // (1, *) is below (1, *), (1, 0) and (1, 1).
+        let partial = BTreeMap::from([(s0.clone(), Some(true))]);
+        let above = all.above(&partial);
+        assert_eq!(above.model_count(), 3.0);
+        assert!(
+            above
+                .interpretations()
+                .iter()
+                .all(|it| it[&s0] == Some(true))
+        );
+
+        let full = BTreeMap::from([(s0, Some(false)), (s1, Some(true))]);
+        assert_eq!(all.above(&full).model_count(), 1.0);
+        assert!(above.above(&full).is_empty());
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let adf = create_test_adf_bdds();
+        let dual = adf.dual_encoding();
+        let (p1, n1) = dual.var_map().make_literals(&Statement::from(1));
+        let set = adf.mk_three_valued_set(p1.and(&n1.not()).and(dual.valid()));
+
+        let mut buffer = Vec::new();
+        set.to_csv(&mut buffer, 3).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "0,1\nu,1\n0,1\n1,1\n");
+
+        let mut buffer = Vec::new();
+        assert!(set.to_csv(&mut buffer, 2).is_err());
+        assert!(buffer.is_empty());
+    }
 }
@@ -1,12 +1,17 @@
-use crate::AdfBdds;
-use crate::adf_bdds::DirectEncoding;
-use crate::model_set::ModelSet;
+use crate::adf_bdds::{DirectEncoding, bdd_nodes};
+use crate::model_set::{ModelSet, format_model_count, write_csv};
+use crate::{AdfBdds, Statement};
+use cancel_this::{Cancellable, is_cancelled};
 use log::trace;
 use ruddy::VariableId;
 use ruddy::split::Bdd;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{Read, Write};
 use std::sync::Arc;
 
+/// Magic bytes identifying the binary format of [`ModelSetTwoValued::save`].
+const BINARY_MAGIC: &[u8; 8] = b"ADFMS2V1";
+
 #[derive(Clone)]
 pub struct ModelSetTwoValued {
     symbolic_set: Bdd,
@@ -22,6 +27,15 @@ impl PartialEq for ModelSetTwoValued {
 
 impl Eq for ModelSetTwoValued {}
 
+impl std::fmt::Debug for ModelSetTwoValued {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ModelSetTwoValued")
+            .field("models", &self.model_count())
+            .field("nodes", &self.symbolic_set.node_count())
+            .finish()
+    }
+}
+
 impl ModelSet for ModelSetTwoValued {
     fn symbolic_set(&self) -> &Bdd {
         ModelSetTwoValued::symbolic_set(self)
@@ -30,6 +44,10 @@ impl ModelSet for ModelSetTwoValued {
     fn model_count(&self) -> f64 {
         ModelSetTwoValued::model_count(self)
     }
+
+    fn model_count_display(&self) -> String {
+        ModelSetTwoValued::model_count_display(self)
+    }
 }
 
 impl ModelSetTwoValued {
@@ -56,11 +74,164 @@ impl ModelSetTwoValued {
         &self.encoding
     }
 
+    /// Move this set to a different `encoding` with the same [`DirectMap`](crate::DirectMap)
+    /// as the current encoding.
+    ///
+    /// Operations like [`ModelSetTwoValued::intersect`] require both sets to share the same
+    /// encoding instance. This makes it possible to combine sets that were built separately
+    /// (e.g. loaded from different sources) for the same ADF. Returns an error if the
+    /// variable maps of the two encodings are not equal.
+    pub fn rebind(&self, encoding: Arc<DirectEncoding>) -> Result<ModelSetTwoValued, String> {
+        if self.encoding.var_map() != encoding.var_map() {
+            return Err(format!(
+                "Cannot rebind a set over {} statements to an incompatible encoding over {} statements",
+                self.encoding.var_map().size(),
+                encoding.var_map().size()
+            ));
+        }
+        Ok(ModelSetTwoValued {
+            symbolic_set: self.symbolic_set.clone(),
+            encoding,
+        })
+    }
+
     /// Count the models in this set (possibly overflowing to [`f64::INFINITY`]).
     pub fn model_count(&self) -> f64 {
         self.encoding.count_direct_valuations(&self.symbolic_set)
     }
 
+    /// Render [`ModelSetTwoValued::model_count`] in a human-readable form (see
+    /// [`ModelSet::model_count_display`]).
+    ///
+    /// If the count overflows, the result is bounded by `2^n`, where `n` is the number
+    /// of statements in the encoding.
+    pub fn model_count_display(&self) -> String {
+        let statements = self.encoding.var_map().size();
+        format_model_count(self.model_count(), Some(format!("2^{}", statements)))
+    }
+
+    /// Count the distinct valuations of the `observable` statements that appear in
+    /// the models of this set (possibly overflowing to [`f64::INFINITY`]).
+    ///
+    /// This is the number of models after projecting the set onto the `observable`
+    /// statements, i.e. models that only differ in the remaining statements are counted once.
+    ///
+    /// # Panics
+    ///
+    /// All `observable` statements must be present in the encoding.
+    pub fn count_projected(&self, observable: &[Statement]) -> f64 {
+        let var_map = self.encoding.var_map();
+        let observable = observable
+            .iter()
+            .map(|statement| {
+                var_map.get(statement).unwrap_or_else(|| {
+                    panic!("Statement `{}` is not present in the encoding", statement)
+                })
+            })
+            .collect::<BTreeSet<_>>();
+        let hidden = var_map
+            .variable_ids()
+            .filter(|var| !observable.contains(var))
+            .copied()
+            .collect::<Vec<_>>();
+
+        // The projected set only depends on the observable variables, hence it is counted
+        // over these variables only (the hidden variables are not part of the count at all).
+        let projected = self.symbolic_set.exists(&hidden);
+        let observable = observable.into_iter().collect::<Vec<_>>();
+        weighted_bdd_count(&projected, &observable, &vec![(1.0, 1.0); observable.len()])
+    }
+
+    /// Compute the weighted model count of this set: the sum over all models of the product
+    /// of statement weights, where each statement contributes its `true` weight or its
+    /// `false` weight depending on its value in the model.
+    ///
+    /// The `weights` map each statement to a `(true_weight, false_weight)` pair. Statements
+    /// that are not in the map have weights `(1.0, 1.0)`. Hence, with an empty map, this is
+    /// the same as [`ModelSetTwoValued::model_count`], while with weights `(p, 1 - p)`
+    /// for all statements, it is the probability of the set when each statement is
+    /// independently true with probability `p`.
+    ///
+    /// The count is computed once for every node of the underlying BDD (from the terminals
+    /// to the root), hence it is linear in the size of the BDD.
+    ///
+    /// # Panics
+    ///
+    /// All statements of the `weights` map must be present in the encoding.
+    pub fn weighted_count(&self, weights: &BTreeMap<Statement, (f64, f64)>) -> f64 {
+        let var_map = self.encoding.var_map();
+        for statement in weights.keys() {
+            assert!(
+                var_map.get(statement).is_some(),
+                "Statement `{}` is not present in the encoding",
+                statement
+            );
+        }
+
+        let variables = var_map.statements().map(|s| var_map[s]).collect::<Vec<_>>();
+        let level_weights = var_map
+            .statements()
+            .map(|s| weights.get(s).copied().unwrap_or((1.0, 1.0)))
+            .collect::<Vec<_>>();
+        weighted_bdd_count(&self.symbolic_set, &variables, &level_weights)
+    }
+
+    /// Compute the marginal probability that `statement` is true in a model of this set.
+    ///
+    /// The statements are assumed to be *independently* true with the probabilities given
+    /// by `priors`. Statements without a prior are true with probability `0.5`, i.e. they
+    /// use the weights `(0.5, 0.5)`, which do not change the result (compared to unweighted
+    /// statements) but keep the weighted counts from overflowing. The result
+    /// is the probability that `statement` is true, conditioned on the interpretation being
+    /// in this set, i.e. the [`ModelSetTwoValued::weighted_count`] of the models where
+    /// `statement` is true, divided by the weighted count of all models. If the set is
+    /// empty (or has zero probability under the given priors), the result is [`f64::NAN`].
+    ///
+    /// # Panics
+    ///
+    /// The `statement` and all statements of `priors` must be present in the encoding,
+    /// and all priors must be in `[0, 1]`.
+    pub fn marginal_probability(
+        &self,
+        statement: Statement,
+        priors: &BTreeMap<Statement, f64>,
+    ) -> f64 {
+        let var_map = self.encoding.var_map();
+        for (s, p) in priors {
+            assert!(
+                var_map.get(s).is_some(),
+                "Statement `{}` is not present in the encoding",
+                s
+            );
+            assert!(
+                (0.0..=1.0).contains(p),
+                "Prior of statement `{}` must be in [0, 1], got {}",
+                s,
+                p
+            );
+        }
+        // Every statement gets weights that sum to one (the default prior is `0.5`), such that
+        // the weighted counts are probabilities and cannot overflow for large encodings.
+        let weights = var_map
+            .statements()
+            .map(|s| {
+                let p = priors.get(s).copied().unwrap_or(0.5);
+                (s.clone(), (p, 1.0 - p))
+            })
+            .collect::<BTreeMap<_, _>>();
+        let literal = var_map.make_literal(&statement, true);
+        let positive = ModelSetTwoValued {
+            symbolic_set: self.symbolic_set.and(&literal),
+            encoding: self.encoding.clone(),
+        };
+
+        let total = self.weighted_count(&weights);
+        if total == 0.0 {
+            return f64::NAN;
+        }
+        positive.weighted_count(&weights) / total
+    }
+
     /// Extract the model with the highest number of zeros (the least number of ones).
     ///
     /// # Panics
@@ -75,6 +246,175 @@ impl ModelSetTwoValued {
         self.symbolic_set.is_false()
     }
 
+    /// Returns `true` if statements `a` and `b` have the same value in every model
+    /// of this set (trivially `true` for an empty set).
+    ///
+    /// # Panics
+    ///
+    /// Both statements must be present in the encoding.
+    pub fn are_equivalent(&self, a: Statement, b: Statement) -> bool {
+        let var_map = self.encoding.var_map();
+        let differ = var_map
+            .make_literal(&a, true)
+            .xor(&var_map.make_literal(&b, true));
+        self.symbolic_set.and(&differ).is_false()
+    }
+
+    /// Compute the backbone of this set, i.e. the statements whose value is the same
+    /// in every model, together with that value.
+    ///
+    /// Returns an empty map if the set is empty.
+    pub fn backbone(&self) -> BTreeMap<Statement, bool> {
+        let mut result = BTreeMap::new();
+        if self.is_empty() {
+            return result;
+        }
+
+        for statement in self.encoding.var_map().statements() {
+            let literal = self.encoding.var_map().make_literal(statement, true);
+            let can_be_true = !self.symbolic_set.and(&literal).is_false();
+            let can_be_false = !self.symbolic_set.and(&literal.not()).is_false();
+            if can_be_true != can_be_false {
+                result.insert(statement.clone(), can_be_true);
+            }
+        }
+
+        result
+    }
+
+    /// Statements that are false in every model of this set (e.g. arguments that can never
+    /// be accepted).
+    ///
+    /// A statement is included if the set implies its negation. Consequently, all
+    /// statements are included if the set is empty. The result is sorted.
+    pub fn never_true(&self) -> Vec<Statement> {
+        self.statements_implied(false)
+    }
+
+    /// Statements that are true in every model of this set.
+    ///
+    /// A statement is included if the set implies it. Consequently, all statements are
+    /// included if the set is empty. The result is sorted.
+    pub fn never_false(&self) -> Vec<Statement> {
+        self.statements_implied(true)
+    }
+
+    /// Statements which have the given `value` in every model of this set.
+    fn statements_implied(&self, value: bool) -> Vec<Statement> {
+        let var_map = self.encoding.var_map();
+        var_map
+            .statements()
+            .filter(|statement| {
+                let opposite = var_map.make_literal(statement, !value);
+                self.symbolic_set.and(&opposite).is_false()
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Compute the value of every statement that is shared by all models of this set:
+    /// `Some(value)` if the statement has the same `value` in all models, and `None` if
+    /// its value varies.
+    ///
+    /// This is the [`ModelSetTwoValued::backbone`] extended to all statements. Returns
+    /// an empty map if the set is empty.
+    pub fn shared_assignment(&self) -> BTreeMap<Statement, Option<bool>> {
+        if self.is_empty() {
+            return BTreeMap::new();
+        }
+
+        let backbone = self.backbone();
+        self.encoding
+            .var_map()
+            .statements()
+            .map(|s| (s.clone(), backbone.get(s).copied()))
+            .collect()
+    }
+
+    /// Compute the largest number of statements that are simultaneously set to one
+    /// in some model of this set. Returns `None` if the set is empty.
+    ///
+    /// Note that the existence of a model with exactly `k` ones is not monotone in `k`,
+    /// hence the candidate counts are tested one by one, starting from the largest.
+    pub fn max_true_count(&self) -> Option<usize> {
+        let statement_count = self.encoding.var_map().size();
+        (0..=statement_count).rev().find(|k| {
+            !self
+                .symbolic_set
+                .and(&self.mk_exactly_k_ones(*k))
+                .is_false()
+        })
+    }
+
+    /// Compute the smallest number of statements that are simultaneously set to one
+    /// in some model of this set. Returns `None` if the set is empty.
+    ///
+    /// See also [`ModelSetTwoValued::max_true_count`].
+    pub fn min_true_count(&self) -> Option<usize> {
+        let statement_count = self.encoding.var_map().size();
+        (0..=statement_count).find(|k| {
+            !self
+                .symbolic_set
+                .and(&self.mk_exactly_k_ones(*k))
+                .is_false()
+        })
+    }
+
+    /// Compute the smallest Hamming distance between a model of this set and the `reference`
+    /// assignment, i.e. the smallest number of statements that have a different value
+    /// than in the `reference`. Statements not mentioned in the `reference` do not count
+    /// towards the distance. Returns `None` if the set is empty.
+    ///
+    /// The set is first transformed such that the `reference` corresponds to the all-zero
+    /// assignment, at which point the distance is the number of ones, computed as in
+    /// [`ModelSetTwoValued::min_true_count`].
+    ///
+    /// # Panics
+    ///
+    /// All statements of the `reference` must be present in the encoding.
+    pub fn min_hamming_to(&self, reference: &BTreeMap<Statement, bool>) -> Option<usize> {
+        let var_map = self.encoding.var_map();
+        let mut observed = BTreeSet::new();
+        let mut flips = Vec::new();
+        for (statement, value) in reference {
+            let var = var_map.get(statement).unwrap_or_else(|| {
+                panic!("Statement `{}` is not present in the encoding", statement)
+            });
+            observed.insert(var);
+            if *value {
+                flips.push(var);
+            }
+        }
+
+        let hidden = var_map
+            .variable_ids()
+            .filter(|var| !observed.contains(var))
+            .copied()
+            .collect::<Vec<_>>();
+        let observed = observed.into_iter().collect::<Vec<_>>();
+        let mut shifted = self.symbolic_set.exists(&hidden);
+        for var in flips {
+            shifted = shifted.safe_substitution(var, &Bdd::new_literal(var, false));
+        }
+
+        (0..=observed.len()).find(|k| {
+            !shifted
+                .and(&Bdd::new_sat_exactly_k(*k, &observed))
+                .is_false()
+        })
+    }
+
+    /// A [`Bdd`] of all valuations of the direct encoding with exactly `k` ones.
+    fn mk_exactly_k_ones(&self, k: usize) -> Bdd {
+        let direct_vars = self
+            .encoding
+            .var_map()
+            .variable_ids()
+            .copied()
+            .collect::<Vec<_>>();
+        Bdd::new_sat_exactly_k(k, &direct_vars)
+    }
+
     /// Compute the intersection of two sets.
     pub fn intersect(&self, other: &ModelSetTwoValued) -> ModelSetTwoValued {
         assert!(Arc::ptr_eq(&self.encoding, &other.encoding));
@@ -105,6 +445,176 @@ impl ModelSetTwoValued {
         }
     }
 
+    /// Compute the symmetric difference of two sets, i.e. the models that are in exactly
+    /// one of the two sets.
+    pub fn symmetric_difference(&self, other: &ModelSetTwoValued) -> ModelSetTwoValued {
+        assert!(Arc::ptr_eq(&self.encoding, &other.encoding));
+
+        ModelSetTwoValued {
+            symbolic_set: self.symbolic_set.xor(&other.symbolic_set),
+            encoding: self.encoding.clone(),
+        }
+    }
+
+    /// Restrict this set to models that agree with the `reference` interpretation on all
+    /// statements in `on`. Statements outside of `on` are not restricted.
+    ///
+    /// # Panics
+    ///
+    /// Every statement in `on` must be present both in the `reference` and in the encoding.
+    pub fn agreeing_with(
+        &self,
+        reference: &BTreeMap<Statement, bool>,
+        on: &[Statement],
+    ) -> ModelSetTwoValued {
+        let mut result = self.symbolic_set.clone();
+        for statement in on {
+            let value = reference.get(statement).unwrap_or_else(|| {
+                panic!(
+                    "Statement {} not found in the reference interpretation",
+                    statement
+                )
+            });
+            result = result.and(&self.encoding.var_map().make_literal(statement, *value));
+        }
+
+        ModelSetTwoValued {
+            symbolic_set: result,
+            encoding: self.encoding.clone(),
+        }
+    }
+
+    /// Serialize this set into a compact binary format.
+    ///
+    /// The output contains the labels of all statements of the encoding (used to validate
+    /// the data in [`ModelSetTwoValued::load`]), followed by a dump of a decision diagram
+    /// equivalent to the underlying BDD. The nodes are written bottom-up, such that every
+    /// node only references nodes written before it. All numbers are little-endian `u32`.
+    pub fn save(&self, writer: &mut impl Write) -> Result<(), String> {
+        let var_map = self.encoding.var_map();
+        let variables = var_map.statements().map(|s| var_map[s]).collect::<Vec<_>>();
+
+        // Nodes as (level, low, high). Identifiers `0` and `1` are the terminal nodes.
+        let (nodes, root) = bdd_nodes(&self.symbolic_set, &variables);
+
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(BINARY_MAGIC);
+        write_u32(&mut buffer, var_map.size());
+        for statement in var_map.statements() {
+            let label = statement.label().as_bytes();
+            write_u32(&mut buffer, label.len());
+            buffer.extend_from_slice(label);
+        }
+        write_u32(&mut buffer, nodes.len());
+        for (level, low, high) in nodes {
+            buffer.extend_from_slice(&level.to_le_bytes());
+            buffer.extend_from_slice(&low.to_le_bytes());
+            buffer.extend_from_slice(&high.to_le_bytes());
+        }
+        buffer.extend_from_slice(&root.to_le_bytes());
+
+        writer
+            .write_all(&buffer)
+            .map_err(|e| format!("Failed to write model set: {}", e))
+    }
+
+    /// Write the models of this set as CSV: a header with the statement labels, followed by
+    /// one row per model with `0`/`1` cells. The rows follow the order of
+    /// [`ModelSetTwoValued::take_models`].
+    ///
+    /// The models are enumerated explicitly. Hence, this returns an error (without writing
+    /// anything) if the set contains more than `max_models` models.
+    pub fn to_csv(&self, writer: &mut impl Write, max_models: usize) -> Result<(), String> {
+        if self.model_count() > max_models as f64 {
+            return Err(format!(
+                "Set contains {} models, but at most {} are allowed",
+                self.model_count_display(),
+                max_models
+            ));
+        }
+
+        let rows = self
+            .take_models(max_models)
+            .into_iter()
+            .map(|model| {
+                model
+                    .values()
+                    .map(|value| if *value { "1" } else { "0" })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        write_csv(writer, self.encoding.var_map().statements(), &rows)
+    }
+
+    /// Load a set previously written using [`ModelSetTwoValued::save`].
+    ///
+    /// Returns an error if the data is malformed, or if it was saved using an encoding
+    /// with different statements than the given `encoding`.
+    pub fn load(
+        reader: &mut impl Read,
+        encoding: Arc<DirectEncoding>,
+    ) -> Result<ModelSetTwoValued, String> {
+        let mut magic = [0u8; 8];
+        reader
+            .read_exact(&mut magic)
+            .map_err(|e| format!("Failed to read model set: {}", e))?;
+        if &magic != BINARY_MAGIC {
+            return Err("Invalid model set format".to_string());
+        }
+
+        let var_map = encoding.var_map();
+        let statement_count = read_u32(reader)? as usize;
+        if statement_count != var_map.size() {
+            return Err(format!(
+                "Statement count mismatch: expected {}, found {}",
+                var_map.size(),
+                statement_count
+            ));
+        }
+        let mut variables = Vec::with_capacity(statement_count);
+        for statement in var_map.statements() {
+            let length = read_u32(reader)? as usize;
+            let mut label = vec![0u8; length];
+            reader
+                .read_exact(&mut label)
+                .map_err(|e| format!("Failed to read model set: {}", e))?;
+            if label != statement.label().as_bytes() {
+                return Err(format!(
+                    "Statement mismatch: expected `{}`, found `{}`",
+                    statement,
+                    String::from_utf8_lossy(&label)
+                ));
+            }
+            variables.push(var_map[statement]);
+        }
+
+        let node_count = read_u32(reader)? as usize;
+        let mut bdds = vec![Bdd::new_false(), Bdd::new_true()];
+        for _ in 0..node_count {
+            let level = read_u32(reader)? as usize;
+            let low = read_u32(reader)? as usize;
+            let high = read_u32(reader)? as usize;
+            let (Some(var), Some(low), Some(high)) =
+                (variables.get(level), bdds.get(low), bdds.get(high))
+            else {
+                return Err("Invalid node in model set".to_string());
+            };
+            let literal = Bdd::new_literal(*var, true);
+            let node = literal.and(high).or(&literal.not().and(low));
+            bdds.push(node);
+        }
+
+        let root = read_u32(reader)? as usize;
+        let Some(symbolic_set) = bdds.get(root) else {
+            return Err("Invalid root node in model set".to_string());
+        };
+
+        Ok(ModelSetTwoValued {
+            symbolic_set: symbolic_set.clone(),
+            encoding,
+        })
+    }
+
     /// Compute the set of ADF interpretations that have *exactly* `k` statements set to one.
     ///
     /// Under normal circumstances, this should be a relatively fast operation, where the
@@ -118,7 +628,17 @@ impl ModelSetTwoValued {
     }
 
     /// Extend this set with every interpretation that has additional statements fixed to one.
+    ///
+    /// This operation is not cancellable. Use [`ModelSetTwoValued::try_extend_with_more_ones`]
+    /// for large sets that may need to be interrupted.
     pub fn extend_with_more_ones(&self) -> ModelSetTwoValued {
+        self.try_extend_with_more_ones()
+            .expect("Extension with more ones was cancelled")
+    }
+
+    /// Cancellable version of [`ModelSetTwoValued::extend_with_more_ones`]. Cancellation
+    /// is checked once for every statement.
+    pub fn try_extend_with_more_ones(&self) -> Cancellable<ModelSetTwoValued> {
         let mut result = self.symbolic_set.clone();
         for (i, var) in self
             .encoding
@@ -128,6 +648,8 @@ impl ModelSetTwoValued {
             .rev()
             .enumerate()
         {
+            is_cancelled!()?;
+
             let lit = Bdd::new_literal(var, true);
             let nlit = Bdd::new_literal(var, false);
 
@@ -152,16 +674,174 @@ impl ModelSetTwoValued {
             }
         }
 
-        ModelSetTwoValued {
+        Ok(ModelSetTwoValued {
             symbolic_set: result,
             encoding: self.encoding.clone(),
+        })
+    }
+
+    /// Enumerate all models in this set such that consecutive models differ in as few
+    /// statements as possible (i.e. a best-effort "Gray code" order).
+    ///
+    /// The ordering is greedy: it starts with the [`ModelSetTwoValued::most_zero_model`] and
+    /// then always continues with the closest (in terms of Hamming distance) model that
+    /// has not been visited yet. Ties are broken by picking the model with the most zeros.
+    /// Consequently, it is not guaranteed that the whole order is optimal, and each step can
+    /// be quite expensive for large sets. This is intended mainly for visualization.
+    pub fn iter_models_min_change(&self) -> impl Iterator<Item = BTreeMap<VariableId, bool>> {
+        let encoding = self.encoding.clone();
+        let mut remaining = self.symbolic_set.clone();
+        let mut previous: Option<Bdd> = None;
+        std::iter::from_fn(move || {
+            if remaining.is_false() {
+                return None;
+            }
+
+            let candidates = match &previous {
+                None => remaining.clone(),
+                Some(previous) => Self::closest_models(&encoding, &remaining, previous),
+            };
+
+            let model = encoding.most_zero_model(&candidates);
+            let mut model_bdd = Bdd::new_true();
+            for (var, value) in &model {
+                model_bdd = model_bdd.and(&Bdd::new_literal(*var, *value));
+            }
+
+            remaining = remaining.and(&model_bdd.not());
+            previous = Some(model_bdd);
+            Some(model)
+        })
+    }
+
+    /// Return at most `n` models of this set, ordered lexicographically by statement
+    /// values (with `false < true`).
+    ///
+    /// The models are obtained by a depth-first walk over the statements which stops once
+    /// `n` models are found. Since every explored branch is non-empty, the walk never
+    /// explores more than `n` branches at each level, regardless of the size of the set.
+    pub fn take_models(&self, n: usize) -> Vec<BTreeMap<Statement, bool>> {
+        let var_map = self.encoding.var_map();
+        let statements = var_map
+            .statements()
+            .map(|s| (s.clone(), var_map[s]))
+            .collect::<Vec<_>>();
+        let mut result = Vec::new();
+        if n == 0 || self.is_empty() {
+            return result;
         }
+        let mut current = BTreeMap::new();
+        Self::take_models_rec(
+            &self.symbolic_set,
+            &statements,
+            &mut current,
+            &mut result,
+            n,
+        );
+        result
     }
+
+    /// Recursive part of [`ModelSetTwoValued::take_models`]: extend the partial
+    /// model `current` using the remaining `statements`. The `set` must not be empty.
+    fn take_models_rec(
+        set: &Bdd,
+        statements: &[(Statement, VariableId)],
+        current: &mut BTreeMap<Statement, bool>,
+        result: &mut Vec<BTreeMap<Statement, bool>>,
+        n: usize,
+    ) {
+        let Some(((statement, var), rest)) = statements.split_first() else {
+            result.push(current.clone());
+            return;
+        };
+        for value in [false, true] {
+            if result.len() >= n {
+                return;
+            }
+            let branch = set.and(&Bdd::new_literal(*var, value));
+            if !branch.is_false() {
+                current.insert(statement.clone(), value);
+                Self::take_models_rec(&branch, rest, current, result, n);
+                current.remove(statement);
+            }
+        }
+    }
+
+    /// Select the subset of `models` that has the minimal Hamming distance to the
+    /// single `model` (given as a conjunction of literals).
+    ///
+    /// The set `models` must not be empty.
+    fn closest_models(encoding: &DirectEncoding, models: &Bdd, model: &Bdd) -> Bdd {
+        // Gradually increase the Hamming ball around `model` until it hits `models`.
+        let mut ball = model.clone();
+        loop {
+            let closest = models.and(&ball);
+            if !closest.is_false() {
+                return closest;
+            }
+
+            let mut extended = ball.clone();
+            for var in encoding.var_map().variable_ids() {
+                extended = extended.or(&ball.exists(&[*var]));
+            }
+            ball = extended;
+        }
+    }
+}
+
+/// Append a length or count to the buffer as a little-endian `u32`.
+fn write_u32(buffer: &mut Vec<u8>, value: usize) {
+    let value = u32::try_from(value).expect("Value too large for the binary format");
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+/// Read a little-endian `u32` from the reader.
+fn read_u32(reader: &mut impl Read) -> Result<u32, String> {
+    let mut bytes = [0u8; 4];
+    reader
+        .read_exact(&mut bytes)
+        .map_err(|e| format!("Failed to read model set: {}", e))?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+/// Compute the weighted count of `bdd` over the given (ordered) `variables`, where the
+/// variable at position `i` has the `(true_weight, false_weight)` pair `level_weights[i]`
+/// (see [`ModelSetTwoValued::weighted_count`]).
+///
+/// The count is computed once for every node of `bdd`, whose support must be a subset
+/// of `variables`.
+fn weighted_bdd_count(bdd: &Bdd, variables: &[VariableId], level_weights: &[(f64, f64)]) -> f64 {
+    // The total weight of all values of the variables in `levels` (skipped by an edge).
+    let skipped = |levels: std::ops::Range<usize>| -> f64 {
+        level_weights[levels]
+            .iter()
+            .map(|(true_weight, false_weight)| true_weight + false_weight)
+            .product()
+    };
+
+    // The nodes are ordered child-first, hence the count of every node only depends
+    // on the counts of the nodes before it. Terminals are at the last level.
+    let (nodes, root) = bdd_nodes(bdd, variables);
+    let mut counts = vec![(0.0, variables.len()), (1.0, variables.len())];
+    for (level, low, high) in nodes {
+        let level = level as usize;
+        let (true_weight, false_weight) = level_weights[level];
+        let (low_count, low_level) = counts[low as usize];
+        let (high_count, high_level) = counts[high as usize];
+        let count = true_weight * skipped(level + 1..high_level) * high_count
+            + false_weight * skipped(level + 1..low_level) * low_count;
+        counts.push((count, level));
+    }
+
+    let (root_count, root_level) = counts[root as usize];
+    skipped(0..root_level) * root_count
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{AdfBdds, Statement};
+    use ruddy::VariableId;
+    use std::collections::{BTreeMap, BTreeSet};
 
     fn create_test_adf_bdds() -> AdfBdds {
         let adf_str = r#"
@@ -639,4 +1319,548 @@ mod tests {
         assert!(extended.is_empty());
         assert_eq!(extended.model_count(), 0.0);
     }
+
+    fn hamming_distance(a: &BTreeMap<VariableId, bool>, b: &BTreeMap<VariableId, bool>) -> usize {
+        a.iter()
+            .filter(|(var, value)| b.get(var) != Some(value))
+            .count()
+    }
+
+    #[test]
+    fn test_iter_models_min_change_empty() {
+        let adf = create_test_adf_bdds();
+        let set = adf.mk_two_valued_set(ruddy::split::Bdd::new_false());
+        assert_eq!(set.iter_models_min_change().count(), 0);
+    }
+
+    #[test]
+    fn test_iter_models_min_change_gray_code() {
+        let expr_adf = crate::AdfExpressions::parse("s(a).\ns(b).\ns(c).").unwrap();
+        let adf = AdfBdds::from(&expr_adf);
+        let set = adf.mk_two_valued_set(ruddy::split::Bdd::new_true());
+
+        let models = set.iter_models_min_change().collect::<Vec<_>>();
+        assert_eq!(models.len(), 8);
+        assert!(models[0].values().all(|value| !value));
+        for pair in models.windows(2) {
+            assert_eq!(hamming_distance(&pair[0], &pair[1]), 1);
+        }
+        let unique = models.iter().collect::<BTreeSet<_>>();
+        assert_eq!(unique.len(), 8);
+    }
+
+    #[test]
+    fn test_iter_models_min_change_skips_gaps() {
+        // Models (F,F) and (T,T) differ in both statements, but there is no better option.
+        let adf = create_test_adf_bdds();
+        let var_map = adf.direct_encoding().var_map();
+        let s0 = var_map.make_literal(&Statement::from(0), true);
+        let s1 = var_map.make_literal(&Statement::from(1), true);
+        let set = adf.mk_two_valued_set(s0.iff(&s1));
+
+        let models = set.iter_models_min_change().collect::<Vec<_>>();
+        assert_eq!(models.len(), 2);
+        assert_eq!(hamming_distance(&models[0], &models[1]), 2);
+        assert!(models[0].values().all(|value| !value));
+        assert!(models[1].values().all(|value| *value));
+    }
+
+    #[test]
+    fn test_agreeing_with() {
+        let adf = create_test_adf_bdds();
+        let set = adf.mk_two_valued_set(ruddy::split::Bdd::new_true());
+        let reference = BTreeMap::from([(Statement::from(0), true), (Statement::from(1), false)]);
+
+        // No statements to agree on.
+        let agreeing = set.agreeing_with(&reference, &[]);
+        assert!(agreeing == set);
+
+        let agreeing = set.agreeing_with(&reference, &[Statement::from(0)]);
+        assert_eq!(agreeing.model_count(), 2.0);
+        let agreeing = agreeing.agreeing_with(&reference, &[Statement::from(1)]);
+        assert_eq!(agreeing.model_count(), 1.0);
+
+        let both = set.agreeing_with(&reference, &[Statement::from(0), Statement::from(1)]);
+        assert!(both == agreeing);
+        assert_eq!(
+            both.most_zero_model().into_values().collect::<Vec<_>>(),
+            vec![true, false]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_agreeing_with_missing_reference() {
+        let adf = create_test_adf_bdds();
+        let set = adf.mk_two_valued_set(ruddy::split::Bdd::new_true());
+        let reference = BTreeMap::from([(Statement::from(0), true)]);
+        set.agreeing_with(&reference, &[Statement::from(1)]);
+    }
+
+    #[test]
+    fn test_save_load_roundtrip() {
+        let adf = create_test_adf_bdds();
+        let var_map = adf.direct_encoding().var_map();
+        let s0 = var_map.make_literal(&Statement::from(0), true);
+        let s1 = var_map.make_literal(&Statement::from(1), true);
+
+        for bdd in [
+            ruddy::split::Bdd::new_true(),
+            ruddy::split::Bdd::new_false(),
+            s0.xor(&s1),
+            s0.and(&s1.not()),
+            s1.clone(),
+        ] {
+            let set = adf.mk_two_valued_set(bdd);
+            let mut buffer = Vec::new();
+            set.save(&mut buffer).unwrap();
+
+            let loaded = super::ModelSetTwoValued::load(
+                &mut buffer.as_slice(),
+                adf.direct_encoding_arc().clone(),
+            )
+            .unwrap();
+            assert!(loaded.symbolic_set().iff(set.symbolic_set()).is_true());
+            assert_eq!(loaded.model_count(), set.model_count());
+        }
+    }
+
+    #[test]
+    fn test_load_encoding_mismatch() {
+        let adf = create_test_adf_bdds();
+        let set = adf.mk_two_valued_set(ruddy::split::Bdd::new_true());
+        let mut buffer = Vec::new();
+        set.save(&mut buffer).unwrap();
+
+        // Different statement labels.
+        let other = crate::AdfExpressions::parse("s(0).\ns(2).").unwrap();
+        let other = AdfBdds::from(&other);
+        let result = super::ModelSetTwoValued::load(
+            &mut buffer.as_slice(),
+            other.direct_encoding_arc().clone(),
+        );
+        assert!(result.is_err());
+
+        // Different statement count.
+        let other = crate::AdfExpressions::parse("s(0).").unwrap();
+        let other = AdfBdds::from(&other);
+        let result = super::ModelSetTwoValued::load(
+            &mut buffer.as_slice(),
+            other.direct_encoding_arc().clone(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_load_malformed() {
+        let adf = create_test_adf_bdds();
+        let encoding = adf.direct_encoding_arc().clone();
+        assert!(super::ModelSetTwoValued::load(&mut b"".as_slice(), encoding.clone()).is_err());
+        assert!(
+            super::ModelSetTwoValued::load(&mut b"NOTVALID".as_slice(), encoding.clone()).is_err()
+        );
+
+        // Truncated data.
+        let set = adf.mk_two_valued_set(ruddy::split::Bdd::new_true());
+        let mut buffer = Vec::new();
+        set.save(&mut buffer).unwrap();
+        buffer.pop();
+        assert!(super::ModelSetTwoValued::load(&mut buffer.as_slice(), encoding).is_err());
+    }
+
+    #[test]
+    fn test_try_extend_with_more_ones() {
+        let adf = create_test_adf_bdds();
+        let var_map = adf.direct_encoding().var_map();
+        let s0_false = var_map.make_literal(&Statement::from(0), false);
+        let set = adf.mk_two_valued_set(s0_false);
+
+        let extended = set.try_extend_with_more_ones().unwrap();
+        assert!(extended == set.extend_with_more_ones());
+        assert_eq!(extended.model_count(), 4.0);
+    }
+
+    #[test]
+    fn test_debug_summary() {
+        let adf = create_test_adf_bdds();
+        let var_map = adf.direct_encoding().var_map();
+        let s0 = var_map.make_literal(&Statement::from(0), true);
+        let set = adf.mk_two_valued_set(s0);
+        assert_eq!(
+            format!("{:?}", set),
+            format!(
+                "ModelSetTwoValued {{ models: 2.0, nodes: {} }}",
+                set.symbolic_set().node_count()
+            )
+        );
+    }
+
+    #[test]
+    fn test_true_count_bounds() {
+        let adf = create_test_adf_bdds();
+        let var_map = adf.direct_encoding().var_map();
+        let s0 = var_map.make_literal(&Statement::from(0), true);
+        let s1 = var_map.make_literal(&Statement::from(1), true);
+
+        // (F,F) or (T,T)
+        let set = adf.mk_two_valued_set(s0.iff(&s1));
+        assert_eq!(set.min_true_count(), Some(0));
+        assert_eq!(set.max_true_count(), Some(2));
+
+        // Exactly (T,F)
+        let set = adf.mk_two_valued_set(s0.and(&s1.not()));
+        assert_eq!(set.min_true_count(), Some(1));
+        assert_eq!(set.max_true_count(), Some(1));
+
+        let empty = adf.mk_two_valued_set(ruddy::split::Bdd::new_false());
+        assert_eq!(empty.min_true_count(), None);
+        assert_eq!(empty.max_true_count(), None);
+    }
+
+    #[test]
+    fn test_are_equivalent_and_backbone() {
+        let adf = create_test_adf_bdds();
+        let var_map = adf.direct_encoding().var_map();
+        let s0 = var_map.make_literal(&Statement::from(0), true);
+        let s1 = var_map.make_literal(&Statement::from(1), true);
+
+        // (F,F) or (T,T)
+        let set = adf.mk_two_valued_set(s0.iff(&s1));
+        assert!(set.are_equivalent(Statement::from(0), Statement::from(1)));
+        assert!(set.backbone().is_empty());
+
+        // (T,F) or (T,T)
+        let set = adf.mk_two_valued_set(s0.clone());
+        assert!(!set.are_equivalent(Statement::from(0), Statement::from(1)));
+        let expected = BTreeMap::from([(Statement::from(0), true)]);
+        assert_eq!(set.backbone(), expected);
+
+        // Only (F,T)
+        let set = adf.mk_two_valued_set(s0.not().and(&s1));
+        let expected = BTreeMap::from([(Statement::from(0), false), (Statement::from(1), true)]);
+        assert_eq!(set.backbone(), expected);
+
+        let empty = adf.mk_two_valued_set(ruddy::split::Bdd::new_false());
+        assert!(empty.backbone().is_empty());
+    }
+
+    #[test]
+    fn test_take_models() {
+        let adf = create_test_adf_bdds();
+        let var_map = adf.direct_encoding().var_map();
+        let s0 = var_map.make_literal(&Statement::from(0), true);
+        let s1 = var_map.make_literal(&Statement::from(1), true);
+        let set = adf.mk_two_valued_set(s0.or(&s1));
+
+        let model =
+            |a: bool, b: bool| BTreeMap::from([(Statement::from(0), a), (Statement::from(1), b)]);
+
+        assert!(set.take_models(0).is_empty());
+        assert_eq!(
+            set.take_models(2),
+            vec![model(false, true), model(true, false)]
+        );
+        let all = vec![model(false, true), model(true, false), model(true, true)];
+        assert_eq!(set.take_models(3), all);
+        assert_eq!(set.take_models(10), all);
+
+        let empty = adf.mk_two_valued_set(ruddy::split::Bdd::new_false());
+        assert!(empty.take_models(5).is_empty());
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let adf = create_test_adf_bdds();
+        let var_map = adf.direct_encoding().var_map();
+        let s0 = var_map.make_literal(&Statement::from(0), true);
+        let s1 = var_map.make_literal(&Statement::from(1), true);
+        let set = adf.mk_two_valued_set(s0.or(&s1));
+
+        let mut buffer = Vec::new();
+        set.to_csv(&mut buffer, 3).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "0,1\n0,1\n1,0\n1,1\n");
+
+        let mut buffer = Vec::new();
+        assert!(set.to_csv(&mut buffer, 2).is_err());
+        assert!(buffer.is_empty());
+
+        let mut buffer = Vec::new();
+        let empty = adf.mk_two_valued_set(ruddy::split::Bdd::new_false());
+        empty.to_csv(&mut buffer, 0).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "0,1\n");
+    }
+
+    #[test]
+    fn test_symmetric_difference() {
+        let adf = create_test_adf_bdds();
+        let var_map = adf.direct_encoding().var_map();
+        let s0 = var_map.make_literal(&Statement::from(0), true);
+        let s1 = var_map.make_literal(&Statement::from(1), true);
+        let a = adf.mk_two_valued_set(s0.clone());
+        let b = adf.mk_two_valued_set(s1.clone());
+
+        let difference = a.symmetric_difference(&b);
+        assert!(difference == a.minus(&b).union(&b.minus(&a)));
+        assert!(difference == b.symmetric_difference(&a));
+        assert_eq!(difference.model_count(), 2.0);
+        assert!(a.symmetric_difference(&a).is_empty());
+    }
+
+    #[test]
+    fn test_rebind() {
+        let first = create_test_adf_bdds();
+        let second = create_test_adf_bdds();
+        let s0 = first
+            .direct_encoding()
+            .var_map()
+            .make_literal(&Statement::from(0), true);
+        let s1 = first
+            .direct_encoding()
+            .var_map()
+            .make_literal(&Statement::from(1), true);
+        let a = first.mk_two_valued_set(s0.clone());
+        let b = second.mk_two_valued_set(s1.clone());
+        assert!(a != second.mk_two_valued_set(s0.clone()));
+
+        let a = a
+            .rebind(second.direct_encoding_arc().clone())
+            .expect("Encodings are compatible");
+        assert!(a == second.mk_two_valued_set(s0.clone()));
+        assert_eq!(a.intersect(&b).model_count(), 1.0);
+
+        let other = AdfBdds::from(
+            &crate::AdfExpressions::parse("s(0).\ns(1).\ns(2).").expect("Failed to parse ADF"),
+        );
+        assert!(a.rebind(other.direct_encoding_arc().clone()).is_err());
+    }
+
+    #[test]
+    fn test_shared_assignment() {
+        let adf = create_test_adf_bdds();
+        let var_map = adf.direct_encoding().var_map();
+        let s0 = var_map.make_literal(&Statement::from(0), true);
+
+        // (F,F) or (F,T)
+        let set = adf.mk_two_valued_set(s0.not());
+        let expected = BTreeMap::from([
+            (Statement::from(0), Some(false)),
+            (Statement::from(1), None),
+        ]);
+        assert_eq!(set.shared_assignment(), expected);
+
+        let empty = adf.mk_two_valued_set(ruddy::split::Bdd::new_false());
+        assert!(empty.shared_assignment().is_empty());
+    }
+
+    #[test]
+    fn test_model_count_display() {
+        use crate::model_set::{ModelSet, format_model_count};
+
+        let adf = create_test_adf_bdds();
+        let all = adf.mk_two_valued_set(ruddy::split::Bdd::new_true());
+        assert_eq!(all.model_count_display(), "4");
+        let empty = adf.mk_two_valued_set(ruddy::split::Bdd::new_false());
+        assert_eq!(ModelSet::model_count_display(&empty), "0");
+
+        // With 300 statements, the count over all BDD variables overflows, but the
+        // number of models does not.
+        let large: String = (0..300).map(|i| format!("s({}).\n", i)).collect();
+        let large = AdfBdds::from(&crate::AdfExpressions::parse(&large).expect("Valid ADF"));
+        let all = large.mk_two_valued_set(ruddy::split::Bdd::new_true());
+        assert_eq!(all.model_count_display(), "≈ 2^300.00");
+        let var_map = large.direct_encoding().var_map();
+        let mut fixed = ruddy::split::Bdd::new_true();
+        for i in 2..300 {
+            fixed = fixed.and(&var_map.make_literal(&Statement::from(i), false));
+        }
+        assert_eq!(large.mk_two_valued_set(fixed).model_count_display(), "4");
+
+        // Only counts that are not representable at all overflow.
+        let huge: String = (0..1100).map(|i| format!("s({}).\n", i)).collect();
+        let huge = AdfBdds::from(&crate::AdfExpressions::parse(&huge).expect("Valid ADF"));
+        let all = huge.mk_two_valued_set(ruddy::split::Bdd::new_true());
+        assert_eq!(all.model_count_display(), "≤ 2^1100 (overflow)");
+
+        assert_eq!(format_model_count(f64::INFINITY, None), "≥ 2^53 (overflow)");
+        assert_eq!(format_model_count(2.0f64.powi(60), None), "≈ 2^60.00");
+    }
+
+    #[test]
+    fn test_count_projected() {
+        let adf = AdfBdds::from(
+            &crate::AdfExpressions::parse("s(a).\ns(b).\ns(c).").expect("Failed to parse ADF"),
+        );
+        let var_map = adf.direct_encoding().var_map();
+        let a = var_map.make_literal(&Statement::from("a"), true);
+        let b = var_map.make_literal(&Statement::from("b"), true);
+
+        // a | b: three valuations of (a, b), each with two values of c.
+        let set = adf.mk_two_valued_set(a.or(&b));
+        assert_eq!(set.model_count(), 6.0);
+        assert_eq!(set.count_projected(&["a".into(), "b".into()]), 3.0);
+        assert_eq!(set.count_projected(&["a".into()]), 2.0);
+        assert_eq!(set.count_projected(&["c".into(), "c".into()]), 2.0);
+        assert_eq!(set.count_projected(&[]), 1.0);
+        assert_eq!(
+            set.count_projected(&["a".into(), "b".into(), "c".into()]),
+            6.0
+        );
+
+        let empty = adf.mk_two_valued_set(ruddy::split::Bdd::new_false());
+        assert_eq!(empty.count_projected(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_count_projected_many_statements() {
+        // The full count of 1100 statements overflows, but the projection does not.
+        let input: String = (0..1100).map(|i| format!("s({}).\n", i)).collect();
+        let adf = AdfBdds::from(&crate::AdfExpressions::parse(&input).expect("Valid ADF"));
+        let var_map = adf.direct_encoding().var_map();
+        let first = var_map.make_literal(&Statement::from(0), true);
+        let last = var_map.make_literal(&Statement::from(1099), true);
+
+        let set = adf.mk_two_valued_set(first.or(&last));
+        assert_eq!(set.model_count(), f64::INFINITY);
+        let observable = [Statement::from(0), Statement::from(1099)];
+        assert_eq!(set.count_projected(&observable), 3.0);
+        assert_eq!(set.count_projected(&observable[..1]), 2.0);
+    }
+
+    #[test]
+    fn test_min_hamming_to() {
+        let adf = AdfBdds::from(
+            &crate::AdfExpressions::parse("s(a).\ns(b).\ns(c).").expect("Failed to parse ADF"),
+        );
+        let var_map = adf.direct_encoding().var_map();
+        let a = var_map.make_literal(&Statement::from("a"), true);
+        let b = var_map.make_literal(&Statement::from("b"), true);
+        let c = var_map.make_literal(&Statement::from("c"), true);
+
+        // The only model is (a, b, c) = (1, 1, 0).
+        let set = adf.mk_two_valued_set(a.and(&b).and(&c.not()));
+        let reference = BTreeMap::from([
+            (Statement::from("a"), false),
+            (Statement::from("b"), true),
+            (Statement::from("c"), true),
+        ]);
+        assert_eq!(set.min_hamming_to(&reference), Some(2));
+
+        // Adding a model (0, 1, 1) matches the reference exactly.
+        let set = set.union(&adf.mk_two_valued_set(a.not().and(&b).and(&c)));
+        assert_eq!(set.min_hamming_to(&reference), Some(0));
+
+        // Unmentioned statements are ignored.
+        let partial = BTreeMap::from([(Statement::from("c"), false)]);
+        assert_eq!(set.min_hamming_to(&partial), Some(0));
+        assert_eq!(set.min_hamming_to(&BTreeMap::new()), Some(0));
+
+        let empty = adf.mk_two_valued_set(ruddy::split::Bdd::new_false());
+        assert_eq!(empty.min_hamming_to(&reference), None);
+    }
+
+    #[test]
+    fn test_weighted_count() {
+        let adf = AdfBdds::from(
+            &crate::AdfExpressions::parse("s(a).\ns(b).\ns(c).").expect("Failed to parse ADF"),
+        );
+        let var_map = adf.direct_encoding().var_map();
+        let a = var_map.make_literal(&Statement::from("a"), true);
+        let b = var_map.make_literal(&Statement::from("b"), true);
+
+        // a | b (with c unconstrained)
+        let set = adf.mk_two_valued_set(a.or(&b));
+        assert_eq!(set.weighted_count(&BTreeMap::new()), set.model_count());
+
+        // P(a | b) = 1 - P(!a) * P(!b) = 1 - 0.5 * 0.75
+        let weights = BTreeMap::from([
+            (Statement::from("a"), (0.5, 0.5)),
+            (Statement::from("b"), (0.25, 0.75)),
+            (Statement::from("c"), (0.1, 0.9)),
+        ]);
+        assert!((set.weighted_count(&weights) - 0.625).abs() < 1e-12);
+
+        // Only (a, b) = (1, 0) with weights 2 * 3, times c with weight 1 + 1.
+        let weights = BTreeMap::from([
+            (Statement::from("a"), (2.0, 5.0)),
+            (Statement::from("b"), (7.0, 3.0)),
+        ]);
+        let set = adf.mk_two_valued_set(a.and(&b.not()));
+        assert_eq!(set.weighted_count(&weights), 12.0);
+
+        let empty = adf.mk_two_valued_set(ruddy::split::Bdd::new_false());
+        assert_eq!(empty.weighted_count(&weights), 0.0);
+
+        // A wide BDD with many equivalent partial assignments.
+        let large: String = (0..40).map(|i| format!("s({}).\n", i)).collect();
+        let large = AdfBdds::from(&crate::AdfExpressions::parse(&large).expect("Valid ADF"));
+        let three_ones = ModelSetTwoValued::mk_exactly_k_one_statements(3, &large);
+        assert_eq!(three_ones.weighted_count(&BTreeMap::new()), 9880.0);
+        let weights = BTreeMap::from([(Statement::from(0), (3.0, 1.0))]);
+        // C(39, 2) models with the first statement set, and C(39, 3) without it.
+        assert_eq!(three_ones.weighted_count(&weights), 3.0 * 741.0 + 9139.0);
+    }
+
+    #[test]
+    fn test_marginal_probability() {
+        let adf = AdfBdds::from(
+            &crate::AdfExpressions::parse("s(a).\ns(b).").expect("Failed to parse ADF"),
+        );
+        let var_map = adf.direct_encoding().var_map();
+        let a = var_map.make_literal(&Statement::from("a"), true);
+        let b = var_map.make_literal(&Statement::from("b"), true);
+
+        // a | b, with uniform priors: a is true in 2 of the 3 models.
+        let set = adf.mk_two_valued_set(a.or(&b));
+        let uniform = set.marginal_probability("a".into(), &BTreeMap::new());
+        assert!((uniform - 2.0 / 3.0).abs() < 1e-12);
+
+        // P(a | a or b) = P(a) / P(a or b) = 0.2 / (1 - 0.8 * 0.5)
+        let priors = BTreeMap::from([(Statement::from("a"), 0.2)]);
+        let result = set.marginal_probability("a".into(), &priors);
+        assert!((result - 0.2 / 0.6).abs() < 1e-12);
+
+        let empty = adf.mk_two_valued_set(ruddy::split::Bdd::new_false());
+        assert!(empty.marginal_probability("a".into(), &priors).is_nan());
+        // Zero-probability sets behave the same as empty sets.
+        let only_a = adf.mk_two_valued_set(a);
+        let never_a = BTreeMap::from([(Statement::from("a"), 0.0)]);
+        assert!(only_a.marginal_probability("b".into(), &never_a).is_nan());
+    }
+
+    #[test]
+    fn test_marginal_probability_many_statements() {
+        // Without normalized default priors, both weighted counts would overflow.
+        let input: String = (0..1100).map(|i| format!("s({}).\n", i)).collect();
+        let adf = AdfBdds::from(&crate::AdfExpressions::parse(&input).expect("Valid ADF"));
+        let var_map = adf.direct_encoding().var_map();
+        let first = var_map.make_literal(&Statement::from(0), true);
+        let last = var_map.make_literal(&Statement::from(1099), true);
+
+        let set = adf.mk_two_valued_set(first.or(&last));
+        let uniform = set.marginal_probability(Statement::from(0), &BTreeMap::new());
+        assert!((uniform - 2.0 / 3.0).abs() < 1e-12);
+        let priors = BTreeMap::from([(Statement::from(0), 0.2)]);
+        let result = set.marginal_probability(Statement::from(0), &priors);
+        assert!((result - 0.2 / 0.6).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_never_true_never_false() {
+        let adf = AdfBdds::from(
+            &crate::AdfExpressions::parse("s(a).\ns(b).\ns(c).").expect("Failed to parse ADF"),
+        );
+        let var_map = adf.direct_encoding().var_map();
+        let a = var_map.make_literal(&Statement::from("a"), true);
+        let b = var_map.make_literal(&Statement::from("b"), true);
+        let c = var_map.make_literal(&Statement::from("c"), true);
+
+        // a & !b & (c | !c)
+        let set = adf.mk_two_valued_set(a.and(&b.not()).and(&c.or(&c.not())));
+        assert_eq!(set.never_true(), vec![Statement::from("b")]);
+        assert_eq!(set.never_false(), vec![Statement::from("a")]);
+
+        let empty = adf.mk_two_valued_set(ruddy::split::Bdd::new_false());
+        assert_eq!(empty.never_true().len(), 3);
+        assert_eq!(empty.never_false().len(), 3);
+    }
 }